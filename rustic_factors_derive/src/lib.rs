@@ -1,39 +1,271 @@
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
 
-#[proc_macro_derive(RecursivePrimeFactorization)]
+/// Implements `PrimeFactorization` by running `RecursivePrimeFactorization` with the deriving
+/// type as the factorizer.
+///
+/// The prime tester defaults to `MillerRabin` and can be replaced with
+/// `#[prime_tester(BailliePsw)]`, where the path is resolved where the type is defined. The
+/// number of successive failures to find a factor before giving up can be set with
+/// `#[max_successive_fails = 500]`, after which `prime_factorization` panics and
+/// `try_prime_factorization` returns a `FactorizationError`, as does
+/// `try_prime_factorization_with_budget` when its `Budget` runs out.
+///
+/// Like the other derives, the generated code refers to `::rustic_factors`, which
+/// `#[rustic_factors(crate = path)]` replaces for crates that re-export it under another name.
+#[proc_macro_derive(
+    RecursivePrimeFactorization,
+    attributes(prime_tester, max_successive_fails, rustic_factors)
+)]
 pub fn recursive_prime_factorization_derive(input: TokenStream) -> TokenStream {
-    let ast = syn::parse(input).unwrap();
+    let ast = parse_macro_input!(input as DeriveInput);
     impl_recursive_prime_factorization(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-fn impl_recursive_prime_factorization(ast: &syn::DeriveInput) -> TokenStream {
+fn impl_recursive_prime_factorization(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &ast.ident;
-    let gen = quote! {
-        impl crate::traits::PrimeFactorization for #name {
-            fn prime_factorization(n: &bnum::types::U512) -> Vec<bnum::types::U512> {
-                crate::orchestration::RecursivePrimeFactorization::<Self, crate::primality_test::MillerRabin>::prime_factorization(n)
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let krate = crate_path(&ast.attrs)?;
+    let options = RecursiveOptions::parse(&ast.attrs)?;
+    let prime_tester = options
+        .prime_tester
+        .unwrap_or_else(|| syn::parse_quote!(#krate::primality_test::MillerRabin));
+    let orchestrator = quote! {
+        #krate::orchestration::RecursivePrimeFactorization::<Self, #prime_tester>
+    };
+    let instance = match options.max_successive_fails {
+        Some(max) => quote! { #orchestrator::new(#max) },
+        None => quote! { #orchestrator::new(#orchestrator::DEFAULT_MAX_SUCCESSIVE_FAILS) },
+    };
+    Ok(quote! {
+        impl #impl_generics #krate::traits::PrimeFactorization for #name #ty_generics #where_clause {
+            fn prime_factorization(n: &#krate::U512) -> Vec<#krate::U512> {
+                #instance.recursive_factorization(*n)
+            }
+
+            fn try_prime_factorization(
+                n: &#krate::U512,
+            ) -> Result<Vec<#krate::U512>, #krate::FactorizationError> {
+                #instance.try_recursive_factorization(*n)
+            }
+
+            fn try_prime_factorization_with_budget(
+                n: &#krate::U512,
+                budget: &#krate::budget::Budget,
+            ) -> Result<Vec<#krate::U512>, #krate::FactorizationError> {
+                #instance.try_recursive_factorization_with_budget(*n, budget)
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct RecursiveOptions {
+    prime_tester: Option<syn::Path>,
+    max_successive_fails: Option<usize>,
+}
+
+impl RecursiveOptions {
+    /// Reports every bad attribute at once rather than only the first.
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+        let mut errors: Option<syn::Error> = None;
+        for attr in attrs {
+            if let Err(error) = options.apply(attr) {
+                match &mut errors {
+                    Some(errors) => errors.combine(error),
+                    None => errors = Some(error),
+                }
             }
         }
+        errors.map_or(Ok(options), Err)
+    }
+
+    fn apply(&mut self, attr: &syn::Attribute) -> syn::Result<()> {
+        if attr.path().is_ident("prime_tester") {
+            let path = attr.parse_args::<syn::Path>().map_err(|e| {
+                syn::Error::new(
+                    e.span(),
+                    "expected the path of a primality test, as in #[prime_tester(BailliePsw)]",
+                )
+            })?;
+            set_once(&mut self.prime_tester, path, attr)
+        } else if attr.path().is_ident("max_successive_fails") {
+            let max = parse_max_successive_fails(attr)?;
+            set_once(&mut self.max_successive_fails, max, attr)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn parse_max_successive_fails(attr: &syn::Attribute) -> syn::Result<usize> {
+    const USAGE: &str = "expected a positive integer, as in #[max_successive_fails = 500]";
+    let syn::Meta::NameValue(name_value) = &attr.meta else {
+        return Err(syn::Error::new_spanned(attr, USAGE));
+    };
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(int),
+        ..
+    }) = &name_value.value
+    else {
+        return Err(syn::Error::new_spanned(&name_value.value, USAGE));
     };
-    gen.into()
+    match int.base10_parse::<usize>() {
+        Ok(max) if max > 0 => Ok(max),
+        _ => Err(syn::Error::new_spanned(int, USAGE)),
+    }
 }
 
-#[proc_macro_derive(FactorizationCommand)]
+fn set_once<T>(slot: &mut Option<T>, value: T, attr: &syn::Attribute) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(syn::Error::new_spanned(attr, "duplicate attribute"));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Implements `Command` and `FactorizationCommand` for a `PrimeFactorization`, printing the
+/// factorization of the number, or the `FactorizationError` if it gave up.
+#[proc_macro_derive(FactorizationCommand, attributes(rustic_factors))]
 pub fn factorization_command_derive(input: TokenStream) -> TokenStream {
-    let ast = syn::parse(input).unwrap();
+    let ast = parse_macro_input!(input as DeriveInput);
     impl_factorization_command(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-fn impl_factorization_command(ast: &syn::DeriveInput) -> TokenStream {
+fn impl_factorization_command(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &ast.ident;
-    let gen = quote! {
-        impl crate::traits::Command for #name {
-            fn run(&self, n: &U512) -> String {
-                crate::Factorization::new(n, Self::prime_factorization(n)).to_string()
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let krate = crate_path(&ast.attrs)?;
+    Ok(quote! {
+        impl #impl_generics #krate::traits::Command for #name #ty_generics #where_clause {
+            fn run(&self, n: &#krate::U512) -> String {
+                let budget = #krate::budget::Budget::unlimited();
+                match <Self as #krate::traits::Command>::try_run(self, n, &budget) {
+                    Ok(output) => output,
+                    Err(error) => error.to_string(),
+                }
+            }
+
+            fn try_run(
+                &self,
+                n: &#krate::U512,
+                budget: &#krate::budget::Budget,
+            ) -> Result<String, #krate::FactorizationError> {
+                let factors = <Self as #krate::traits::PrimeFactorization>::
+                    try_prime_factorization_with_budget(n, budget)?;
+                Ok(#krate::Factorization::new(n, factors).to_string())
             }
         }
-        impl crate::traits::FactorizationCommand for # name {}
+        impl #impl_generics #krate::traits::FactorizationCommand for #name #ty_generics #where_clause {}
+    })
+}
+
+/// Implements `Command` and `PrimalityCommand` for a `PrimalityTest`, printing whether the
+/// number is prime.
+///
+/// `#[primality_command(witness)]` adds the base that proves a composite number composite,
+/// from the type's `CompositeWitness` implementation, and `#[primality_command(certificate)]`
+/// adds a `PrimalityCertificate` for a prime number.
+#[proc_macro_derive(PrimalityCommand, attributes(primality_command, rustic_factors))]
+pub fn primality_command_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    impl_primality_command(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn impl_primality_command(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let krate = crate_path(&ast.attrs)?;
+    let options = PrimalityCommandOptions::parse(&ast.attrs)?;
+    let prime = if options.certificate {
+        quote! {
+            match #krate::primality_test::PrimalityCertificate::prove(n) {
+                Some(certificate) => format!("{} is prime\n{}", n, certificate.to_string().trim_end()),
+                None => format!("{} is prime, but no certificate was found", n),
+            }
+        }
+    } else {
+        quote! { format!("{} is prime", n) }
     };
-    gen.into()
+    let composite = if options.witness {
+        quote! {
+            match <Self as #krate::traits::CompositeWitness>::composite_witness(n) {
+                Some(witness) => format!("{} is composite, witnessed by {}", n, witness),
+                None => format!("{} is composite", n),
+            }
+        }
+    } else {
+        quote! { format!("{} is composite", n) }
+    };
+    Ok(quote! {
+        impl #impl_generics #krate::traits::Command for #name #ty_generics #where_clause {
+            fn run(&self, n: &#krate::U512) -> String {
+                if <Self as #krate::traits::PrimalityTest>::is_prime(n) {
+                    #prime
+                } else {
+                    #composite
+                }
+            }
+        }
+        impl #impl_generics #krate::traits::PrimalityCommand for #name #ty_generics #where_clause {}
+    })
+}
+
+#[derive(Default)]
+struct PrimalityCommandOptions {
+    witness: bool,
+    certificate: bool,
+}
+
+impl PrimalityCommandOptions {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs {
+            if !attr.path().is_ident("primality_command") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                let flag = if meta.path.is_ident("witness") {
+                    &mut options.witness
+                } else if meta.path.is_ident("certificate") {
+                    &mut options.certificate
+                } else {
+                    return Err(meta.error("expected `witness` or `certificate`"));
+                };
+                if *flag {
+                    return Err(meta.error("duplicate option"));
+                }
+                *flag = true;
+                Ok(())
+            })?;
+        }
+        Ok(options)
+    }
+}
+
+/// Returns the path of the `rustic_factors` crate, which is `::rustic_factors` unless
+/// `#[rustic_factors(crate = path)]` says otherwise.
+fn crate_path(attrs: &[syn::Attribute]) -> syn::Result<syn::Path> {
+    let mut path = None;
+    for attr in attrs {
+        if !attr.path().is_ident("rustic_factors") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("crate") {
+                return Err(meta.error("expected `crate = path`"));
+            }
+            let value = meta.value()?.parse::<syn::Path>()?;
+            set_once(&mut path, value, attr)
+        })?;
+    }
+    Ok(path.unwrap_or_else(|| syn::parse_quote!(::rustic_factors)))
 }
@@ -1,7 +1,27 @@
+pub mod cfrac;
+pub(crate) mod congruence_of_squares;
+pub mod dixon;
+pub mod elliptic_curve_method;
 pub mod fermats_factorization_method;
+pub mod hart_one_line;
+pub mod lehman;
+pub mod pollards_p_minus_1;
 pub mod pollards_rho;
+pub mod quadratic_sieve;
+pub mod squfof;
 pub mod trial_division;
+pub(crate) mod two_stage;
+pub mod williams_p_plus_1;
 
+pub use self::cfrac::Cfrac;
+pub use self::dixon::Dixon;
+pub use self::elliptic_curve_method::EllipticCurveMethod;
 pub use self::fermats_factorization_method::FermatsFactorizationMethod;
-pub use self::pollards_rho::PollardsRho;
+pub use self::hart_one_line::HartOneLine;
+pub use self::lehman::Lehman;
+pub use self::pollards_p_minus_1::PollardsPMinus1;
+pub use self::pollards_rho::{PollardsRho, PollardsRhoBrent};
+pub use self::quadratic_sieve::QuadraticSieve;
+pub use self::squfof::Squfof;
 pub use self::trial_division::TrialDivision;
+pub use self::williams_p_plus_1::WilliamsPPlus1;
@@ -0,0 +1,172 @@
+use super::congruence_of_squares::{self, FactorBase, Relation, Relations, EXTRA_RELATIONS};
+use super::PollardsRho;
+use crate::budget::{Budget, GaveUp};
+use crate::modular;
+use crate::observer::Event;
+use crate::traits::Factorize;
+use bnum::types::U512;
+use num_integer::{Integer, Roots};
+use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
+
+/// The continued fraction factorization method of Morrison and Brillhart.
+///
+/// The convergents `A_i / B_i` of the continued fraction expansion of `sqrt(kn)` satisfy
+/// `A_{i-1}² = (-1)^i Q_i (mod n)` with `Q_i < 2 sqrt(kn)`, so the `Q_i` are far more likely to
+/// be smooth than arbitrary residues. Those that factor over the factor base, apart from at
+/// most one large prime, become relations for a congruence of squares. Numbers of at most
+/// 64 bits are handed to [`PollardsRho`], which is faster in that range.
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+pub struct Cfrac;
+
+impl Cfrac {
+    const LARGE_PRIME_MULTIPLIER: u64 = 64;
+
+    fn factor_base_size(bits: u32) -> usize {
+        match bits {
+            0..=80 => 100,
+            81..=100 => 250,
+            101..=130 => 600,
+            131..=160 => 1_500,
+            _ => 3_000,
+        }
+    }
+}
+
+impl Factorize for Cfrac {
+    fn factorize(n: &U512) -> U512 {
+        Self::factorize_with_budget(n, &Budget::unlimited()).unwrap_or(*n)
+    }
+
+    /// Takes a step of the budget per term of the continued fraction expansion, and reports
+    /// every new relation.
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        if n.is_even() {
+            return Ok(U512::TWO);
+        }
+        if n.bits() <= 64 {
+            return PollardsRho::factorize_with_budget(n, budget);
+        }
+        if let Some(root) = congruence_of_squares::perfect_power_root(n) {
+            return Ok(root);
+        }
+        let fb = match FactorBase::new(n, Self::factor_base_size(n.bits())) {
+            Ok(fb) => fb,
+            Err(p) => return Ok(p),
+        };
+        let primes = fb.prime_values();
+        let large_prime_bound = fb.largest_prime() * Self::LARGE_PRIME_MULTIPLIER;
+        let wanted = primes.len() + 1 + EXTRA_RELATIONS;
+        let mut relations = Relations::new(n);
+        let mut expansion = ContinuedFraction::new(n, &fb.kn);
+        while relations.len() < wanted {
+            budget.spend(1)?;
+            let Some((y, q, is_negative)) = expansion.next() else {
+                return Ok(*n);
+            };
+            let (mut exponents, rest) = congruence_of_squares::trial_divide(q, &primes);
+            if is_negative {
+                exponents.insert(0, (0, 1));
+            }
+            let collected = relations.len();
+            let relation = Relation::new(y, exponents);
+            match u64::try_from(rest) {
+                Ok(1) => relations.add_full(relation),
+                Ok(large_prime) if large_prime < large_prime_bound => {
+                    relations.add_partial(large_prime, relation)
+                }
+                _ => {}
+            }
+            if relations.len() > collected {
+                budget.report(Event::RelationsCollected {
+                    relations: relations.len(),
+                    wanted,
+                });
+            }
+        }
+        Ok(congruence_of_squares::find_divisor(
+            n,
+            &primes,
+            &relations.into_full(),
+        ))
+    }
+}
+
+/// The continued fraction expansion of `sqrt(kn)`, yielding `(A_{i-1} mod n, Q_i, i is odd)`
+/// for `i = 1, 2, ...` until the end of the first period.
+struct ContinuedFraction<'a> {
+    n: &'a U512,
+    sqrt_kn: U512,
+    p: U512,
+    q: U512,
+    q_prev: U512,
+    a: U512,
+    a_prev: U512,
+    is_odd: bool,
+}
+
+impl<'a> ContinuedFraction<'a> {
+    fn new(n: &'a U512, kn: &U512) -> Self {
+        let sqrt_kn = kn.sqrt();
+        Self {
+            n,
+            sqrt_kn,
+            p: sqrt_kn,
+            q: kn - sqrt_kn * sqrt_kn,
+            q_prev: U512::ONE,
+            a: sqrt_kn % n,
+            a_prev: U512::ONE,
+            is_odd: true,
+        }
+    }
+}
+
+impl Iterator for ContinuedFraction<'_> {
+    type Item = (U512, U512, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.q <= U512::ONE {
+            return None;
+        }
+        let item = (self.a, self.q, self.is_odd);
+        let b = (self.sqrt_kn + self.p) / self.q;
+        let p_next = b * self.q - self.p;
+        let q_next = self.q_prev + b * self.p - b * p_next;
+        let a_next = modular::add_mod(&modular::mul_mod(&b, &self.a, self.n), &self.a_prev, self.n);
+        (self.p, self.q_prev, self.q) = (p_next, self.q, q_next);
+        (self.a_prev, self.a) = (self.a, a_next);
+        self.is_odd = !self.is_odd;
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+
+    #[test]
+    fn default() {
+        CheckTestBuilder::default().build::<Cfrac>().check_cases()
+    }
+
+    #[test]
+    fn balanced_semiprimes() {
+        CheckTestBuilder::new()
+            .case(3181868265497317982815477, &[1013841287273, 3138428376749])
+            .case(
+                618970020197248618211718991,
+                &[17592186056779, 35184372095629],
+            )
+            .build::<Cfrac>()
+            .check_cases()
+    }
+
+    #[test]
+    fn convergents_square_to_q() {
+        let n = U512::from(1000000016000000063u64);
+        for (a, q, is_negative) in ContinuedFraction::new(&n, &n).take(50) {
+            let residue = if is_negative { n - q } else { q };
+            assert_eq!(a * a % n, residue);
+        }
+    }
+}
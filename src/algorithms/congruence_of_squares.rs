@@ -0,0 +1,99 @@
+pub mod factor_base;
+pub mod linear_algebra;
+pub mod relation;
+pub mod utils;
+
+pub use self::factor_base::FactorBase;
+pub use self::relation::{Relation, Relations};
+
+use crate::modular;
+use crate::word::Word;
+use bnum::types::U512;
+use num_integer::{Integer, Roots};
+
+/// Number of relations to collect beyond the size of the factor base, so that the
+/// linear algebra yields several independent dependencies to try.
+pub const EXTRA_RELATIONS: usize = 16;
+
+/// Combines `relations` over the factor base `primes` into congruences of squares, and returns
+/// the first proper factor of `n` they yield, or `n` if none does.
+pub fn find_divisor(n: &U512, primes: &[u64], relations: &[Relation]) -> U512 {
+    let rows: Vec<Vec<usize>> = relations.iter().map(Relation::odd_columns).collect();
+    linear_algebra::find_dependencies(&rows, primes.len() + 1)
+        .iter()
+        .map(|dependency| congruent_squares_divisor(n, primes, relations, dependency))
+        .find(|d| d != &U512::ONE && d != n)
+        .unwrap_or(*n)
+}
+
+/// Multiplies the relations in `dependency` into `X² = Y² (mod n)` and returns `gcd(X - Y, n)`.
+fn congruent_squares_divisor(
+    n: &U512,
+    primes: &[u64],
+    relations: &[Relation],
+    dependency: &[usize],
+) -> U512 {
+    let mut x = U512::ONE;
+    let mut y = U512::ONE;
+    let mut exponents = vec![0u32; primes.len() + 1];
+    for relation in dependency.iter().map(|&idx| &relations[idx]) {
+        x = modular::mul_mod(&x, &relation.y, n);
+        y = modular::mul_mod(&y, &relation.square_root_of_rest, n);
+        for &(column, exp) in &relation.exponents {
+            exponents[column] += exp;
+        }
+    }
+    for (&p, &exp) in primes.iter().zip(&exponents[1..]) {
+        for _ in 0..exp / 2 {
+            y = modular::mul_mod(&y, &U512::from(p), n);
+        }
+    }
+    x.abs_diff(y).gcd(n)
+}
+
+/// Divides `value` by the primes in `primes` as often as possible. Returns the exponents in
+/// the column layout of [`Relation`], without the sign column, together with what is left.
+pub fn trial_divide(mut value: U512, primes: &[u64]) -> (Vec<(usize, u32)>, U512) {
+    let mut exponents = vec![];
+    for (idx, &p) in primes.iter().enumerate() {
+        let mut exp = 0;
+        while value.rem_u64(p) == 0 {
+            value /= U512::from(p);
+            exp += 1;
+        }
+        if exp > 0 {
+            exponents.push((idx + 1, exp));
+        }
+    }
+    (exponents, value)
+}
+
+/// Returns `r` if `n = r^k` for some `k >= 2`. Congruences of squares cannot split such `n`,
+/// since their only square roots of unity are `±1`.
+pub fn perfect_power_root(n: &U512) -> Option<U512> {
+    (2..n.bits())
+        .map(|k| (k, n.nth_root(k)))
+        .take_while(|(_, root)| root > &U512::ONE)
+        .find(|(k, root)| &root.pow(*k) == n)
+        .map(|(_, root)| root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perfect_powers() {
+        let p = U512::from(1000000007u64);
+        assert_eq!(perfect_power_root(&p.pow(2)), Some(p));
+        assert_eq!(perfect_power_root(&p.pow(3)), Some(p));
+        assert_eq!(perfect_power_root(&(p * (p + U512::TWO))), None);
+    }
+
+    #[test]
+    fn trial_division_over_primes() {
+        let (exponents, rest) = trial_divide(U512::from(2u32 * 2 * 7 * 7 * 7 * 101), &[2, 3, 7]);
+        assert_eq!(exponents, vec![(1, 2), (3, 3)]);
+        assert_eq!(rest, U512::from(101u8));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::modular;
+use crate::primes;
+use crate::word::Word;
+use bnum::types::U512;
+
+pub struct FactorBasePrime {
+    pub p: u64,
+    /// A square root of `kn` modulo `p`.
+    pub sqrt_kn: u64,
+    /// `log₂(p)`, rounded, as added to a sieve array.
+    pub log: u8,
+}
+
+/// The primes `p` for which `kn` is a square modulo `p`, i.e. the only primes that can divide
+/// values `y² - kn`, where the multiplier `k` is chosen to make these primes as small as possible.
+pub struct FactorBase {
+    pub kn: U512,
+    pub primes: Vec<FactorBasePrime>,
+}
+
+const MULTIPLIERS: [u64; 31] = [
+    1, 3, 5, 7, 11, 13, 15, 17, 19, 21, 23, 29, 31, 33, 35, 37, 39, 41, 43, 47, 51, 53, 55, 57, 59,
+    61, 65, 67, 69, 71, 73,
+];
+
+impl FactorBase {
+    /// Builds a factor base of `size` primes for the odd number `n`.
+    ///
+    /// If a prime encountered along the way divides `n`, that prime is returned as the error.
+    pub fn new(n: &U512, size: usize) -> Result<Self, U512> {
+        let multiplier = knuth_schroeppel_multiplier(n);
+        let kn = n * U512::from(multiplier);
+        let mut primes = vec![FactorBasePrime {
+            p: 2,
+            sqrt_kn: 1,
+            log: 1,
+        }];
+        let mut limit = 16 * size as u64 + 100;
+        while primes.len() < size {
+            let largest_so_far = primes.last().unwrap().p;
+            for p in primes::sieve_of_eratosthenes(limit)
+                .into_iter()
+                .skip_while(|&p| p <= largest_so_far)
+            {
+                if n.rem_u64(p) == 0 {
+                    return Err(U512::from(p));
+                }
+                let kn_mod_p = kn.rem_u64(p);
+                if kn_mod_p == 0 || modular::jacobi_symbol(kn_mod_p, p) == 1 {
+                    let sqrt_kn = modular::sqrt_mod(&U512::from(kn_mod_p), &U512::from(p))
+                        .and_then(|root| u64::from_u512(&root))
+                        .expect("kn is a square modulo p");
+                    primes.push(FactorBasePrime {
+                        p,
+                        sqrt_kn,
+                        log: (p as f64).log2().round() as u8,
+                    });
+                    if primes.len() == size {
+                        break;
+                    }
+                }
+            }
+            limit *= 2;
+        }
+        Ok(Self { kn, primes })
+    }
+
+    pub fn largest_prime(&self) -> u64 {
+        self.primes.last().unwrap().p
+    }
+
+    pub fn prime_values(&self) -> Vec<u64> {
+        self.primes.iter().map(|prime| prime.p).collect()
+    }
+}
+
+/// Chooses the multiplier `k` that maximizes the Knuth–Schroeppel function, i.e. the expected
+/// contribution of small primes to the values `y² - kn`.
+fn knuth_schroeppel_multiplier(n: &U512) -> u64 {
+    let small_primes = primes::sieve_of_eratosthenes(1000);
+    let max_bits = U512::BITS - 2 - 7;
+    MULTIPLIERS
+        .iter()
+        .copied()
+        .filter(|&k| k == 1 || n.bits() < max_bits)
+        .map(|k| {
+            let kn_mod_8 = (n.rem_u64(8) * k) % 8;
+            let ln2 = 2f64.ln();
+            let mut score = -0.5 * (k as f64).ln()
+                + match kn_mod_8 {
+                    1 => 2.0 * ln2,
+                    5 => ln2,
+                    _ => 0.5 * ln2,
+                };
+            for &p in &small_primes[1..] {
+                let ln_p = (p as f64).ln();
+                if k % p == 0 {
+                    score += ln_p / p as f64;
+                } else if modular::jacobi_symbol(n.rem_u64(p) * k % p, p) == 1 {
+                    score += 2.0 * ln_p / (p - 1) as f64;
+                }
+            }
+            (k, score)
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(k, _)| k)
+        .unwrap()
+}
@@ -0,0 +1,103 @@
+/// A row of a matrix over GF(2), packed into 64-bit words.
+#[derive(Clone)]
+struct BitRow(Vec<u64>);
+
+impl BitRow {
+    fn zeros(len: usize) -> Self {
+        Self(vec![0; len.div_ceil(64)])
+    }
+
+    fn get(&self, idx: usize) -> bool {
+        (self.0[idx / 64] >> (idx % 64)) & 1 == 1
+    }
+
+    fn set(&mut self, idx: usize) {
+        self.0[idx / 64] |= 1 << (idx % 64);
+    }
+
+    fn xor_assign(&mut self, other: &BitRow) {
+        for (a, b) in self.0.iter_mut().zip(&other.0) {
+            *a ^= b;
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().enumerate().flat_map(|(word_idx, &word)| {
+            (0..64)
+                .filter(move |bit| (word >> bit) & 1 == 1)
+                .map(move |bit| word_idx * 64 + bit)
+        })
+    }
+}
+
+/// Finds subsets of `rows` in which every column occurs an even number of times, where each
+/// row lists the columns in which it has a one. Uses Gaussian elimination over GF(2), keeping
+/// track of which original rows were added together to produce each row.
+pub fn find_dependencies(rows: &[Vec<usize>], num_columns: usize) -> Vec<Vec<usize>> {
+    let mut matrix: Vec<BitRow> = rows
+        .iter()
+        .map(|columns| {
+            let mut row = BitRow::zeros(num_columns);
+            columns.iter().for_each(|&column| row.set(column));
+            row
+        })
+        .collect();
+    let mut history: Vec<BitRow> = (0..rows.len())
+        .map(|idx| {
+            let mut row = BitRow::zeros(rows.len());
+            row.set(idx);
+            row
+        })
+        .collect();
+    let mut is_pivot = vec![false; rows.len()];
+    for column in 0..num_columns {
+        let Some(pivot) = (0..rows.len()).find(|&idx| !is_pivot[idx] && matrix[idx].get(column))
+        else {
+            continue;
+        };
+        is_pivot[pivot] = true;
+        let (pivot_row, pivot_history) = (matrix[pivot].clone(), history[pivot].clone());
+        for idx in 0..rows.len() {
+            if idx != pivot && matrix[idx].get(column) {
+                matrix[idx].xor_assign(&pivot_row);
+                history[idx].xor_assign(&pivot_history);
+            }
+        }
+    }
+    matrix
+        .iter()
+        .zip(&history)
+        .filter(|(row, _)| row.is_zero())
+        .map(|(_, combination)| combination.ones().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dependencies_sum_to_zero() {
+        let rows = vec![
+            vec![0, 1],
+            vec![1, 2],
+            vec![0, 2],
+            vec![3],
+            vec![0, 3],
+            vec![1],
+        ];
+        let dependencies = find_dependencies(&rows, 4);
+        assert!(!dependencies.is_empty());
+        for dependency in dependencies {
+            let mut counts = [0; 4];
+            for row in &dependency {
+                rows[*row].iter().for_each(|&column| counts[column] += 1);
+            }
+            assert!(counts.iter().all(|count| count % 2 == 0), "{dependency:?}");
+        }
+    }
+}
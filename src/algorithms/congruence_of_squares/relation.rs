@@ -0,0 +1,84 @@
+use crate::modular;
+use bnum::types::U512;
+use std::collections::HashMap;
+
+/// A congruence `y² = (-1)^e₀ · p₁^e₁ ⋯ p_k^e_k · r² (mod n)` whose right-hand side is
+/// smooth over the factor base, apart from the square `r²`.
+pub struct Relation {
+    pub y: U512,
+    /// Pairs `(column, exponent)`. Column `0` stands for the sign `-1`, and column `i + 1`
+    /// for the `i`-th prime of the factor base.
+    pub exponents: Vec<(usize, u32)>,
+    pub square_root_of_rest: U512,
+}
+
+impl Relation {
+    pub fn new(y: U512, exponents: Vec<(usize, u32)>) -> Self {
+        Self {
+            y,
+            exponents,
+            square_root_of_rest: U512::ONE,
+        }
+    }
+
+    /// The columns in which the exponent is odd.
+    pub fn odd_columns(&self) -> Vec<usize> {
+        self.exponents
+            .iter()
+            .filter(|(_, exp)| exp % 2 == 1)
+            .map(|&(column, _)| column)
+            .collect()
+    }
+
+    /// Combines two relations that share the same large prime `q` outside the factor base,
+    /// into one where `q²` becomes part of the square.
+    fn combine(self, other: Relation, large_prime: u64, n: &U512) -> Relation {
+        let mut exponents: HashMap<usize, u32> = self.exponents.into_iter().collect();
+        for (column, exp) in other.exponents {
+            *exponents.entry(column).or_insert(0) += exp;
+        }
+        Relation {
+            y: modular::mul_mod(&self.y, &other.y, n),
+            exponents: exponents.into_iter().collect(),
+            square_root_of_rest: U512::from(large_prime) % n,
+        }
+    }
+}
+
+/// Collects full relations, and pairs up partial relations with the same large prime.
+pub struct Relations<'a> {
+    n: &'a U512,
+    full: Vec<Relation>,
+    partial: HashMap<u64, Relation>,
+}
+
+impl<'a> Relations<'a> {
+    pub fn new(n: &'a U512) -> Self {
+        Self {
+            n,
+            full: vec![],
+            partial: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.full.len()
+    }
+
+    pub fn add_full(&mut self, relation: Relation) {
+        self.full.push(relation);
+    }
+
+    pub fn add_partial(&mut self, large_prime: u64, relation: Relation) {
+        match self.partial.remove(&large_prime) {
+            Some(other) => self.full.push(relation.combine(other, large_prime, self.n)),
+            None => {
+                self.partial.insert(large_prime, relation);
+            }
+        }
+    }
+
+    pub fn into_full(self) -> Vec<Relation> {
+        self.full
+    }
+}
@@ -0,0 +1,20 @@
+use crate::word::Word;
+
+/// The inverse of `a` modulo the prime `p`, by Fermat's little theorem.
+pub fn inverse_mod(a: u64, p: u64) -> u64 {
+    a.pow_mod(p - 2, p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverses_modulo_primes() {
+        for p in [3, 5, 13, 17, 41, 65537, 1000000007] {
+            for a in 1..50.min(p) {
+                assert_eq!(a.mul_mod(inverse_mod(a, p), p), 1, "1/{a} mod {p}");
+            }
+        }
+    }
+}
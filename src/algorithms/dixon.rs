@@ -0,0 +1,114 @@
+use super::congruence_of_squares::{self, Relation, Relations, EXTRA_RELATIONS};
+use crate::budget::{Budget, GaveUp};
+use crate::modular;
+use crate::observer::Event;
+use crate::primes;
+use crate::traits::Factorize;
+use crate::word::Word;
+use bnum::types::U512;
+use num_integer::Integer;
+use rand::Rng;
+use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
+
+/// Dixon's random squares method.
+///
+/// Picks random `x` and keeps those for which `x² mod n` factors over the primes up to a
+/// smoothness bound, apart from at most one large prime, as relations for a congruence of
+/// squares. Since `x² mod n` is as large as `n`, relations are found far more slowly than by
+/// [`Cfrac`](super::Cfrac) or [`QuadraticSieve`](super::QuadraticSieve), which makes this
+/// method practical only up to about 40 bits.
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+pub struct Dixon;
+
+impl Dixon {
+    const LARGE_PRIME_MULTIPLIER: u64 = 16;
+
+    /// `L(n)^(1/2)`, where `L(n) = exp(sqrt(ln n ln ln n))`, which balances the number of
+    /// relations needed against the chance of a random residue being smooth.
+    fn smoothness_bound(n: &U512) -> u64 {
+        let ln_n = n.bits() as f64 * 2f64.ln();
+        let bound = (0.5 * (ln_n * ln_n.ln()).sqrt()).exp();
+        (bound as u64).max(50)
+    }
+}
+
+impl Factorize for Dixon {
+    fn factorize(n: &U512) -> U512 {
+        Self::factorize_with_budget(n, &Budget::unlimited()).unwrap_or(*n)
+    }
+
+    /// Takes a step of the budget per random `x`, and reports every new relation.
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        if let Some(root) = congruence_of_squares::perfect_power_root(n) {
+            return Ok(root);
+        }
+        let primes = primes::sieve_of_eratosthenes(Self::smoothness_bound(n));
+        if let Some(&p) = primes.iter().find(|&&p| n.rem_u64(p) == 0) {
+            return Ok(U512::from(p));
+        }
+        let large_prime_bound = primes.last().unwrap() * Self::LARGE_PRIME_MULTIPLIER;
+        let wanted = primes.len() + 1 + EXTRA_RELATIONS;
+        let mut relations = Relations::new(n);
+        let mut rng = rand::thread_rng();
+        while relations.len() < wanted {
+            budget.spend(1)?;
+            let x = rng.gen_range(U512::TWO..*n);
+            let d = x.gcd(n);
+            if d != U512::ONE {
+                return Ok(d);
+            }
+            let (exponents, rest) =
+                congruence_of_squares::trial_divide(modular::mul_mod(&x, &x, n), &primes);
+            let collected = relations.len();
+            let relation = Relation::new(x, exponents);
+            match u64::try_from(rest) {
+                Ok(1) => relations.add_full(relation),
+                Ok(large_prime) if large_prime < large_prime_bound => {
+                    relations.add_partial(large_prime, relation)
+                }
+                _ => {}
+            }
+            if relations.len() > collected {
+                budget.report(Event::RelationsCollected {
+                    relations: relations.len(),
+                    wanted,
+                });
+            }
+        }
+        Ok(congruence_of_squares::find_divisor(
+            n,
+            &primes,
+            &relations.into_full(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+
+    #[test]
+    fn default() {
+        CheckTestBuilder::default().build::<Dixon>().check_cases()
+    }
+
+    #[test]
+    fn congruence_of_squares() {
+        // Both factors are above the smoothness bound, so the relations have to be combined.
+        CheckTestBuilder::new()
+            .case(4296310871, &[32771, 131101])
+            .build::<Dixon>()
+            .check_cases()
+    }
+
+    #[test]
+    fn perfect_powers() {
+        // No congruence of squares splits a prime power, so these need the root.
+        CheckTestBuilder::new()
+            .case(1000006000009, &[1000003, 1000003])
+            .case(4330747, &[163, 163, 163])
+            .build::<Dixon>()
+            .check_cases()
+    }
+}
@@ -0,0 +1,151 @@
+mod montgomery_curve;
+
+use self::montgomery_curve::MontgomeryCurve;
+use super::two_stage;
+use crate::budget::{Budget, GaveUp};
+use crate::observer::Event;
+use crate::primes;
+use crate::traits::Factorize;
+use bnum::types::U512;
+use num_integer::Integer;
+use rand::Rng;
+use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
+
+/// Lenstra's elliptic curve method, trying up to `CURVES` random curves per call.
+///
+/// Each curve finds a prime factor `p` of `n` when the order of the curve modulo `p` is
+/// `B1`-smooth apart from at most one prime in `(B1, B2]`. Stage two is skipped when `B2 <= B1`.
+#[derive(Default, FactorizationCommand, RecursivePrimeFactorization)]
+pub struct EllipticCurveMethod<
+    const B1: u64 = 50_000,
+    const B2: u64 = 5_000_000,
+    const CURVES: usize = 25,
+>;
+
+impl<const B1: u64, const B2: u64, const CURVES: usize> Factorize
+    for EllipticCurveMethod<B1, B2, CURVES>
+{
+    fn factorize(n: &U512) -> U512 {
+        Self::factorize_with_budget(n, &Budget::unlimited()).unwrap_or(*n)
+    }
+
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        factorize_with_bounds(n, B1, B2, CURVES, budget)
+    }
+}
+
+/// Does the same as [`EllipticCurveMethod`] with bounds and number of curves chosen at run
+/// time, taking a step of the budget per prime of either stage and reporting every curve that
+/// fails.
+pub fn factorize_with_bounds(
+    n: &U512,
+    b1: u64,
+    b2: u64,
+    curves: usize,
+    budget: &Budget,
+) -> Result<U512, GaveUp> {
+    if n.is_even() {
+        return Ok(U512::TWO);
+    }
+    // There is no sigma in `[6, n)` to start from.
+    if n < &U512::SEVEN {
+        return Ok(*n);
+    }
+    let primes = primes::cached_primes_up_to(b1.max(b2));
+    for attempt in 1..=curves {
+        budget.check()?;
+        let (curve, start) = match MontgomeryCurve::suyama(&random_sigma(n), n) {
+            Ok(curve_and_start) => curve_and_start,
+            Err(d) if &d != n => return Ok(d),
+            Err(_) => continue,
+        };
+        if let Some(d) = two_stage::find_factor(&curve, start, n, &primes, b1, budget)? {
+            return Ok(d);
+        }
+        budget.report(Event::CurveDone {
+            curve: attempt,
+            curves,
+        });
+    }
+    Ok(*n)
+}
+
+fn random_sigma(n: &U512) -> U512 {
+    rand::thread_rng().gen_range(U512::SIX..*n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::two_stage::{GiantSteps, StageOneGroup, StageTwoGroup};
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+    use std::sync::{Arc, Mutex};
+
+    type SmallBoundsEcm = EllipticCurveMethod<2_000, 100_000, 50>;
+
+    #[test]
+    fn default() {
+        CheckTestBuilder::default()
+            .build::<EllipticCurveMethod>()
+            .check_cases()
+    }
+
+    #[test]
+    fn reports_every_failed_curve() {
+        let curves = Arc::new(Mutex::new(vec![]));
+        let observed = Arc::clone(&curves);
+        let budget = Budget::unlimited().with_observer(Arc::new(move |event: &Event| {
+            if let Event::CurveDone { curve, curves } = event {
+                observed.lock().unwrap().push((*curve, *curves));
+            }
+        }));
+        // Curves with bounds this small are all but certain not to split a product of two
+        // primes of 61 and 89 bits.
+        let n = (U512::power_of_two(61) - U512::ONE) * (U512::power_of_two(89) - U512::ONE);
+        assert_eq!(factorize_with_bounds(&n, 100, 1_000, 3, &budget), Ok(n));
+        assert_eq!(*curves.lock().unwrap(), [(1, 3), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn composites() {
+        CheckTestBuilder::new()
+            .case(8051, &[83, 97])
+            .case(455459, &[613, 743])
+            .case(
+                10000000019000000030000000057,
+                &[10000000019, 1000000000000000003],
+            )
+            .build::<SmallBoundsEcm>()
+            .check_cases()
+    }
+
+    #[test]
+    fn primes() {
+        CheckTestBuilder::new()
+            .case(3, &[3])
+            .case(1021, &[1021])
+            .case(1000000000000000003, &[1000000000000000003])
+            .build::<SmallBoundsEcm>()
+            .check_cases()
+    }
+
+    #[test]
+    fn small_numbers() {
+        for n in [1u8, 3, 5] {
+            let n = U512::from(n);
+            assert_eq!(EllipticCurveMethod::<100, 1_000, 3>::factorize(&n), n);
+        }
+    }
+
+    #[test]
+    fn giant_steps_match_scalar_multiplication() {
+        let n = U512::from(1000000007u64);
+        let (curve, q) = MontgomeryCurve::suyama(&U512::from(11u8), &n).unwrap();
+        let mut giant_steps = GiantSteps::new(&curve, &q, 210, 0);
+        for m in [1, 2, 3, 7] {
+            let expected = curve.multiply(&q, 210 * m);
+            let actual = giant_steps.advance_to(m);
+            assert_eq!(curve.cross_difference(&actual, &expected), U512::ZERO);
+        }
+    }
+}
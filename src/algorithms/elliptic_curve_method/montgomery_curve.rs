@@ -0,0 +1,149 @@
+use crate::algorithms::two_stage::{StageOneGroup, StageTwoGroup};
+use crate::modular::MontgomeryContext;
+use bnum::types::U512;
+use num_integer::Integer;
+
+/// A point on a Montgomery curve in projective `(X : Z)` coordinates, without its y-coordinate.
+#[derive(Clone, Copy)]
+pub struct Point {
+    pub x: U512,
+    pub z: U512,
+}
+
+impl Point {
+    pub const INFINITY: Point = Point {
+        x: U512::ONE,
+        z: U512::ZERO,
+    };
+}
+
+/// The Montgomery curve `By² = x³ + Ax² + x` over `Z/nZ`, where `(A + 2) / 4 = a24_num / a24_den`.
+///
+/// The denominator is kept separate so that no modular inverse is needed to set up a curve.
+/// All coordinates and constants are kept in Montgomery form, which leaves the gcds with `n`
+/// that the method looks for unchanged.
+pub struct MontgomeryCurve {
+    context: MontgomeryContext<U512>,
+    a24_num: U512,
+    a24_den: U512,
+}
+
+impl MontgomeryCurve {
+    /// Constructs a curve and a starting point from `sigma`, which guarantees that the group
+    /// order of the curve modulo any prime is divisible by 12.
+    ///
+    /// Setting up the curve requires inverting a number modulo `n`. If it is not a unit,
+    /// its common divisor with `n` is returned as the error instead.
+    pub fn suyama(sigma: &U512, n: &U512) -> Result<(Self, Point), U512> {
+        let curve = Self {
+            context: MontgomeryContext::new(*n),
+            a24_num: U512::ZERO,
+            a24_den: U512::ONE,
+        };
+        let constant = |c: u8| curve.context.to_montgomery(U512::from(c));
+        let sigma = curve.context.to_montgomery(*sigma);
+        let u = curve.sub(&curve.mul(&sigma, &sigma), &constant(5));
+        let v = curve.mul(&constant(4), &sigma);
+        let u_cubed = curve.mul(&curve.mul(&u, &u), &u);
+        let v_minus_u = curve.sub(&v, &u);
+        let a24_num = curve.mul(
+            &curve.mul(&curve.mul(&v_minus_u, &v_minus_u), &v_minus_u),
+            &curve.add(&curve.mul(&constant(3), &u), &v),
+        );
+        let a24_den = curve.mul(&curve.mul(&constant(16), &u_cubed), &v);
+        let d = a24_den.gcd(n);
+        if d != U512::ONE {
+            return Err(d);
+        }
+        let start = Point {
+            x: u_cubed,
+            z: curve.mul(&curve.mul(&v, &v), &v),
+        };
+        let curve = Self {
+            a24_num,
+            a24_den,
+            ..curve
+        };
+        Ok((curve, start))
+    }
+
+    pub fn mul(&self, a: &U512, b: &U512) -> U512 {
+        self.context.mul(*a, *b)
+    }
+
+    fn add(&self, a: &U512, b: &U512) -> U512 {
+        self.context.add(*a, *b)
+    }
+
+    fn sub(&self, a: &U512, b: &U512) -> U512 {
+        self.context.sub(*a, *b)
+    }
+}
+
+impl StageOneGroup for MontgomeryCurve {
+    type Element = Point;
+
+    /// Computes `[k]p` with the Montgomery ladder.
+    fn multiply(&self, p: &Point, k: u64) -> Point {
+        if k == 0 {
+            return Point::INFINITY;
+        }
+        let mut low = *p;
+        let mut high = self.double(p);
+        for bit in (0..k.ilog2()).rev() {
+            if (k >> bit) & 1 == 1 {
+                low = self.differential_add(&high, &low, p);
+                high = self.double(&high);
+            } else {
+                high = self.differential_add(&high, &low, p);
+                low = self.double(&low);
+            }
+        }
+        low
+    }
+
+    /// The point at infinity is the one with `Z = 0`.
+    fn identity_residue(&self, p: &Point) -> U512 {
+        p.z
+    }
+}
+
+impl StageTwoGroup for MontgomeryCurve {
+    fn double(&self, p: &Point) -> Point {
+        let sum = self.add(&p.x, &p.z);
+        let diff = self.sub(&p.x, &p.z);
+        let sum_sq = self.mul(&sum, &sum);
+        let diff_sq = self.mul(&diff, &diff);
+        let four_xz = self.sub(&sum_sq, &diff_sq);
+        let scaled_diff_sq = self.mul(&self.a24_den, &diff_sq);
+        Point {
+            x: self.mul(&scaled_diff_sq, &sum_sq),
+            z: self.mul(
+                &four_xz,
+                &self.add(&scaled_diff_sq, &self.mul(&self.a24_num, &four_xz)),
+            ),
+        }
+    }
+
+    /// Returns `p + q` given the difference `p - q`, which must not be the point at infinity.
+    fn differential_add(&self, p: &Point, q: &Point, diff: &Point) -> Point {
+        let u = self.mul(&self.sub(&p.x, &p.z), &self.add(&q.x, &q.z));
+        let v = self.mul(&self.add(&p.x, &p.z), &self.sub(&q.x, &q.z));
+        let sum = self.add(&u, &v);
+        let difference = self.sub(&u, &v);
+        Point {
+            x: self.mul(&diff.z, &self.mul(&sum, &sum)),
+            z: self.mul(&diff.x, &self.mul(&difference, &difference)),
+        }
+    }
+
+    /// Returns `x(p) z(q) - x(q) z(p)`, which vanishes modulo a prime `p | n` exactly when
+    /// `p = ±q` on the curve reduced modulo `p`.
+    fn cross_difference(&self, p: &Point, q: &Point) -> U512 {
+        self.sub(&self.mul(&p.x, &q.z), &self.mul(&q.x, &p.z))
+    }
+
+    fn mul_residues(&self, a: &U512, b: &U512) -> U512 {
+        self.mul(a, b)
+    }
+}
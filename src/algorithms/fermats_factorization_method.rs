@@ -1,6 +1,7 @@
-use crate::traits::{Factorize, PrimeFactorization};
+use crate::budget::{Budget, GaveUp};
+use crate::traits::Factorize;
+use crate::word::{self, Word};
 use bnum::types::U512;
-use num_integer::Roots;
 use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
 
 #[derive(FactorizationCommand, RecursivePrimeFactorization)]
@@ -8,25 +9,59 @@ pub struct FermatsFactorizationMethod;
 
 impl Factorize for FermatsFactorizationMethod {
     fn factorize(n: &U512) -> U512 {
-        let mut a = ceil_sqrt(n);
-        let mut b2 = a * a - n;
-        while !is_perfect_square(&b2) {
-            a += U512::ONE;
-            b2 = a * a - n;
+        word::factorize_narrowest::<Self>(n)
+    }
+
+    fn factorize_word<W: Word>(n: W) -> W {
+        Self::factorize_word_with_budget(n, &Budget::unlimited()).unwrap_or(n)
+    }
+
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        word::factorize_narrowest_with_budget::<Self>(n, budget)
+    }
+
+    /// Takes a step of the budget per candidate `a`, of which there are `(p + q) / 2 - sqrt(n)`
+    /// for `n = pq`, so that numbers with unbalanced factors give up rather than run for ever.
+    fn factorize_word_with_budget<W: Word>(n: W, budget: &Budget) -> Result<W, GaveUp> {
+        let mut a = ceil_sqrt(&n);
+        if let Some(d) = search(n, &mut a, budget)? {
+            return Ok(d);
         }
-        a + b2.sqrt()
+        if W::BITS >= U512::BITS {
+            return Ok(n);
+        }
+        // `a²` no longer fits in `W`, so carry on from the same `a` in `U512`.
+        let d = search(n.to_u512(), &mut a.to_u512(), budget)?.unwrap_or(n.to_u512());
+        Ok(W::from_u512(&d).unwrap())
     }
 }
 
-fn ceil_sqrt(n: &U512) -> U512 {
+/// Tries `a, a + 1, ...` until `a² - n` is a square `b²` and returns `a + b`, or returns `None`
+/// with `a` left at the first candidate whose square does not fit in `W`.
+fn search<W: Word>(n: W, a: &mut W, budget: &Budget) -> Result<Option<W>, GaveUp> {
+    loop {
+        let (a2, overflow) = a.mul_wide(*a);
+        if !overflow.is_zero() {
+            return Ok(None);
+        }
+        let b2 = a2 - n;
+        if is_perfect_square(&b2) {
+            return Ok(Some(*a + b2.sqrt()));
+        }
+        budget.spend(1)?;
+        *a = *a + W::one();
+    }
+}
+
+pub(super) fn ceil_sqrt<W: Word>(n: &W) -> W {
     if is_perfect_square(n) {
         n.sqrt()
     } else {
-        n.sqrt() + U512::ONE
+        n.sqrt() + W::one()
     }
 }
 
-fn is_perfect_square(n: &U512) -> bool {
+pub(super) fn is_perfect_square<W: Word>(n: &W) -> bool {
     let sqrt = n.sqrt();
     sqrt * sqrt == *n
 }
@@ -53,6 +88,24 @@ mod tests {
             .check_cases()
     }
 
+    #[test]
+    fn squares_beyond_u64() {
+        // (2³² − 267)(2³² + 267) fits in a u64, but a = 2³² does not square in one.
+        let n = 18446744073709480327u64;
+        assert_eq!(FermatsFactorizationMethod::factorize_word(n), 4294967563);
+    }
+
+    #[test]
+    fn gives_up_on_unbalanced_factors() {
+        // 3 × 1000000007 needs about 5 × 10⁸ steps.
+        let n = U512::from(3000000021u64);
+        let budget = Budget::unlimited().with_max_steps(10_000);
+        assert_eq!(
+            FermatsFactorizationMethod::factorize_with_budget(&n, &budget),
+            Err(GaveUp)
+        );
+    }
+
     #[test]
     fn primes() {
         CheckTestBuilder::new()
@@ -65,4 +118,15 @@ mod tests {
             .build::<FermatsFactorizationMethod>()
             .check_cases()
     }
+
+    #[test]
+    fn gives_up_near_u64_max() {
+        // 13 × 3889 × 364870227143809, whose first candidate a = 2³² already overflows a u64.
+        let n = U512::from(18446744073709551613u64);
+        let budget = Budget::unlimited().with_max_steps(10_000);
+        assert_eq!(
+            FermatsFactorizationMethod::factorize_with_budget(&n, &budget),
+            Err(GaveUp)
+        );
+    }
 }
@@ -0,0 +1,77 @@
+use super::fermats_factorization_method::{ceil_sqrt, is_perfect_square};
+use super::{Lehman, TrialDivision};
+use crate::budget::{Budget, GaveUp};
+use crate::traits::Factorize;
+use crate::word::Word;
+use bnum::types::U512;
+use num_integer::{Integer, Roots};
+use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
+
+/// Hart's one line factoring algorithm.
+///
+/// For `i = 1, 2, ...` it takes `s = ceil(sqrt(in))` and stops once `s² mod n` is a square
+/// `t²`, since then `gcd(s - t, n)` is a factor of `n`. This is a simpler and in practice
+/// faster variant of [`Lehman`]'s method, to which it falls back if no factor is found after
+/// `n^(1/3)` steps.
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+pub struct HartOneLine;
+
+impl Factorize for HartOneLine {
+    fn factorize(n: &U512) -> U512 {
+        Self::factorize_with_budget(n, &Budget::unlimited()).unwrap_or(*n)
+    }
+
+    /// Takes a step of the budget per trial division and per multiplier `i`.
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        let cbrt = n.cbrt();
+        let limit = u64::from_u512(&cbrt).unwrap_or(u64::MAX);
+        if let Some(d) = TrialDivision::smallest_factor_up_to(n, limit, budget)? {
+            return Ok(d);
+        }
+        let mut i = U512::ONE;
+        while i <= cbrt {
+            budget.spend(1)?;
+            let s = ceil_sqrt(&(i * n));
+            let m = s * s % n;
+            if is_perfect_square(&m) {
+                let d = (s - m.sqrt()).gcd(n);
+                if d != U512::ONE && &d != n {
+                    return Ok(d);
+                }
+            }
+            i += U512::ONE;
+        }
+        Lehman::factorize_with_budget(n, budget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::FermatsFactorizationMethod;
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+
+    #[test]
+    fn default() {
+        CheckTestBuilder::default()
+            .build::<HartOneLine>()
+            .check_cases()
+    }
+
+    #[test]
+    fn factors_near_an_integer_ratio() {
+        // 3000000037 is close to 3 × 1000000007, so that `ceil(sqrt(3n))` squares to a square
+        // modulo n, while Fermat's method would take about 2.7 × 10⁸ steps.
+        let n = U512::from(3000000058000000259u64);
+        let budget = Budget::unlimited().with_max_steps(1_000_000);
+        assert_eq!(
+            FermatsFactorizationMethod::factorize_with_budget(&n, &budget),
+            Err(GaveUp)
+        );
+        let budget = Budget::unlimited().with_max_steps(1_000_000);
+        assert!(matches!(
+            HartOneLine::factorize_with_budget(&n, &budget),
+            Ok(d) if d == U512::from(1000000007u64) || d == U512::from(3000000037u64)
+        ));
+    }
+}
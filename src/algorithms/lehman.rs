@@ -0,0 +1,99 @@
+use super::fermats_factorization_method::{ceil_sqrt, is_perfect_square};
+use super::TrialDivision;
+use crate::budget::{Budget, GaveUp};
+use crate::traits::Factorize;
+use crate::word::Word;
+use bnum::types::U512;
+use num_integer::{Integer, Roots};
+use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
+
+/// Lehman's method, which finds a factor of `n` in `O(n^(1/3))` steps.
+///
+/// After trial division up to `n^(1/3)`, any remaining composite `n` has a representation
+/// `a² - 4kn = b²` with `k <= n^(1/3)` and `a` within `n^(1/6) / (4 sqrt(k))` of
+/// `sqrt(4kn)`, and then `gcd(a + b, n)` is a proper factor. Unlike
+/// [`FermatsFactorizationMethod`](super::FermatsFactorizationMethod), this does not depend on
+/// the factors of `n` being close to each other.
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+pub struct Lehman;
+
+impl Factorize for Lehman {
+    fn factorize(n: &U512) -> U512 {
+        Self::factorize_with_budget(n, &Budget::unlimited()).unwrap_or(*n)
+    }
+
+    /// Takes a step of the budget per trial division and per candidate `a`.
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        let cbrt = n.cbrt();
+        let limit = u64::from_u512(&cbrt).unwrap_or(u64::MAX);
+        if let Some(d) = TrialDivision::smallest_factor_up_to(n, limit, budget)? {
+            return Ok(d);
+        }
+        let mut k = U512::ONE;
+        while k <= cbrt {
+            let four_kn = U512::FOUR * k * n;
+            let slack = (cbrt + U512::ONE).pow(2) + cbrt / (U512::from(16u8) * k) + U512::ONE;
+            let a_max = (four_kn + slack).sqrt();
+            let mut a = ceil_sqrt(&four_kn);
+            while a <= a_max {
+                budget.spend(1)?;
+                let b2 = a * a - four_kn;
+                if is_perfect_square(&b2) {
+                    return Ok((a + b2.sqrt()).gcd(n));
+                }
+                a += U512::ONE;
+            }
+            k += U512::ONE;
+        }
+        Ok(*n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::FermatsFactorizationMethod;
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+
+    #[test]
+    fn default() {
+        CheckTestBuilder::default().build::<Lehman>().check_cases()
+    }
+
+    #[test]
+    fn factors_near_a_fraction() {
+        // 2333333449 is close to 7/3 × 1000000007, so that `a = 7p + 3q` is found just above
+        // `sqrt(4kn)` for k = 21, while Fermat's method would take about 1.4 × 10⁸ steps.
+        let n = U512::from(2333333465333334143u64);
+        let budget = Budget::unlimited().with_max_steps(1_000_000);
+        assert_eq!(
+            FermatsFactorizationMethod::factorize_with_budget(&n, &budget),
+            Err(GaveUp)
+        );
+        let budget = Budget::unlimited().with_max_steps(1_000_000);
+        assert!(matches!(
+            Lehman::factorize_with_budget(&n, &budget),
+            Ok(d) if d == U512::from(1000000007u64) || d == U512::from(2333333449u64)
+        ));
+    }
+
+    #[test]
+    fn near_squares() {
+        // Fermat's case k = 1, where a is the first candidate.
+        CheckTestBuilder::new()
+            .case(1000036000099, &[1000003, 1000033])
+            .case(1000000014000000049, &[1000000007, 1000000007])
+            .build::<Lehman>()
+            .check_cases()
+    }
+
+    #[test]
+    fn factors_just_above_the_cube_root() {
+        // 65537 is just above the cube root 65536.3… of the second n, so trial division misses it.
+        CheckTestBuilder::new()
+            .case(1000031900057, &[100003, 10000019])
+            .case(281479272661007, &[65537, 4294967311])
+            .build::<Lehman>()
+            .check_cases()
+    }
+}
@@ -0,0 +1,146 @@
+use super::two_stage::{self, LucasSequence, Stage, StageOneGroup};
+use crate::budget::{Budget, GaveUp};
+use crate::modular;
+use crate::primes;
+use crate::traits::Factorize;
+use bnum::types::U512;
+use num_integer::Integer;
+use rand::Rng;
+use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
+
+/// Pollard's p − 1 method with stage one bound `B1` and stage two bound `B2`.
+///
+/// Stage one finds a prime factor `p` of `n` whenever every prime power dividing `p − 1`
+/// is at most `B1`. Stage two additionally allows `p − 1` to contain a single prime in
+/// `(B1, B2]`, and is skipped when `B2 <= B1`.
+#[derive(Default, FactorizationCommand, RecursivePrimeFactorization)]
+pub struct PollardsPMinus1<const B1: u64 = 10_000, const B2: u64 = 1_000_000>;
+
+impl<const B1: u64, const B2: u64> Factorize for PollardsPMinus1<B1, B2> {
+    fn factorize(n: &U512) -> U512 {
+        Self::factorize_with_budget(n, &Budget::unlimited()).unwrap_or(*n)
+    }
+
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        factorize_with_bounds(n, B1, B2, budget)
+    }
+}
+
+/// Does the same as [`PollardsPMinus1`] with bounds chosen at run time, taking a step of the
+/// budget per prime of either stage.
+pub fn factorize_with_bounds(n: &U512, b1: u64, b2: u64, budget: &Budget) -> Result<U512, GaveUp> {
+    // There is no base in `[2, n)` to start from.
+    if n <= &U512::TWO {
+        return Ok(*n);
+    }
+    let primes = primes::cached_primes_up_to(b1.max(b2));
+    let (stage_one_primes, stage_two_primes) =
+        primes.split_at(primes.partition_point(|&p| p <= b1));
+    let base = random_integer(n);
+    let d = base.gcd(n);
+    if d != U512::ONE {
+        return Ok(d);
+    }
+    let divisor = match two_stage::stage_one(&Powers { n }, base, n, stage_one_primes, b1, budget)?
+    {
+        Stage::Found(d) => Some(d),
+        Stage::Continue(x) => stage_two(&x, n, stage_two_primes, budget)?,
+        Stage::Failed => None,
+    };
+    Ok(divisor.unwrap_or(*n))
+}
+
+/// Stage two looks for a prime `q` with `x^q = 1` modulo a factor of `n`. Since
+/// `V_k(x + x⁻¹, 1) = x^k + x^(−k)`, which is 2 exactly when `x^k = 1`, this is the
+/// continuation of Williams' p + 1 on the Lucas sequence with `V_1 = x + x⁻¹`.
+fn stage_two(x: &U512, n: &U512, primes: &[u64], budget: &Budget) -> Result<Option<U512>, GaveUp> {
+    let Some(x_inverse) = modular::inverse(x, n) else {
+        return Ok(Some(x.gcd(n)));
+    };
+    let v = modular::add_mod(x, &x_inverse, n);
+    two_stage::stage_two(&LucasSequence { n }, v, n, primes, budget)
+}
+
+/// The units modulo `n` under multiplication.
+struct Powers<'a> {
+    n: &'a U512,
+}
+
+impl StageOneGroup for Powers<'_> {
+    type Element = U512;
+
+    fn multiply(&self, x: &U512, k: u64) -> U512 {
+        modular::pow_mod(x, &U512::from(k), self.n)
+    }
+
+    fn identity_residue(&self, x: &U512) -> U512 {
+        modular::sub_mod(x, &U512::ONE, self.n)
+    }
+}
+
+fn random_integer(bound: &U512) -> U512 {
+    rand::thread_rng().gen_range(U512::TWO..*bound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+
+    #[test]
+    fn default() {
+        CheckTestBuilder::default()
+            .build::<PollardsPMinus1>()
+            .check_cases()
+    }
+
+    #[test]
+    fn composites() {
+        CheckTestBuilder::new()
+            .case(8051, &[83, 97])
+            .case(455459, &[613, 743])
+            .case(1001000000350333000005661, &[1000000000333, 1001000000017])
+            .build::<PollardsPMinus1>()
+            .check_cases()
+    }
+
+    #[test]
+    fn primes() {
+        CheckTestBuilder::new()
+            .case(3, &[3])
+            .case(1021, &[1021])
+            .case(1000000000333, &[1000000000333])
+            .build::<PollardsPMinus1>()
+            .check_cases()
+    }
+
+    #[test]
+    fn small_numbers() {
+        for n in 0..=3u8 {
+            let n = U512::from(n);
+            assert_eq!(PollardsPMinus1::<10, 100>::factorize(&n), n);
+        }
+    }
+
+    #[test]
+    fn gives_up_within_the_stages() {
+        // Neither 1000000931 − 1 = 2 × 5 × 487 × 205339 nor 2000000011 − 1 is smooth enough
+        // for stage one to end early.
+        let n = U512::from(2000001873000010241u128);
+        let budget = Budget::unlimited().with_max_steps(1_000);
+        assert_eq!(
+            factorize_with_bounds(&n, 10_000, 1_000_000, &budget),
+            Err(GaveUp)
+        );
+    }
+
+    #[test]
+    fn stage_two_finds_factor_beyond_b1() {
+        // 1000000933 − 1 = 2² × 3 × 7 × 19 × 83 × 7549 and 2000000011 − 1 = 2 × 3 × 5 × 66666667
+        let n = U512::from(2000001877000010263u128);
+        assert_eq!(
+            PollardsPMinus1::<100, 10_000>::factorize(&n),
+            U512::from(1000000933u128)
+        );
+    }
+}
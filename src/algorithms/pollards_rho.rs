@@ -1,8 +1,10 @@
 mod utils;
 
-use crate::traits::{Factorize, PrimeFactorization};
+use crate::budget::{Budget, GaveUp};
+use crate::modular::MontgomeryContext;
+use crate::traits::Factorize;
+use crate::word::{self, Word};
 use bnum::types::U512;
-use num_integer::Integer;
 use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
 
 #[derive(FactorizationCommand, RecursivePrimeFactorization)]
@@ -10,11 +12,61 @@ pub struct PollardsRho;
 
 impl Factorize for PollardsRho {
     fn factorize(n: &U512) -> U512 {
-        let init = U512::TWO;
-        let pseudorandom_fn = utils::generate_pseudorandom_fn(n);
-        let finished = move |x: &U512, y: &U512| x.abs_diff(*y).gcd(n) != U512::ONE;
-        let (tortoise, hare) = utils::floyds_cycle_detection(init, &pseudorandom_fn, &finished);
-        hare.abs_diff(tortoise).gcd(n)
+        word::factorize_narrowest::<Self>(n)
+    }
+
+    fn factorize_word<W: Word>(n: W) -> W {
+        Self::factorize_word_with_budget(n, &Budget::unlimited()).unwrap_or(n)
+    }
+
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        word::factorize_narrowest_with_budget::<Self>(n, budget)
+    }
+
+    fn factorize_word_with_budget<W: Word>(n: W, budget: &Budget) -> Result<W, GaveUp> {
+        if n.is_even() {
+            return Ok(W::two());
+        }
+        let context = MontgomeryContext::new(n);
+        let init = W::two();
+        let pseudorandom_fn = utils::generate_pseudorandom_fn(&context);
+        let finished = move |x: &W, y: &W| !x.abs_diff(*y).gcd(&n).is_one();
+        let (tortoise, hare) =
+            utils::floyds_cycle_detection(init, &pseudorandom_fn, &finished, budget)?;
+        Ok(hare.abs_diff(tortoise).gcd(&n))
+    }
+}
+
+/// Pollard's rho with Brent's cycle detection, which evaluates the pseudorandom function once
+/// per step instead of three times and takes a gcd only once per batch of steps.
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+pub struct PollardsRhoBrent;
+
+impl PollardsRhoBrent {
+    const BATCH_SIZE: usize = 128;
+}
+
+impl Factorize for PollardsRhoBrent {
+    fn factorize(n: &U512) -> U512 {
+        word::factorize_narrowest::<Self>(n)
+    }
+
+    fn factorize_word<W: Word>(n: W) -> W {
+        Self::factorize_word_with_budget(n, &Budget::unlimited()).unwrap_or(n)
+    }
+
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        word::factorize_narrowest_with_budget::<Self>(n, budget)
+    }
+
+    fn factorize_word_with_budget<W: Word>(n: W, budget: &Budget) -> Result<W, GaveUp> {
+        if n.is_even() {
+            return Ok(W::two());
+        }
+        let context = MontgomeryContext::new(n);
+        let init = W::two();
+        let pseudorandom_fn = utils::generate_pseudorandom_fn(&context);
+        utils::brents_cycle_detection(init, &pseudorandom_fn, &context, Self::BATCH_SIZE, budget)
     }
 }
 
@@ -51,4 +103,39 @@ mod tests {
             .build::<PollardsRho>()
             .check_cases()
     }
+
+    #[test]
+    fn brent_default() {
+        CheckTestBuilder::default()
+            .build::<PollardsRhoBrent>()
+            .check_cases()
+    }
+
+    #[test]
+    fn brent_needs_several_batches() {
+        // Finding 1000000007 takes tens of thousands of steps, many times the batch size.
+        CheckTestBuilder::new()
+            .case(1000000016000000063, &[1000000007, 1000000009])
+            .build::<PollardsRhoBrent>()
+            .check_cases()
+    }
+
+    #[test]
+    fn give_up_after_max_steps() {
+        // Finding 1000000007 takes tens of thousands of steps.
+        let n = U512::from(1000000016000000063u64);
+        let budget = Budget::unlimited().with_max_steps(1000);
+        assert_eq!(PollardsRho::factorize_with_budget(&n, &budget), Err(GaveUp));
+        let budget = Budget::unlimited().with_max_steps(1000);
+        assert_eq!(
+            PollardsRhoBrent::factorize_with_budget(&n, &budget),
+            Err(GaveUp)
+        );
+        // A single attempt may still collide modulo both factors at once.
+        assert!((0..3).any(|_| {
+            let budget = Budget::unlimited().with_max_steps(1_000_000);
+            let d = PollardsRhoBrent::factorize_with_budget(&n, &budget).unwrap();
+            d != n && (n % d).is_zero()
+        }));
+    }
 }
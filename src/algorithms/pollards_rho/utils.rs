@@ -1,25 +1,100 @@
-use bnum::types::U512;
+use crate::budget::{Budget, GaveUp};
+use crate::modular::MontgomeryContext;
+use crate::word::Word;
 use rand::Rng;
 
-pub fn floyds_cycle_detection<F, P>(init: U512, next: &F, finished: &P) -> (U512, U512)
+/// Spends one step of the budget per step of the tortoise.
+pub fn floyds_cycle_detection<W, F, P>(
+    init: W,
+    next: &F,
+    finished: &P,
+    budget: &Budget,
+) -> Result<(W, W), GaveUp>
 where
-    F: Fn(&U512) -> U512 + ?Sized,
-    P: Fn(&U512, &U512) -> bool + ?Sized,
+    W: Word,
+    F: Fn(&W) -> W + ?Sized,
+    P: Fn(&W, &W) -> bool + ?Sized,
 {
+    budget.check()?;
     let mut tortoise = init;
     let mut hare = next(&tortoise);
     while !finished(&tortoise, &hare) {
+        budget.spend(1)?;
         tortoise = next(&tortoise);
         hare = next(&next(&hare));
     }
-    (tortoise, hare)
+    Ok((tortoise, hare))
 }
 
-pub fn generate_pseudorandom_fn(n: &'_ U512) -> impl Fn(&U512) -> U512 + '_ {
-    let c = random_integer(n);
-    move |x| (x.pow(2) + c) % n
+/// Brent's cycle detection, which compares the hare with the tortoise only at powers of two
+/// and accumulates the products of `|x - y| mod n` so that a gcd is taken once per
+/// `batch_size` steps. Returns the first non-trivial gcd found, or `n` if the sequence cycled
+/// modulo every factor of `n` at once. Every step of the hare spends a step of the budget.
+pub fn brents_cycle_detection<W, F>(
+    init: W,
+    next: &F,
+    context: &MontgomeryContext<W>,
+    batch_size: usize,
+    budget: &Budget,
+) -> Result<W, GaveUp>
+where
+    W: Word,
+    F: Fn(&W) -> W + ?Sized,
+{
+    let n = context.modulus();
+    let mut hare = init;
+    let mut power = 1;
+    loop {
+        let tortoise = hare;
+        budget.spend(power as u64)?;
+        for _ in 0..power {
+            hare = next(&hare);
+        }
+        let mut steps = 0;
+        while steps < power {
+            let batch_start = hare;
+            let batch = batch_size.min(power - steps);
+            budget.spend(batch as u64)?;
+            let mut product = context.one();
+            for _ in 0..batch {
+                hare = next(&hare);
+                product = context.mul(product, tortoise.abs_diff(hare));
+            }
+            match product.gcd(&n) {
+                g if g.is_one() => steps += batch,
+                g if g == n => return Ok(backtrack(tortoise, batch_start, next, n)),
+                g => return Ok(g),
+            }
+        }
+        power *= 2;
+    }
+}
+
+/// Repeats the steps of a batch whose accumulated product was divisible by `n` one at a time,
+/// in case a factor was found at some step before the product collapsed to zero.
+fn backtrack<W, F>(tortoise: W, mut hare: W, next: &F, n: W) -> W
+where
+    W: Word,
+    F: Fn(&W) -> W + ?Sized,
+{
+    loop {
+        hare = next(&hare);
+        let g = tortoise.abs_diff(hare).gcd(&n);
+        if !g.is_one() {
+            return g;
+        }
+    }
+}
+
+/// Returns `x -> x² + c` on residues in Montgomery form for a random `c`.
+///
+/// Since the Montgomery form is a bijection modulo every factor of `n`, this is still a
+/// pseudorandom map, and the differences of its values share the same divisors with `n`.
+pub fn generate_pseudorandom_fn<W: Word>(context: &MontgomeryContext<W>) -> impl Fn(&W) -> W + '_ {
+    let c = random_integer(context.modulus());
+    move |x| context.add(context.square(*x), c)
 }
 
-fn random_integer(bound: &U512) -> U512 {
-    rand::thread_rng().gen_range(U512::TWO..*bound)
+fn random_integer<W: Word>(bound: W) -> W {
+    rand::thread_rng().gen_range(W::two()..bound)
 }
@@ -0,0 +1,131 @@
+mod parameters;
+mod polynomial;
+mod sieve;
+
+use self::parameters::{Parameters, THRESHOLD_SLACK};
+use self::polynomial::ASelector;
+use self::sieve::Smoothness;
+use super::congruence_of_squares::{self, FactorBase, Relation, Relations, EXTRA_RELATIONS};
+use super::PollardsRho;
+use crate::budget::{Budget, GaveUp};
+use crate::observer::Event;
+use crate::traits::Factorize;
+use bnum::types::U512;
+use num_integer::Integer;
+use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
+
+/// The self-initializing quadratic sieve.
+///
+/// Collects relations `y² = Q (mod n)` where `Q` factors over a base of small primes, until
+/// a subset of them multiplies to a congruence of squares `X² = Y² (mod n)`, from which
+/// `gcd(X - Y, n)` is likely a proper factor. Numbers of at most 64 bits are handed to
+/// [`PollardsRho`], which is faster in that range.
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+pub struct QuadraticSieve;
+
+impl Factorize for QuadraticSieve {
+    fn factorize(n: &U512) -> U512 {
+        Self::factorize_with_budget(n, &Budget::unlimited()).unwrap_or(*n)
+    }
+
+    /// Takes a step of the budget per sieved polynomial, after which the number of relations is
+    /// reported if it grew.
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        if n.is_even() {
+            return Ok(U512::TWO);
+        }
+        if n.bits() <= 64 {
+            return PollardsRho::factorize_with_budget(n, budget);
+        }
+        if let Some(root) = congruence_of_squares::perfect_power_root(n) {
+            return Ok(root);
+        }
+        let params = Parameters::for_bits(n.bits());
+        let fb = match FactorBase::new(n, params.factor_base_size) {
+            Ok(fb) => fb,
+            Err(p) => return Ok(p),
+        };
+        let relations = collect_relations(n, &fb, &params, budget)?;
+        Ok(congruence_of_squares::find_divisor(
+            n,
+            &fb.prime_values(),
+            &relations,
+        ))
+    }
+}
+
+fn collect_relations(
+    n: &U512,
+    fb: &FactorBase,
+    params: &Parameters,
+    budget: &Budget,
+) -> Result<Vec<Relation>, GaveUp> {
+    let wanted = fb.primes.len() + 1 + EXTRA_RELATIONS;
+    let large_prime_bound = fb.largest_prime() * params.large_prime_multiplier;
+    let threshold = sieve_threshold(fb, params.sieve_radius);
+    let mut relations = Relations::new(n);
+    let mut selector = ASelector::new(fb, params.sieve_radius);
+    let mut rng = rand::thread_rng();
+    while relations.len() < wanted {
+        let Some(mut family) = selector.next_family(&mut rng) else {
+            break;
+        };
+        loop {
+            budget.spend(1)?;
+            let collected = relations.len();
+            let poly = family.current();
+            for x in sieve::sieve(&poly, fb, params.sieve_radius, threshold) {
+                match sieve::trial_divide(x, &poly, fb, n, large_prime_bound) {
+                    Smoothness::Full(relation) => relations.add_full(relation),
+                    Smoothness::Partial(q, relation) => relations.add_partial(q, relation),
+                    Smoothness::Neither => {}
+                }
+            }
+            if relations.len() > collected {
+                budget.report(Event::RelationsCollected {
+                    relations: relations.len(),
+                    wanted,
+                });
+            }
+            if relations.len() >= wanted || !family.advance() {
+                break;
+            }
+        }
+    }
+    Ok(relations.into_full())
+}
+
+/// `|Q(x)| / A` is at most about `radius · sqrt(kn / 2)`. Sieve locations are reported when the
+/// logarithms of their factor base divisors sum to nearly that much, where the slack accounts
+/// for a large prime, for the small primes that are not sieved with and for rounding.
+fn sieve_threshold(fb: &FactorBase, radius: usize) -> u8 {
+    let log_max_value = (radius as f64).log2() + (fb.kn.bits() as f64 - 1.0) / 2.0;
+    let log_slack = THRESHOLD_SLACK * (fb.largest_prime() as f64).log2();
+    (log_max_value - log_slack).max(1.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+
+    #[test]
+    fn default() {
+        CheckTestBuilder::default()
+            .build::<QuadraticSieve>()
+            .check_cases()
+    }
+
+    #[test]
+    fn balanced_semiprimes() {
+        CheckTestBuilder::new()
+            .case(1000000016000000063, &[1000000007, 1000000009])
+            .case(3181868265497317982815477, &[1013841287273, 3138428376749])
+            .case(
+                3192332350532421392570123148989,
+                &[1004747561509963, 3177248169415703],
+            )
+            .build::<QuadraticSieve>()
+            .check_cases()
+    }
+}
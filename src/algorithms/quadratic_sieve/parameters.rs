@@ -0,0 +1,39 @@
+/// Tuning parameters for a number of a given size.
+pub struct Parameters {
+    /// Number of primes in the factor base.
+    pub factor_base_size: usize,
+    /// The sieve interval is `[-sieve_radius, sieve_radius)`.
+    pub sieve_radius: usize,
+    /// Cofactors left after trial division by the factor base are kept as large primes when
+    /// they are below `large_prime_multiplier` times the largest prime in the factor base.
+    pub large_prime_multiplier: u64,
+}
+
+/// Primes below this bound are not sieved with, since they cost the most and contribute the
+/// least. The sieve threshold is lowered to compensate.
+pub const SMALL_PRIME_BOUND: u64 = 30;
+
+/// Sieve locations are kept when their logarithms fall short of the largest values by at most
+/// this many times `log₂` of the largest prime in the factor base.
+pub const THRESHOLD_SLACK: f64 = 2.6;
+
+impl Parameters {
+    pub fn for_bits(bits: u32) -> Self {
+        let (factor_base_size, sieve_radius, large_prime_multiplier) = match bits {
+            0..=80 => (120, 16_384, 32),
+            81..=100 => (200, 32_768, 32),
+            101..=130 => (400, 32_768, 64),
+            131..=160 => (900, 32_768, 64),
+            161..=200 => (3_000, 32_768, 96),
+            201..=230 => (5_000, 65_536, 128),
+            231..=260 => (8_000, 65_536, 128),
+            261..=300 => (15_000, 98_304, 128),
+            _ => (30_000, 131_072, 128),
+        };
+        Self {
+            factor_base_size,
+            sieve_radius,
+            large_prime_multiplier,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use super::parameters::SMALL_PRIME_BOUND;
+use crate::algorithms::congruence_of_squares::{utils, FactorBase};
+use crate::word::Word;
+use bnum::types::{I512, U512};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+/// A sieving polynomial `Q(x) = (Ax + B)² - kn`, where `B² = kn (mod A)` so that `A` divides
+/// every value `Q(x)`.
+pub struct Polynomial<'a> {
+    pub a: U512,
+    pub b: I512,
+    /// Indices into the factor base of the primes whose product is `A`.
+    pub a_factors: &'a [usize],
+    /// For every prime `p` in the factor base, the solutions `x` of `Q(x) = 0 (mod p)`, or
+    /// `None` if `p` divides `A` or is too small to sieve with.
+    pub roots: &'a [Option<(u64, u64)>],
+}
+
+/// The `2^(s-1)` polynomials sharing the same `A = q_1 ⋯ q_s`, whose `B` values are
+/// `±B_1 ± ⋯ ± B_s`.
+///
+/// Consecutive polynomials are visited in Gray code order so that each differs from the
+/// previous one in the sign of a single `B_l`, which lets the sieve roots be updated with one
+/// addition per prime instead of being recomputed. This is what makes the quadratic sieve
+/// self-initializing.
+pub struct PolynomialFamily<'a> {
+    fb: &'a FactorBase,
+    a: U512,
+    a_factors: Vec<usize>,
+    b_terms: Vec<U512>,
+    /// `2 B_l A⁻¹ mod p` for every `l` and every prime `p` in the factor base.
+    b_terms_over_a: Vec<Vec<u64>>,
+    index: usize,
+    b: I512,
+    roots: Vec<Option<(u64, u64)>>,
+}
+
+impl<'a> PolynomialFamily<'a> {
+    fn new(fb: &'a FactorBase, a_factors: Vec<usize>) -> Self {
+        let a = a_factors
+            .iter()
+            .fold(U512::ONE, |a, &idx| a * U512::from(fb.primes[idx].p));
+        let b_terms: Vec<U512> = a_factors
+            .iter()
+            .map(|&idx| {
+                let q = fb.primes[idx].p;
+                let a_over_q = a / U512::from(q);
+                let inverse = utils::inverse_mod(a_over_q.rem_u64(q), q);
+                let gamma = fb.primes[idx].sqrt_kn.mul_mod(inverse, q);
+                a_over_q * U512::from(gamma.min(q - gamma))
+            })
+            .collect();
+        let b = b_terms
+            .iter()
+            .fold(I512::ZERO, |b, term| b + I512::from_bits(*term));
+        let mut roots = vec![None; fb.primes.len()];
+        let mut b_terms_over_a = vec![vec![0; fb.primes.len()]; b_terms.len()];
+        for (idx, prime) in fb.primes.iter().enumerate() {
+            let p = prime.p;
+            if p < SMALL_PRIME_BOUND || a_factors.contains(&idx) {
+                continue;
+            }
+            let a_inverse = utils::inverse_mod(a.rem_u64(p), p);
+            for (l, term) in b_terms.iter().enumerate() {
+                b_terms_over_a[l][idx] = (2 * term.rem_u64(p)).mul_mod(a_inverse, p);
+            }
+            let b_mod_p = b.to_bits().rem_u64(p);
+            let root = |t: u64| a_inverse.mul_mod((t + p - b_mod_p) % p, p);
+            roots[idx] = Some((root(prime.sqrt_kn), root(p - prime.sqrt_kn)));
+        }
+        Self {
+            fb,
+            a,
+            a_factors,
+            b_terms,
+            b_terms_over_a,
+            index: 0,
+            b,
+            roots,
+        }
+    }
+
+    pub fn current(&self) -> Polynomial<'_> {
+        Polynomial {
+            a: self.a,
+            b: self.b,
+            a_factors: &self.a_factors,
+            roots: &self.roots,
+        }
+    }
+
+    /// Switches to the next polynomial of the family, or returns `false` once all have been used.
+    pub fn advance(&mut self) -> bool {
+        self.index += 1;
+        if self.index >> (self.b_terms.len() - 1) != 0 {
+            return false;
+        }
+        let l = self.index.trailing_zeros() as usize;
+        let gray_code = self.index ^ (self.index >> 1);
+        let term = I512::from_bits(self.b_terms[l]) * I512::TWO;
+        let becomes_negative = (gray_code >> l) & 1 == 1;
+        if becomes_negative {
+            self.b -= term;
+        } else {
+            self.b += term;
+        }
+        for (idx, roots) in self.roots.iter_mut().enumerate() {
+            let Some((r1, r2)) = roots else {
+                continue;
+            };
+            let p = self.fb.primes[idx].p;
+            let delta = self.b_terms_over_a[l][idx];
+            // The roots are A⁻¹(±t - B), so they move opposite to B.
+            let shift = |r: u64| {
+                if becomes_negative {
+                    (r + delta) % p
+                } else {
+                    (r + p - delta) % p
+                }
+            };
+            *roots = Some((shift(*r1), shift(*r2)));
+        }
+        true
+    }
+}
+
+/// Picks values of `A` close to `sqrt(2kn) / radius`, which keeps `|Q(x)| / A` small over the
+/// sieve interval, as products of distinct factor base primes of similar size.
+pub struct ASelector<'a> {
+    fb: &'a FactorBase,
+    log_target: f64,
+    num_factors: usize,
+    /// Primes of about the size `A^(1/s)`, from which all but the last factor are drawn.
+    pool: Vec<usize>,
+    /// All primes eligible to divide `A`, from which the last factor is chosen to bring `A`
+    /// closest to the target.
+    eligible: Vec<usize>,
+    used: HashSet<Vec<usize>>,
+}
+
+impl<'a> ASelector<'a> {
+    const PREFERRED_FACTOR_BITS: f64 = 11.0;
+    const MAX_ATTEMPTS: usize = 1000;
+
+    pub fn new(fb: &'a FactorBase, radius: usize) -> Self {
+        let log_target = (fb.kn.bits() as f64 + 1.0) / 2.0 - (radius as f64).log2();
+        let num_factors = (log_target / Self::PREFERRED_FACTOR_BITS).round().max(1.0) as usize;
+        let factor_bits = log_target / num_factors as f64;
+        let eligible: Vec<usize> = (0..fb.primes.len())
+            .filter(|&idx| fb.primes[idx].p > SMALL_PRIME_BOUND && fb.primes[idx].sqrt_kn != 0)
+            .collect();
+        let mut pool: Vec<usize> = eligible
+            .iter()
+            .copied()
+            .filter(|&idx| ((fb.primes[idx].p as f64).log2() - factor_bits).abs() <= 1.0)
+            .collect();
+        if pool.len() < num_factors + 4 {
+            pool.clone_from(&eligible);
+        }
+        Self {
+            fb,
+            log_target,
+            num_factors,
+            pool,
+            eligible,
+            used: HashSet::new(),
+        }
+    }
+
+    /// Returns a family of polynomials for a value of `A` that has not been used before,
+    /// or `None` if no new value can be found.
+    pub fn next_family(&mut self, rng: &mut impl Rng) -> Option<PolynomialFamily<'a>> {
+        for _ in 0..Self::MAX_ATTEMPTS {
+            let mut a_factors: Vec<usize> = self
+                .pool
+                .choose_multiple(rng, self.num_factors - 1)
+                .copied()
+                .collect();
+            let log_a: f64 = a_factors
+                .iter()
+                .map(|&idx| (self.fb.primes[idx].p as f64).log2())
+                .sum();
+            let distance = |idx: usize| {
+                (log_a + (self.fb.primes[idx].p as f64).log2() - self.log_target).abs()
+            };
+            let last = *self
+                .eligible
+                .iter()
+                .filter(|idx| !a_factors.contains(idx))
+                .min_by(|&&i, &&j| distance(i).total_cmp(&distance(j)))?;
+            a_factors.push(last);
+            a_factors.sort_unstable();
+            if self.used.insert(a_factors.clone()) {
+                return Some(PolynomialFamily::new(self.fb, a_factors));
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,78 @@
+use super::polynomial::Polynomial;
+use crate::algorithms::congruence_of_squares::{FactorBase, Relation};
+use crate::word::Word;
+use bnum::types::{I512, U512};
+
+/// Returns the `x` in `[-radius, radius)` for which `Q(x) / A` is likely to factor over the
+/// factor base, by adding `log₂(p)` at every `x` where `p` divides `Q(x)`.
+pub fn sieve(poly: &Polynomial, fb: &FactorBase, radius: usize, threshold: u8) -> Vec<i64> {
+    let mut logs = vec![0u8; 2 * radius];
+    for (prime, roots) in fb.primes.iter().zip(poly.roots) {
+        let Some((r1, r2)) = *roots else {
+            continue;
+        };
+        let p = prime.p as usize;
+        let offset = radius % p;
+        for root in [r1, r2].into_iter().take(if r1 == r2 { 1 } else { 2 }) {
+            let start = (root as usize + offset) % p;
+            for log in logs.iter_mut().skip(start).step_by(p) {
+                *log = log.wrapping_add(prime.log);
+            }
+        }
+    }
+    logs.iter()
+        .enumerate()
+        .filter(|(_, &log)| log >= threshold)
+        .map(|(idx, _)| idx as i64 - radius as i64)
+        .collect()
+}
+
+pub enum Smoothness {
+    Full(Relation),
+    /// The relation contains one prime outside of the factor base, which is given first.
+    Partial(u64, Relation),
+    Neither,
+}
+
+/// Factors `Q(x) = (Ax + B)² - kn` over the factor base by trial division.
+pub fn trial_divide(
+    x: i64,
+    poly: &Polynomial,
+    fb: &FactorBase,
+    n: &U512,
+    large_prime_bound: u64,
+) -> Smoothness {
+    let y = I512::from_bits(poly.a) * I512::from(x) + poly.b;
+    let q = y * y - I512::from_bits(fb.kn);
+    let mut exponents = vec![];
+    if q.is_negative() {
+        exponents.push((0, 1));
+    }
+    let mut rest = q.unsigned_abs() / poly.a;
+    if rest.is_zero() {
+        return Smoothness::Neither;
+    }
+    for (idx, (prime, roots)) in fb.primes.iter().zip(poly.roots).enumerate() {
+        let p = prime.p;
+        let x_mod_p = x.rem_euclid(p as i64) as u64;
+        if matches!(roots, Some((r1, r2)) if x_mod_p != *r1 && x_mod_p != *r2) {
+            continue;
+        }
+        let mut exp = poly.a_factors.iter().filter(|&&a_idx| a_idx == idx).count() as u32;
+        while rest.rem_u64(p) == 0 {
+            rest /= U512::from(p);
+            exp += 1;
+        }
+        if exp > 0 {
+            exponents.push((idx + 1, exp));
+        }
+    }
+    let relation = Relation::new(y.unsigned_abs() % n, exponents);
+    match u64::try_from(rest) {
+        Ok(1) => Smoothness::Full(relation),
+        Ok(large_prime) if large_prime < large_prime_bound => {
+            Smoothness::Partial(large_prime, relation)
+        }
+        _ => Smoothness::Neither,
+    }
+}
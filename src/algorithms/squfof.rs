@@ -0,0 +1,259 @@
+use super::PollardsRhoBrent;
+use crate::budget::{Budget, GaveUp};
+use crate::traits::Factorize;
+use crate::word::{self, Word};
+use bnum::types::U512;
+use num_integer::{Integer, Roots};
+use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
+
+/// Shanks' square forms factorization.
+///
+/// Runs on native `u128` arithmetic, which makes it well suited to cofactors of up to about
+/// 100 bits. Numbers that do not fit in a `u128` are handed to [`PollardsRhoBrent`], and so
+/// are those SQUFOF fails on. SQUFOF is deterministic, so retrying it would only repeat the
+/// same failure, whereas every run of rho starts from a new random point.
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+pub struct Squfof;
+
+impl Squfof {
+    /// Square-free multipliers suggested by Gower and Wagstaff. The continued fraction
+    /// expansions of `sqrt(kn)` for all of them are raced against each other.
+    const MULTIPLIERS: [u128; 16] = [
+        1,
+        3,
+        5,
+        7,
+        11,
+        3 * 5,
+        3 * 7,
+        3 * 11,
+        5 * 7,
+        5 * 11,
+        7 * 11,
+        3 * 5 * 7,
+        3 * 5 * 11,
+        3 * 7 * 11,
+        5 * 7 * 11,
+        3 * 5 * 7 * 11,
+    ];
+
+    /// Number of iterations each multiplier is advanced by before the next one gets a turn.
+    const STEPS_PER_TURN: u64 = 256;
+
+    /// Returns a proper divisor of `n`, or `None` if none was found, for example because `n`
+    /// is prime.
+    pub fn factorize_u64(n: u64) -> Option<u64> {
+        Self::factorize_u128(n as u128).map(|d| d as u64)
+    }
+
+    /// Returns a proper divisor of `n`, or `None` if none was found, for example because `n`
+    /// is prime.
+    pub fn factorize_u128(n: u128) -> Option<u128> {
+        Self::factorize_u128_with_budget(n, &Budget::unlimited()).unwrap_or(None)
+    }
+
+    /// Does the same as [`Squfof::factorize_u128`], taking a step of the budget per iteration
+    /// of any multiplier.
+    pub fn factorize_u128_with_budget(n: u128, budget: &Budget) -> Result<Option<u128>, GaveUp> {
+        if n < 4 {
+            return Ok(None);
+        }
+        if let Some(p) = [2, 3, 5, 7, 11]
+            .into_iter()
+            .find(|p| n.is_multiple_of(*p) && n != *p)
+        {
+            return Ok(Some(p));
+        }
+        let root = n.sqrt();
+        if root * root == n {
+            return Ok(Some(root));
+        }
+        let mut races: Vec<SquareForms> = Self::MULTIPLIERS
+            .iter()
+            .filter_map(|&k| SquareForms::new(n, k))
+            .collect();
+        while !races.is_empty() {
+            for race in races.iter_mut() {
+                budget.spend(Self::STEPS_PER_TURN)?;
+                if let Some(d) = race.advance(Self::STEPS_PER_TURN) {
+                    return Ok(Some(d));
+                }
+            }
+            races.retain(|race| !race.is_exhausted());
+        }
+        Ok(None)
+    }
+}
+
+impl Factorize for Squfof {
+    fn factorize(n: &U512) -> U512 {
+        word::factorize_narrowest::<Self>(n)
+    }
+
+    fn factorize_word<W: Word>(n: W) -> W {
+        Self::factorize_word_with_budget(n, &Budget::unlimited()).unwrap_or(n)
+    }
+
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        word::factorize_narrowest_with_budget::<Self>(n, budget)
+    }
+
+    /// Takes a step of the budget per iteration of SQUFOF, and passes the budget on to
+    /// [`PollardsRhoBrent`] when falling back to it.
+    fn factorize_word_with_budget<W: Word>(n: W, budget: &Budget) -> Result<W, GaveUp> {
+        let divisor = match u128::from_u512(&n.to_u512()) {
+            Some(n_u128) => Self::factorize_u128_with_budget(n_u128, budget)?
+                .and_then(|d| W::from_u512(&U512::from(d))),
+            None => None,
+        };
+        match divisor {
+            Some(d) => Ok(d),
+            None => PollardsRhoBrent::factorize_word_with_budget(n, budget),
+        }
+    }
+}
+
+/// The continued fraction expansion of `sqrt(kn)`, walked through the principal cycle of
+/// reduced quadratic forms until a form with a square leading coefficient shows up.
+struct SquareForms {
+    n: u128,
+    kn: u128,
+    p0: u128,
+    p: u128,
+    q: u128,
+    q_prev: u128,
+    iteration: u64,
+    max_iterations: u64,
+}
+
+impl SquareForms {
+    fn new(n: u128, k: u128) -> Option<Self> {
+        let kn = n.checked_mul(k)?;
+        let p0 = kn.sqrt();
+        let q = kn - p0 * p0;
+        if q == 0 {
+            return None;
+        }
+        Some(Self {
+            n,
+            kn,
+            p0,
+            p: p0,
+            q,
+            q_prev: 1,
+            iteration: 1,
+            max_iterations: 6 * (2 * p0).sqrt() as u64,
+        })
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.iteration >= self.max_iterations
+    }
+
+    /// Runs at most `steps` iterations of the forward cycle, and returns the divisor found
+    /// by the reverse cycle once it yields a proper one.
+    fn advance(&mut self, steps: u64) -> Option<u128> {
+        let last = self.max_iterations.min(self.iteration + steps);
+        while self.iteration < last {
+            let b = (self.p0 + self.p) / self.q;
+            let p_prev = self.p;
+            self.p = b * self.q - self.p;
+            let q = self.q;
+            self.q = self.q_prev + b * p_prev - b * self.p;
+            self.q_prev = q;
+            self.iteration += 1;
+            if self.iteration.is_even() {
+                let r = self.q.sqrt();
+                if r * r == self.q {
+                    if let Some(d) = self.reverse_cycle(r) {
+                        return Some(d);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Starts from the square root of the square form found in the forward cycle and walks
+    /// until two consecutive `P` coincide, at which point `gcd(n, Q)` may split `n`.
+    fn reverse_cycle(&self, r: u128) -> Option<u128> {
+        let b = (self.p0 - self.p) / r;
+        let mut p = b * r + self.p;
+        let mut q_prev = r;
+        let mut q = (self.kn - p * p) / q_prev;
+        for _ in 0..self.max_iterations {
+            let b = (self.p0 + p) / q;
+            let p_prev = p;
+            p = b * q - p;
+            let q_next = q_prev + b * p_prev - b * p;
+            q_prev = q;
+            q = q_next;
+            if p == p_prev {
+                let d = self.n.gcd(&q_prev);
+                return (d != 1 && d != self.n).then_some(d);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+
+    #[test]
+    fn default() {
+        CheckTestBuilder::default().build::<Squfof>().check_cases()
+    }
+
+    #[test]
+    fn large_semiprimes() {
+        // Checked on SQUFOF itself, since `factorize` would fall back to rho on failure.
+        assert!(matches!(
+            Squfof::factorize_u128(1000000016000000063),
+            Some(1000000007 | 1000000009)
+        ));
+        assert!(matches!(
+            Squfof::factorize_u128(618970020197248618211718991),
+            Some(17592186056779 | 35184372095629)
+        ));
+    }
+
+    #[test]
+    fn perfect_squares() {
+        // The continued fraction expansion of the square root of a square is empty.
+        assert_eq!(
+            Squfof::factorize_u128(1000000007 * 1000000007),
+            Some(1000000007)
+        );
+        CheckTestBuilder::new()
+            .case(1000000014000000049, &[1000000007, 1000000007])
+            .case(121 * 121, &[11; 4])
+            .build::<Squfof>()
+            .check_cases()
+    }
+
+    #[test]
+    fn gives_up_when_the_budget_runs_out() {
+        // About n^(1/4) iterations are needed, far more than the one turn the budget allows.
+        let n = 618970020197248618211718991;
+        let budget = Budget::unlimited().with_max_steps(Squfof::STEPS_PER_TURN);
+        assert_eq!(Squfof::factorize_u128_with_budget(n, &budget), Err(GaveUp));
+        let budget = Budget::unlimited().with_max_steps(Squfof::STEPS_PER_TURN);
+        assert_eq!(
+            Squfof::factorize_with_budget(&U512::from(n), &budget),
+            Err(GaveUp)
+        );
+    }
+
+    #[test]
+    fn native_integers() {
+        assert_eq!(Squfof::factorize_u64(1000000007), None);
+        assert!(matches!(Squfof::factorize_u64(8051), Some(83 | 97)));
+        assert!(matches!(
+            Squfof::factorize_u128(3000000000000148000000000001369),
+            Some(1000000000000037 | 3000000000000037)
+        ));
+    }
+}
@@ -1,63 +1,131 @@
+use crate::budget::{Budget, GaveUp};
+use crate::primes;
+use crate::word::Word;
 use crate::PrimeFactorization;
 use bnum::types::U512;
-use num_integer::Integer;
 use rustic_factors_derive::FactorizationCommand;
+use std::sync::OnceLock;
 
 #[derive(FactorizationCommand)]
 pub struct TrialDivision;
 
-impl PrimeFactorization for TrialDivision {
-    fn prime_factorization(n: &U512) -> Vec<U512> {
-        if n <= &U512::ONE {
-            return vec![*n];
+impl TrialDivision {
+    /// Divides out every prime factor `p <= limit` of `n`, returning those factors in increasing
+    /// order together with the remaining cofactor, which has no prime factors up to `limit`.
+    pub fn strip_small_factors(n: &U512, limit: u64) -> (Vec<U512>, U512) {
+        if let Some(n) = u64::from_u512(n) {
+            let (factors, rest) = strip_small_factors(n, limit);
+            (factors, rest.to_u512())
+        } else if let Some(n) = u128::from_u512(n) {
+            let (factors, rest) = strip_small_factors(n, limit);
+            (factors, rest.to_u512())
+        } else {
+            strip_small_factors(*n, limit)
         }
-        trial_div(*n)
+    }
+
+    /// Returns the smallest prime factor `p <= limit` of `n` with `p < n`, if any, taking a
+    /// step of the budget per candidate divisor.
+    pub fn smallest_factor_up_to(
+        n: &U512,
+        limit: u64,
+        budget: &Budget,
+    ) -> Result<Option<U512>, GaveUp> {
+        for d in DivisorCandidates::new().take_while(|&d| d <= limit) {
+            budget.spend(1)?;
+            if U512::from(d) >= *n {
+                break;
+            }
+            if n.rem_u64(d) == 0 {
+                return Ok(Some(U512::from(d)));
+            }
+        }
+        Ok(None)
     }
 }
 
-fn trial_div(mut n: U512) -> Vec<U512> {
+fn strip_small_factors<W: Word>(mut n: W, limit: u64) -> (Vec<U512>, W) {
     let mut factors = vec![];
-    let divisors = DivisorCandidates::new();
-    for d in divisors {
-        if n < d.pow(2) {
+    let mut sqrt = n.sqrt();
+    for d in DivisorCandidates::new().take_while(|&d| d <= limit) {
+        if W::from(d) > sqrt {
+            if !n.is_one() && n <= W::from(limit) {
+                factors.push(n.to_u512());
+                n = W::one();
+            }
             break;
         }
-        while n.is_multiple_of(&d) {
-            n /= &d;
-            factors.push(d);
+        if n.rem_u64(d) == 0 {
+            while n.rem_u64(d) == 0 {
+                n = n / W::from(d);
+                factors.push(U512::from(d));
+            }
+            sqrt = n.sqrt();
         }
     }
-    if is_still_undivided(&n) {
-        factors.push(n);
+    (factors, n)
+}
+
+impl PrimeFactorization for TrialDivision {
+    fn prime_factorization(n: &U512) -> Vec<U512> {
+        if n <= &U512::ONE {
+            return vec![*n];
+        }
+        let (mut factors, rest) = Self::strip_small_factors(n, u64::MAX);
+        if !rest.is_one() {
+            factors.push(rest);
+        }
+        factors
     }
-    factors
 }
 
-fn is_still_undivided(n: &U512) -> bool {
-    !n.is_one()
+/// Primes below this bound are tried from a precomputed table.
+const PRIME_TABLE_LIMIT: u64 = 1 << 16;
+
+fn prime_table() -> &'static [u64] {
+    static PRIMES: OnceLock<Vec<u64>> = OnceLock::new();
+    PRIMES.get_or_init(|| primes::sieve_of_eratosthenes(PRIME_TABLE_LIMIT))
 }
 
+/// Yields the primes below [`PRIME_TABLE_LIMIT`], followed by the numbers above it that are
+/// coprime to 30.
 struct DivisorCandidates {
-    current: U512,
+    table_idx: usize,
+    wheel_base: u64,
+    wheel_idx: usize,
 }
 
 impl DivisorCandidates {
+    const WHEEL: [u64; 8] = [1, 7, 11, 13, 17, 19, 23, 29];
+
     fn new() -> Self {
-        DivisorCandidates { current: U512::TWO }
+        DivisorCandidates {
+            table_idx: 0,
+            wheel_base: PRIME_TABLE_LIMIT / 30 * 30,
+            wheel_idx: 0,
+        }
     }
 }
 
 impl Iterator for DivisorCandidates {
-    type Item = U512;
+    type Item = u64;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let output = self.current;
-        self.current = if self.current == U512::TWO {
-            self.current + U512::ONE
-        } else {
-            self.current + U512::TWO
-        };
-        Some(output)
+        if let Some(&p) = prime_table().get(self.table_idx) {
+            self.table_idx += 1;
+            return Some(p);
+        }
+        loop {
+            let candidate = self.wheel_base.checked_add(Self::WHEEL[self.wheel_idx])?;
+            self.wheel_idx += 1;
+            if self.wheel_idx == Self::WHEEL.len() {
+                self.wheel_idx = 0;
+                self.wheel_base = self.wheel_base.checked_add(30)?;
+            }
+            if candidate > PRIME_TABLE_LIMIT {
+                return Some(candidate);
+            }
+        }
     }
 }
 
@@ -85,7 +153,57 @@ mod tests {
     fn composite() {
         CheckTestBuilder::new()
             .case(12, &[2, 2, 3])
+            .case(281479272661007, &[65537, 4294967311])
+            .case(1000036000099, &[1000003, 1000033])
             .build::<TrialDivision>()
             .check_cases()
     }
+
+    #[test]
+    fn strip_small_factors() {
+        let n = U512::from(2u64 * 3 * 3 * 101 * 1000003 * 1000033);
+        let (factors, rest) = TrialDivision::strip_small_factors(&n, 1000);
+        assert_eq!(factors, [2u8, 3, 3, 101].map(U512::from));
+        assert_eq!(rest, U512::from(1000003u64 * 1000033));
+    }
+
+    #[test]
+    fn smallest_factor_up_to() {
+        let budget = Budget::unlimited();
+        let n = U512::from(101u64 * 1000003);
+        let smallest = |n: &U512, limit| TrialDivision::smallest_factor_up_to(n, limit, &budget);
+        assert_eq!(smallest(&n, 100), Ok(None));
+        assert_eq!(smallest(&n, 101), Ok(Some(U512::from(101u8))));
+        assert_eq!(smallest(&U512::from(101u8), 1000), Ok(None));
+        assert_eq!(
+            smallest(&U512::from(65537u64 * 65537), 1 << 20),
+            Ok(Some(U512::from(65537u32)))
+        );
+        let budget = Budget::unlimited().with_max_steps(10);
+        assert_eq!(
+            TrialDivision::smallest_factor_up_to(&n, 1000, &budget),
+            Err(GaveUp)
+        );
+    }
+
+    #[test]
+    fn strip_small_factors_leaves_no_cofactor_below_limit() {
+        let (factors, rest) = TrialDivision::strip_small_factors(&U512::from(2u16 * 997), 1000);
+        assert_eq!(factors, [2u16, 997].map(U512::from));
+        assert_eq!(rest, U512::ONE);
+    }
+
+    #[test]
+    fn candidates_cover_primes_past_the_table() {
+        let candidates: Vec<u64> = DivisorCandidates::new()
+            .skip_while(|&d| d < PRIME_TABLE_LIMIT - 100)
+            .take_while(|&d| d < PRIME_TABLE_LIMIT + 1000)
+            .collect();
+        let primes = primes::sieve_of_eratosthenes(PRIME_TABLE_LIMIT + 1000);
+        let expected = primes.iter().filter(|&&p| p >= PRIME_TABLE_LIMIT - 100);
+        assert!(expected.into_iter().all(|p| candidates.contains(p)));
+        assert!(candidates
+            .iter()
+            .all(|d| d % 2 != 0 && d % 3 != 0 && d % 5 != 0));
+    }
 }
@@ -0,0 +1,268 @@
+use crate::budget::{Budget, GaveUp};
+use crate::lucas;
+use crate::modular;
+use crate::primes;
+use bnum::types::U512;
+use num_integer::Integer;
+
+/// The group of a method that finds a prime factor `p` of `n` when the order of the group
+/// modulo `p` is smooth, such as the units modulo `n` for Pollard's p − 1 or a curve for the
+/// elliptic curve method. Elements are written additively.
+pub(crate) trait StageOneGroup {
+    type Element: Copy;
+
+    /// Returns `[k]x`.
+    fn multiply(&self, x: &Self::Element, k: u64) -> Self::Element;
+
+    /// Returns a residue that vanishes modulo a prime factor of `n` exactly when `x` is the
+    /// identity modulo that factor.
+    fn identity_residue(&self, x: &Self::Element) -> U512;
+}
+
+/// A group whose elements are only known up to sign, like the x-coordinates of points on a
+/// Montgomery curve or the terms `V_k = x^k + x^(−k)` of a Lucas sequence, which is all that
+/// the baby-step giant-step continuation needs.
+pub(crate) trait StageTwoGroup: StageOneGroup {
+    fn double(&self, x: &Self::Element) -> Self::Element;
+
+    /// Returns `x + y` given the difference `x − y`, which must not be the identity.
+    fn differential_add(
+        &self,
+        x: &Self::Element,
+        y: &Self::Element,
+        diff: &Self::Element,
+    ) -> Self::Element;
+
+    /// Returns a residue that vanishes modulo a prime factor of `n` exactly when `x = ±y`
+    /// modulo that factor.
+    fn cross_difference(&self, x: &Self::Element, y: &Self::Element) -> U512;
+
+    /// Multiplies two residues returned by [`StageTwoGroup::cross_difference`].
+    fn mul_residues(&self, a: &U512, b: &U512) -> U512;
+}
+
+pub(crate) enum Stage<E> {
+    Found(U512),
+    Continue(E),
+    /// Every prime factor of `n` was found at once, so the starting element is of no use.
+    Failed,
+}
+
+const BATCH_SIZE: usize = 64;
+
+/// Runs stage one with the primes up to `b1` and then stage two with the rest of `primes`.
+pub(crate) fn find_factor<G: StageTwoGroup>(
+    group: &G,
+    start: G::Element,
+    n: &U512,
+    primes: &[u64],
+    b1: u64,
+    budget: &Budget,
+) -> Result<Option<U512>, GaveUp> {
+    let (stage_one_primes, stage_two_primes) =
+        primes.split_at(primes.partition_point(|&p| p <= b1));
+    match stage_one(group, start, n, stage_one_primes, b1, budget)? {
+        Stage::Found(d) => Ok(Some(d)),
+        Stage::Continue(x) => stage_two(group, x, n, stage_two_primes, budget),
+        Stage::Failed => Ok(None),
+    }
+}
+
+/// Multiplies `start` by the largest power `q^k <= bound` of every prime `q` in `primes`,
+/// checking for a factor after every batch of primes so that small factors are found early.
+/// Takes a step of the budget per prime.
+pub(crate) fn stage_one<G: StageOneGroup>(
+    group: &G,
+    start: G::Element,
+    n: &U512,
+    primes: &[u64],
+    bound: u64,
+    budget: &Budget,
+) -> Result<Stage<G::Element>, GaveUp> {
+    let mut x = start;
+    for batch in primes.chunks(BATCH_SIZE) {
+        budget.spend(batch.len() as u64)?;
+        let checkpoint = x;
+        for &q in batch {
+            x = group.multiply(&x, primes::largest_power_not_exceeding(q, bound));
+        }
+        match group.identity_residue(&x).gcd(n) {
+            d if d == U512::ONE => continue,
+            d if &d != n => return Ok(Stage::Found(d)),
+            _ => return Ok(stage_one_backtrack(group, checkpoint, n, batch, bound)),
+        }
+    }
+    Ok(Stage::Continue(x))
+}
+
+/// Every prime factor of `n` was found within the same batch, so replay it one prime at a time
+/// and stop as soon as the first of them appears.
+fn stage_one_backtrack<G: StageOneGroup>(
+    group: &G,
+    mut x: G::Element,
+    n: &U512,
+    batch: &[u64],
+    bound: u64,
+) -> Stage<G::Element> {
+    for &q in batch {
+        let mut power = 1;
+        while power <= bound / q {
+            x = group.multiply(&x, q);
+            power *= q;
+            match group.identity_residue(&x).gcd(n) {
+                d if d == U512::ONE => continue,
+                d if &d != n => return Stage::Found(d),
+                _ => return Stage::Failed,
+            }
+        }
+    }
+    Stage::Failed
+}
+
+/// The baby-step giant-step continuation: every prime `q` in `primes` is written as
+/// `mD ± j` with `j < D/2`, and `[q]x` vanishes modulo a prime factor of `n` exactly when
+/// `[mD]x = ±[j]x` there. Takes a step of the budget per prime.
+pub(crate) fn stage_two<G: StageTwoGroup>(
+    group: &G,
+    x: G::Element,
+    n: &U512,
+    primes: &[u64],
+    budget: &Budget,
+) -> Result<Option<U512>, GaveUp> {
+    const D: u64 = 210;
+    let Some(&first) = primes.first() else {
+        return Ok(None);
+    };
+    let baby_steps = odd_multiples(group, &x, D / 2);
+    let mut giant_steps = GiantSteps::new(group, &x, D, (first + D / 2) / D);
+    let mut acc = U512::ONE;
+    for batch in primes.chunks(BATCH_SIZE) {
+        budget.spend(batch.len() as u64)?;
+        for &q in batch {
+            let m = (q + D / 2) / D;
+            let j = q.abs_diff(m * D);
+            let giant_step = giant_steps.advance_to(m);
+            let difference = group.cross_difference(&giant_step, &baby_steps[(j / 2) as usize]);
+            acc = group.mul_residues(&acc, &difference);
+        }
+    }
+    match acc.gcd(n) {
+        d if d == U512::ONE || &d == n => Ok(None),
+        d => Ok(Some(d)),
+    }
+}
+
+/// Returns `[1]x, [3]x, [5]x, ...` up to `[bound]x`.
+fn odd_multiples<G: StageTwoGroup>(group: &G, x: &G::Element, bound: u64) -> Vec<G::Element> {
+    let double = group.double(x);
+    let mut multiples = vec![*x, group.multiply(x, 3)];
+    for j in (5..=bound).step_by(2) {
+        let idx = (j / 2) as usize;
+        let next = group.differential_add(&multiples[idx - 1], &double, &multiples[idx - 2]);
+        multiples.push(next);
+    }
+    multiples
+}
+
+/// Walks the multiples `[mD]x` for increasing `m`.
+pub(super) struct GiantSteps<'a, G: StageTwoGroup> {
+    group: &'a G,
+    step: G::Element,
+    m: u64,
+    prev: G::Element,
+    current: G::Element,
+}
+
+impl<'a, G: StageTwoGroup> GiantSteps<'a, G> {
+    pub(super) fn new(group: &'a G, x: &G::Element, d: u64, m: u64) -> Self {
+        let step = group.multiply(x, d);
+        Self {
+            group,
+            step,
+            m,
+            // Only used for m >= 2, below which the next step needs no difference.
+            prev: group.multiply(&step, m.saturating_sub(1)),
+            current: group.multiply(&step, m),
+        }
+    }
+
+    pub(super) fn advance_to(&mut self, m: u64) -> G::Element {
+        while self.m < m {
+            // The difference of [D]x and [0]x would be the identity.
+            let next = match self.m {
+                0 => self.step,
+                1 => self.group.double(&self.step),
+                _ => self
+                    .group
+                    .differential_add(&self.current, &self.step, &self.prev),
+            };
+            self.prev = self.current;
+            self.current = next;
+            self.m += 1;
+        }
+        self.current
+    }
+}
+
+/// The terms `V_k(v, 1)` of the Lucas sequence with `V_1 = v`, where `V_k` is `[k]V_1`. With
+/// `v = x + x⁻¹` these are `x^k + x^(−k)`, the units modulo `n` up to inversion.
+pub(crate) struct LucasSequence<'a> {
+    pub n: &'a U512,
+}
+
+impl StageOneGroup for LucasSequence<'_> {
+    type Element = U512;
+
+    fn multiply(&self, v: &U512, k: u64) -> U512 {
+        lucas::lucas_v(v, k, self.n)
+    }
+
+    /// `V_k = 2` is the identity.
+    fn identity_residue(&self, v: &U512) -> U512 {
+        modular::sub_mod(v, &U512::TWO, self.n)
+    }
+}
+
+impl StageTwoGroup for LucasSequence<'_> {
+    fn double(&self, v: &U512) -> U512 {
+        lucas::double(v, self.n)
+    }
+
+    fn differential_add(&self, v_i: &U512, v_j: &U512, v_diff: &U512) -> U512 {
+        lucas::differential_add(v_i, v_j, v_diff, self.n)
+    }
+
+    fn cross_difference(&self, v_i: &U512, v_j: &U512) -> U512 {
+        modular::sub_mod(v_i, v_j, self.n)
+    }
+
+    fn mul_residues(&self, a: &U512, b: &U512) -> U512 {
+        modular::mul_mod(a, b, self.n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn giant_steps_match_lucas_sequence() {
+        let n = U512::from(1000000007u64);
+        let v = U512::from(11u8);
+        let group = LucasSequence { n: &n };
+        let mut giant_steps = GiantSteps::new(&group, &v, 210, 0);
+        for m in [1, 2, 3, 7] {
+            assert_eq!(giant_steps.advance_to(m), lucas::lucas_v(&v, 210 * m, &n));
+        }
+    }
+
+    #[test]
+    fn odd_multiples_match_lucas_sequence() {
+        let n = U512::from(1000000007u64);
+        let v = U512::from(11u8);
+        let multiples = odd_multiples(&LucasSequence { n: &n }, &v, 105);
+        for (i, multiple) in multiples.iter().enumerate() {
+            assert_eq!(*multiple, lucas::lucas_v(&v, 2 * i as u64 + 1, &n));
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::two_stage::{self, LucasSequence};
+use crate::budget::{Budget, GaveUp};
+use crate::primes;
+use crate::traits::Factorize;
+use bnum::types::U512;
+use rand::Rng;
+use rustic_factors_derive::{FactorizationCommand, RecursivePrimeFactorization};
+
+/// Williams' p + 1 method with stage one bound `B1` and stage two bound `B2`, trying up to
+/// `STARTS` random starting values per call.
+///
+/// With the starting value `A`, it works with the Lucas sequence `V_k(A, 1)` and finds a prime
+/// factor `p` of `n` when `p − (D/p)` is `B1`-smooth apart from at most one prime in
+/// `(B1, B2]`, where `D = A² − 4`. This is `p + 1` for about half of the starting values and
+/// `p − 1` for the others, which is why several are tried. Stage two is skipped when
+/// `B2 <= B1`.
+#[derive(Default, FactorizationCommand, RecursivePrimeFactorization)]
+pub struct WilliamsPPlus1<
+    const B1: u64 = 10_000,
+    const B2: u64 = 1_000_000,
+    const STARTS: usize = 3,
+>;
+
+impl<const B1: u64, const B2: u64, const STARTS: usize> Factorize
+    for WilliamsPPlus1<B1, B2, STARTS>
+{
+    fn factorize(n: &U512) -> U512 {
+        Self::factorize_with_budget(n, &Budget::unlimited()).unwrap_or(*n)
+    }
+
+    /// Takes a step of the budget per prime of either stage.
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        // There is no starting value in `[3, n)` to draw.
+        if n <= &U512::THREE {
+            return Ok(*n);
+        }
+        let primes = primes::cached_primes_up_to(B1.max(B2));
+        let group = LucasSequence { n };
+        for _ in 0..STARTS {
+            let start = random_start(n);
+            if let Some(d) = two_stage::find_factor(&group, start, n, &primes, B1, budget)? {
+                return Ok(d);
+            }
+        }
+        Ok(*n)
+    }
+}
+
+fn random_start(n: &U512) -> U512 {
+    rand::thread_rng().gen_range(U512::THREE..*n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::two_stage::Stage;
+    use crate::algorithms::PollardsPMinus1;
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+
+    #[test]
+    fn default() {
+        CheckTestBuilder::default()
+            .build::<WilliamsPPlus1>()
+            .check_cases()
+    }
+
+    #[test]
+    fn p_plus_1_smooth_but_p_minus_1_not() {
+        // 1000000931 − 1 = 2 × 5 × 487 × 205339 and 2000000011 − 1 = 2 × 3 × 5 × 66666667
+        // are beyond the reach of Pollard's p − 1, while 1000000931 + 1 is 10⁴-smooth.
+        let n = U512::from(2000001873000010241u128);
+        assert_eq!(PollardsPMinus1::<100, 10_000>::factorize(&n), n);
+        assert_eq!(
+            WilliamsPPlus1::<100, 10_000, 20>::factorize(&n),
+            U512::from(1000000931u64)
+        );
+    }
+
+    #[test]
+    fn small_numbers() {
+        for n in 0..=3u8 {
+            let n = U512::from(n);
+            assert_eq!(WilliamsPPlus1::<10, 100>::factorize(&n), n);
+        }
+    }
+
+    #[test]
+    fn gives_up_within_the_stages() {
+        // Both p − 1 and p + 1 have a prime factor above 10⁶ for either factor of n.
+        let n = U512::from(1000000000100000000002379u128);
+        let budget = Budget::unlimited().with_max_steps(1_000);
+        assert_eq!(
+            WilliamsPPlus1::<10_000, 1_000_000>::factorize_with_budget(&n, &budget),
+            Err(GaveUp)
+        );
+    }
+
+    #[test]
+    fn stage_two_finds_factor_beyond_b1() {
+        // 1000000931 + 1 = 2² × 3 × 7 × 19 × 83 × 7549 and 2000000011 + 1 = 2² × 500000003,
+        // while 6² − 4 is a quadratic non-residue modulo both.
+        let n = U512::from(2000001873000010241u128);
+        let primes = primes::sieve_of_eratosthenes(10_000);
+        let group = LucasSequence { n: &n };
+        let budget = Budget::unlimited();
+        let (stage_one_primes, stage_two_primes) =
+            primes.split_at(primes.partition_point(|&p| p <= 100));
+        let Ok(Stage::Continue(v)) =
+            two_stage::stage_one(&group, U512::SIX, &n, stage_one_primes, 100, &budget)
+        else {
+            panic!("stage one should not find a factor");
+        };
+        assert_eq!(
+            two_stage::stage_two(&group, v, &n, stage_two_primes, &budget),
+            Ok(Some(U512::from(1000000931u64)))
+        );
+    }
+}
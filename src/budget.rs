@@ -0,0 +1,195 @@
+use crate::observer::{Event, FactorizationObserver};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Limits how long a factorization may run, by a wall-clock deadline, by a number of steps
+/// and by cancellation tokens. Algorithms call [`Budget::spend`] from their main loops, where
+/// a step is one iteration of the loop, and stop with [`GaveUp`] once any limit is reached.
+///
+/// Clones share their step counters and tokens, so a budget can be handed to several threads.
+/// A budget narrowed with [`Budget::with_max_steps`] or [`Budget::with_cancellation`] also
+/// keeps the limits of the budget it was made from.
+///
+/// Since it is passed wherever a factorization runs, the budget also carries the
+/// [`FactorizationObserver`] that algorithms report their progress to.
+#[derive(Clone, Default)]
+pub struct Budget {
+    deadline: Option<Instant>,
+    step_limits: Vec<(Arc<AtomicU64>, u64)>,
+    tokens: Vec<CancellationToken>,
+    observer: Option<Arc<dyn FactorizationObserver>>,
+}
+
+impl fmt::Debug for Budget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Budget")
+            .field("deadline", &self.deadline)
+            .field("step_limits", &self.step_limits)
+            .field("tokens", &self.tokens)
+            .field("observer", &self.observer.is_some())
+            .finish()
+    }
+}
+
+impl Budget {
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(self.deadline.map_or(deadline, |d| d.min(deadline)));
+        self
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.with_deadline(deadline),
+            None => self,
+        }
+    }
+
+    /// Allows `max_steps` more steps, counted from now.
+    pub fn with_max_steps(mut self, max_steps: u64) -> Self {
+        self.step_limits
+            .push((Arc::new(AtomicU64::new(0)), max_steps));
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.tokens.push(token);
+        self
+    }
+
+    /// Sends the events of the factorization to `observer`, in place of any set before.
+    pub fn with_observer(mut self, observer: Arc<dyn FactorizationObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Passes `event` on to the observer, if there is one.
+    pub fn report(&self, event: Event) {
+        if let Some(observer) = &self.observer {
+            observer.observe(&event);
+        }
+    }
+
+    /// Counts `steps` against the step limits, and fails if any limit has been reached.
+    pub fn spend(&self, steps: u64) -> Result<(), GaveUp> {
+        let mut exhausted = false;
+        for (spent, max_steps) in &self.step_limits {
+            let before = spent.fetch_add(steps, Ordering::Relaxed);
+            exhausted |= before.saturating_add(steps) > *max_steps;
+        }
+        exhausted |= self.tokens.iter().any(CancellationToken::is_cancelled);
+        exhausted |= self.deadline.is_some_and(|d| Instant::now() >= d);
+        if exhausted {
+            Err(GaveUp)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Fails if any limit has been reached, without spending a step.
+    pub fn check(&self) -> Result<(), GaveUp> {
+        self.spend(0)
+    }
+}
+
+/// A flag shared between clones that stops every [`Budget`] holding it once set.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Returned by an algorithm that stopped because its [`Budget`] ran out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GaveUp;
+
+impl fmt::Display for GaveUp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "gave up after running out of budget")
+    }
+}
+
+impl std::error::Error for GaveUp {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlimited() {
+        let budget = Budget::unlimited();
+        assert_eq!(budget.spend(u64::MAX), Ok(()));
+        assert_eq!(budget.spend(u64::MAX), Ok(()));
+    }
+
+    #[test]
+    fn step_limits_are_shared_by_clones_and_nested() {
+        let budget = Budget::unlimited().with_max_steps(10);
+        let clone = budget.clone();
+        assert_eq!(budget.spend(6), Ok(()));
+        assert_eq!(clone.spend(4), Ok(()));
+        assert_eq!(clone.spend(1), Err(GaveUp));
+        assert_eq!(budget.check(), Err(GaveUp));
+
+        let outer = Budget::unlimited().with_max_steps(5);
+        let inner = outer.clone().with_max_steps(100);
+        assert_eq!(inner.spend(5), Ok(()));
+        assert_eq!(inner.spend(1), Err(GaveUp));
+        assert_eq!(outer.check(), Err(GaveUp));
+    }
+
+    #[test]
+    fn deadline() {
+        assert_eq!(
+            Budget::unlimited().with_timeout(Duration::ZERO).check(),
+            Err(GaveUp)
+        );
+        assert_eq!(
+            Budget::unlimited()
+                .with_timeout(Duration::from_secs(3600))
+                .check(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn cancellation() {
+        let token = CancellationToken::new();
+        let budget = Budget::unlimited().with_cancellation(token.clone());
+        assert_eq!(budget.check(), Ok(()));
+        token.cancel();
+        assert_eq!(budget.check(), Err(GaveUp));
+    }
+
+    #[test]
+    fn observer_is_shared_by_clones() {
+        let events = Arc::new(AtomicU64::new(0));
+        let counter = Arc::clone(&events);
+        let budget = Budget::unlimited().with_observer(Arc::new(move |_: &Event| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }));
+        let event = Event::CofactorPrime {
+            cofactor: bnum::types::U512::SEVEN,
+        };
+        budget.report(event.clone());
+        budget.clone().with_max_steps(1).report(event.clone());
+        Budget::unlimited().report(event);
+        assert_eq!(events.load(Ordering::Relaxed), 2);
+    }
+}
@@ -1,31 +1,93 @@
+mod progress_line;
+
+pub use progress_line::ProgressLine;
+
+use crate::budget::Budget;
 use crate::commands::CommandMap;
+use crate::factorization::FactorizationError;
+use crate::orchestration::pipeline::{ParsePipelineError, Pipeline};
+use crate::traits::Command;
 use bnum::types::U512;
+use std::io::{self, IsTerminal};
+use std::sync::Arc;
+use std::time::Duration;
 
+/// Runs `<command> <number>`, or `--pipeline <stages> <number>` with the stages of a
+/// [`Pipeline`]. Either may be preceded by `--timeout <seconds>`, after which the
+/// factorization gives up. When run in a terminal, the progress is shown on stderr by a
+/// [`ProgressLine`].
 pub fn run(args: &[String]) -> Result<String, Error> {
     let input = ParsedInput::try_from(args)?;
     println!("{}...", &input);
-    let cmd_map = CommandMap::default();
-    match cmd_map.get(&input.command_name) {
-        Some(cmd) => Ok(cmd.run(&input.number).to_string()),
-        None => Err(Error::CommandNotFound(cmd_map.available_commands())),
+    let mut budget = match input.timeout {
+        Some(timeout) => Budget::unlimited().with_timeout(timeout),
+        None => Budget::unlimited(),
+    };
+    let progress = io::stderr()
+        .is_terminal()
+        .then(|| Arc::new(ProgressLine::new(io::stderr())));
+    if let Some(progress) = &progress {
+        budget = budget.with_observer(progress.clone());
     }
+    let output = run_within(&input, &budget);
+    if let Some(progress) = progress {
+        progress.clear();
+    }
+    output
+}
+
+fn run_within(input: &ParsedInput, budget: &Budget) -> Result<String, Error> {
+    let output = match &input.pipeline {
+        Some(stages) => {
+            let pipeline = stages.parse::<Pipeline>().map_err(Error::InvalidPipeline)?;
+            pipeline.try_run(&input.number, budget)
+        }
+        None => {
+            let cmd_map = CommandMap::default();
+            match cmd_map.get(&input.command_name) {
+                Some(cmd) => cmd.try_run(&input.number, budget),
+                None => return Err(Error::CommandNotFound(cmd_map.available_commands())),
+            }
+        }
+    };
+    output.map_err(Error::FactorizationFailed)
 }
 
 struct ParsedInput {
     number: U512,
     digit_len: usize,
     command_name: String,
+    pipeline: Option<String>,
+    timeout: Option<Duration>,
 }
 
 impl TryFrom<&[String]> for ParsedInput {
     type Error = Error;
 
     fn try_from(value: &[String]) -> Result<Self, Self::Error> {
-        let slice: &[String; 3] = value.try_into().map_err(|_| Error::IncorrectNumArgs)?;
+        let mut args = value.get(1..).unwrap_or_default();
+        let mut timeout = None;
+        if let [flag, seconds, rest @ ..] = args {
+            if flag == "--timeout" {
+                let seconds = seconds.parse::<f64>().map_err(|_| Error::InvalidTimeout)?;
+                timeout =
+                    Some(Duration::try_from_secs_f64(seconds).map_err(|_| Error::InvalidTimeout)?);
+                args = rest;
+            }
+        }
+        let (command_name, pipeline, number) = match args {
+            [command_name, number] => (command_name.to_string(), None, number),
+            [flag, stages, number] if flag == "--pipeline" => {
+                ("pipeline".to_string(), Some(stages.to_string()), number)
+            }
+            _ => return Err(Error::IncorrectNumArgs),
+        };
         Ok(ParsedInput {
-            number: slice[2].parse().map_err(|_| Error::ParseIntErr)?,
-            digit_len: slice[2].len(),
-            command_name: slice[1].to_string(),
+            number: number.parse().map_err(|_| Error::ParseIntErr)?,
+            digit_len: number.len(),
+            command_name,
+            pipeline,
+            timeout,
         })
     }
 }
@@ -36,7 +98,14 @@ impl std::fmt::Display for ParsedInput {
             f,
             "Using command {} with number {} ({} digits)",
             self.command_name, self.number, self.digit_len
-        )
+        )?;
+        if let Some(stages) = &self.pipeline {
+            write!(f, " and stages {stages}")?;
+        }
+        match self.timeout {
+            Some(timeout) => write!(f, ", giving up after {}s", timeout.as_secs_f64()),
+            None => Ok(()),
+        }
     }
 }
 
@@ -45,6 +114,22 @@ pub enum Error {
     CommandNotFound(String),
     ParseIntErr,
     IncorrectNumArgs,
+    InvalidPipeline(ParsePipelineError),
+    InvalidTimeout,
+    FactorizationFailed(FactorizationError),
+}
+
+impl Error {
+    /// Usage errors exit with 1, factorizations that failed to split a composite with 2 and
+    /// those that ran out of time with 3, so that scripts can tell a hard number from a bad
+    /// invocation.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::FactorizationFailed(error) if error.gave_up => 3,
+            Error::FactorizationFailed(_) => 2,
+            _ => 1,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -76,6 +161,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pipeline_flag() {
+        let args = |stages: &str, number: &str| {
+            ["rustic_factors", "--pipeline", stages, number].map(String::from)
+        };
+        assert_eq!(
+            run(&args("trial_division:100,pollards_rho", "8051")),
+            Ok("8051 = 83 x 97".to_string())
+        );
+        assert!(matches!(
+            run(&args("trial_division:10", "8051")),
+            Err(Error::FactorizationFailed(_))
+        ));
+        assert_eq!(
+            run(&args("rho", "8051")),
+            Err(Error::InvalidPipeline(ParsePipelineError::UnknownStage(
+                "rho".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn exit_codes() {
+        let error = Error::FactorizationFailed(FactorizationError {
+            number: U512::from(49u8),
+            factors: vec![],
+            cofactors: vec![U512::from(49u8)],
+            gave_up: false,
+        });
+        assert_eq!(error.exit_code(), 2);
+        let error = Error::FactorizationFailed(FactorizationError::gave_up_on(&U512::from(49u8)));
+        assert_eq!(error.exit_code(), 3);
+        assert_eq!(Error::IncorrectNumArgs.exit_code(), 1);
+    }
+
+    #[test]
+    fn timeout_flag() {
+        let args = |seconds: &str, command: &str| {
+            ["rustic_factors", "--timeout", seconds, command, "8051"].map(String::from)
+        };
+        assert_eq!(
+            run(&args("10", "pollards_rho")),
+            Ok("8051 = 83 x 97".to_string())
+        );
+        match run(&args("0", "pollards_rho")) {
+            Err(Error::FactorizationFailed(error)) => assert!(error.gave_up),
+            result => panic!("expected to give up, got {result:?}"),
+        }
+        assert_eq!(run(&args("-1", "pollards_rho")), Err(Error::InvalidTimeout));
+        assert_eq!(
+            run(&[
+                "rustic_factors",
+                "--timeout",
+                "1",
+                "--pipeline",
+                "ecm",
+                "8051"
+            ]
+            .map(String::from)),
+            Ok("8051 = 83 x 97".to_string())
+        );
+    }
+
     #[test]
     fn too_few_args() {
         assert_eq!(
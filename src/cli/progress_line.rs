@@ -0,0 +1,124 @@
+use crate::observer::{Event, FactorizationObserver};
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Redraws the progress of a factorization in a single status line, such as the stage that is
+/// running or the curves done so far, and prints the factors found above it as they turn up.
+/// The frequent events of the curves and relations are shown at most every
+/// [`ProgressLine::REDRAW_INTERVAL`].
+pub struct ProgressLine<W: Write + Send> {
+    state: Mutex<State<W>>,
+}
+
+struct State<W> {
+    out: W,
+    status_shown: bool,
+    last_redraw: Option<Instant>,
+}
+
+/// Moves to the start of the line and erases it.
+const CLEAR_LINE: &str = "\r\x1b[2K";
+
+impl<W: Write + Send> ProgressLine<W> {
+    pub const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+    pub fn new(out: W) -> Self {
+        Self {
+            state: Mutex::new(State {
+                out,
+                status_shown: false,
+                last_redraw: None,
+            }),
+        }
+    }
+
+    /// Erases the status line, so that the result can be printed in its place.
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.status_shown {
+            let _ = write!(state.out, "{CLEAR_LINE}");
+            let _ = state.out.flush();
+            state.status_shown = false;
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.state.into_inner().unwrap().out
+    }
+}
+
+impl<W: Write + Send> FactorizationObserver for ProgressLine<W> {
+    fn observe(&self, event: &Event) {
+        let mut state = self.state.lock().unwrap();
+        let clear = if state.status_shown { CLEAR_LINE } else { "" };
+        // Progress is only worth showing, so errors writing it are ignored.
+        let _ = match event {
+            Event::FactorFound { .. } | Event::CofactorPrime { .. } => {
+                state.status_shown = false;
+                writeln!(state.out, "{clear}{event}")
+            }
+            Event::CurveDone { .. } | Event::RelationsCollected { .. }
+                if state
+                    .last_redraw
+                    .is_some_and(|last| last.elapsed() < Self::REDRAW_INTERVAL) =>
+            {
+                return;
+            }
+            _ => {
+                state.status_shown = true;
+                state.last_redraw = Some(Instant::now());
+                write!(state.out, "{clear}{event}")
+            }
+        };
+        let _ = state.out.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bnum::types::U512;
+
+    #[test]
+    fn factors_are_printed_above_the_status_line() {
+        let progress = ProgressLine::new(vec![]);
+        progress.observe(&Event::CurveDone {
+            curve: 1,
+            curves: 25,
+        });
+        progress.observe(&Event::FactorFound {
+            n: U512::from(8051u16),
+            factor: U512::from(83u8),
+        });
+        progress.observe(&Event::CofactorPrime {
+            cofactor: U512::from(97u8),
+        });
+        // Stages are shown however soon they follow the last redraw.
+        progress.observe(&Event::StageStarted {
+            stage: "pollards_rho:100000".to_string(),
+            n: U512::from(8051u16),
+        });
+        progress.clear();
+        assert_eq!(
+            String::from_utf8(progress.into_inner()).unwrap(),
+            "Curve 1 of 25 done\
+             \r\x1b[2KFound factor 83 of 8051\n\
+             97 is prime\n\
+             Running pollards_rho:100000 on a 4-digit composite\
+             \r\x1b[2K"
+        );
+    }
+
+    #[test]
+    fn frequent_events_are_throttled() {
+        let progress = ProgressLine::new(vec![]);
+        for curve in 1..=3 {
+            progress.observe(&Event::CurveDone { curve, curves: 25 });
+        }
+        assert_eq!(
+            String::from_utf8(progress.into_inner()).unwrap(),
+            "Curve 1 of 25 done"
+        );
+    }
+}
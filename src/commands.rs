@@ -1,3 +1,4 @@
+use crate::orchestration::Pipeline;
 use crate::traits::Command;
 use crate::{algorithms, primality_test};
 use std::collections::BTreeMap;
@@ -27,12 +28,48 @@ impl CommandMap {
 impl Default for CommandMap {
     fn default() -> Self {
         CommandMap(BTreeMap::new())
+            .add("aks", Box::new(primality_test::Aks))
+            .add("bpsw", Box::new(primality_test::BailliePsw))
+            .add("cfrac", Box::new(algorithms::Cfrac))
+            .add("dixon", Box::new(algorithms::Dixon))
+            .add("ecm", Box::<algorithms::EllipticCurveMethod>::default())
+            .add("ecpp", Box::new(primality_test::certificate::Ecpp))
+            .add("fermat", Box::<primality_test::FermatTest>::default())
             .add(
                 "fermats_factorization_method",
                 Box::new(algorithms::FermatsFactorizationMethod),
             )
-            .add("miller_rabin", Box::new(primality_test::MillerRabin))
+            .add("hart_one_line", Box::new(algorithms::HartOneLine))
+            .add("lehman", Box::new(algorithms::Lehman))
+            .add(
+                "miller_rabin",
+                Box::<primality_test::MillerRabin>::default(),
+            )
+            .add("pipeline", Box::<Pipeline>::default())
+            .add(
+                "pollards_p_minus_1",
+                Box::<algorithms::PollardsPMinus1>::default(),
+            )
             .add("pollards_rho", Box::new(algorithms::PollardsRho))
+            .add("pollards_rho_brent", Box::new(algorithms::PollardsRhoBrent))
+            .add(
+                "prove_prime",
+                Box::new(primality_test::certificate::ProvePrime),
+            )
+            .add(
+                "quadratic_frobenius",
+                Box::new(primality_test::QuadraticFrobenius),
+            )
+            .add("quadratic_sieve", Box::new(algorithms::QuadraticSieve))
+            .add(
+                "solovay_strassen",
+                Box::<primality_test::SolovayStrassen>::default(),
+            )
+            .add("squfof", Box::new(algorithms::Squfof))
             .add("trial_division", Box::new(algorithms::TrialDivision))
+            .add(
+                "williams_p_plus_1",
+                Box::<algorithms::WilliamsPPlus1>::default(),
+            )
     }
 }
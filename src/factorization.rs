@@ -40,6 +40,52 @@ impl fmt::Display for Factorization<'_> {
     }
 }
 
+/// The outcome of a factorization that stopped before every factor was prime: the prime
+/// factors found so far and the composite cofactors that could not be split, either because
+/// the factorizer kept failing or because it gave up when its budget ran out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FactorizationError {
+    pub number: U512,
+    pub factors: Vec<U512>,
+    pub cofactors: Vec<U512>,
+    pub gave_up: bool,
+}
+
+impl FactorizationError {
+    /// Gave up on `n` before finding any factors.
+    pub fn gave_up_on(n: &U512) -> Self {
+        Self {
+            number: *n,
+            factors: vec![],
+            cofactors: vec![*n],
+            gave_up: true,
+        }
+    }
+}
+
+impl fmt::Display for FactorizationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |numbers: &[U512], separator| {
+            numbers
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        if self.gave_up {
+            write!(f, "Gave up on factoring {}: ", self.number)?;
+        } else {
+            write!(f, "Failed to factor {} completely: ", self.number)?;
+        }
+        if !self.factors.is_empty() {
+            write!(f, "found {}, but ", join(&self.factors, " x "))?;
+        }
+        write!(f, "could not split {}", join(&self.cofactors, ", "))
+    }
+}
+
+impl std::error::Error for FactorizationError {}
+
 fn format_factor(base: &U512, exponent: u128) -> String {
     fn to_superscript(exp: u128) -> String {
         if exp <= 1 {
@@ -75,4 +121,30 @@ mod tests {
     fn big_composite() {
         check(4096, &[2; 12], "4096 = 2¹²");
     }
+
+    #[test]
+    fn partial_factorization() {
+        let error = FactorizationError {
+            number: U512::from(3003u16),
+            factors: vec![U512::THREE, U512::SEVEN],
+            cofactors: vec![U512::from(143u8)],
+            gave_up: false,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Failed to factor 3003 completely: found 3 x 7, but could not split 143"
+        );
+        let error = FactorizationError {
+            factors: vec![],
+            ..error
+        };
+        assert_eq!(
+            error.to_string(),
+            "Failed to factor 3003 completely: could not split 143"
+        );
+        assert_eq!(
+            FactorizationError::gave_up_on(&U512::from(143u8)).to_string(),
+            "Gave up on factoring 143: could not split 143"
+        );
+    }
 }
@@ -1,13 +1,26 @@
 pub mod algorithms;
+pub mod budget;
 pub mod cli;
 pub mod commands;
 pub mod factorization;
+pub(crate) mod lucas;
+pub mod modular;
+pub mod observer;
 pub mod orchestration;
 pub mod primality_test;
+pub(crate) mod primes;
 pub mod traits;
+pub mod word;
 
-pub use factorization::Factorization;
+pub use bnum::types::U512;
+pub use factorization::{Factorization, FactorizationError};
+pub use rustic_factors_derive::{
+    FactorizationCommand, PrimalityCommand, RecursivePrimeFactorization,
+};
 pub use traits::PrimeFactorization;
 
+// The derive macros refer to `::rustic_factors`, which this makes work inside the crate too.
+extern crate self as rustic_factors;
+
 #[cfg(test)]
 pub(crate) mod test_framework;
@@ -0,0 +1,112 @@
+use crate::modular::{self, MontgomeryContext};
+use crate::word::Word;
+use bnum::types::U512;
+
+/// Computes `V_k(p, 1) mod n`, the `k`-th term of the Lucas sequence `V_0 = 2`, `V_1 = p`,
+/// `V_{i+1} = p V_i - V_{i-1}`, using a ladder over the pairs `(V_i, V_{i+1})`.
+///
+/// These satisfy `V_{mk}(p, 1) = V_m(V_k(p, 1), 1)`, so exponents can be applied one at a time.
+pub fn lucas_v(p: &U512, k: u64, n: &U512) -> U512 {
+    if k == 0 {
+        return U512::TWO % n;
+    }
+    let mut low = *p % n;
+    let mut high = double(&low, n);
+    for bit in (0..k.ilog2()).rev() {
+        if (k >> bit) & 1 == 1 {
+            low = differential_add(&low, &high, p, n);
+            high = double(&high, n);
+        } else {
+            high = differential_add(&low, &high, p, n);
+            low = double(&low, n);
+        }
+    }
+    low
+}
+
+/// Computes `V_{2i}` from `V_i`, as `V_i² - 2`.
+pub fn double(v: &U512, n: &U512) -> U512 {
+    modular::sub_mod(&modular::mul_mod(v, v, n), &U512::TWO, n)
+}
+
+/// Computes `V_{i+j}` from `V_i`, `V_j` and `V_{i-j}`, as `V_i V_j - V_{i-j}`.
+pub fn differential_add(v_i: &U512, v_j: &U512, v_diff: &U512, n: &U512) -> U512 {
+    modular::sub_mod(&modular::mul_mod(v_i, v_j, n), v_diff, n)
+}
+
+/// Computes `(U_k, V_k, Q^k)` for the Lucas sequences `U_0 = 0`, `U_1 = 1`, `V_0 = 2`,
+/// `V_1 = P` with `X_{i+1} = P X_i - Q X_{i-1}`, modulo the odd modulus of `context`.
+///
+/// `p`, `q` and the results are residues in Montgomery form. Works through the bits of `k`
+/// with `U_{2i} = U_i V_i`, `V_{2i} = V_i² - 2Q^i`, and
+/// `U_{i+1} = (P U_i + V_i) / 2`, `V_{i+1} = (D U_i + P V_i) / 2` where `D = P² - 4Q`.
+pub fn lucas_sequences<W: Word>(context: &MontgomeryContext<W>, p: W, q: W, k: W) -> (W, W, W) {
+    let two = context.add(context.one(), context.one());
+    let four_q = context.add(context.add(q, q), context.add(q, q));
+    let d = context.sub(context.square(p), four_q);
+    let (mut u, mut v, mut q_k) = (W::zero(), two, context.one());
+    let k = k.to_u512();
+    for bit in (0..k.bits()).rev() {
+        u = context.mul(u, v);
+        v = context.sub(context.square(v), context.add(q_k, q_k));
+        q_k = context.square(q_k);
+        if k.bit(bit) {
+            let u_next = context.halve(context.add(context.mul(p, u), v));
+            v = context.halve(context.add(context.mul(d, u), context.mul(p, v)));
+            u = u_next;
+            q_k = context.mul(q_k, q);
+        }
+    }
+    (u, v, q_k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_recurrence() {
+        let n = U512::from(1000000007u64);
+        let p = U512::from(5u8);
+        let (mut prev, mut current) = (U512::TWO, p);
+        for k in 1..100 {
+            assert_eq!(lucas_v(&p, k, &n), current, "V_{k}");
+            let next = differential_add(&p, &current, &prev, &n);
+            (prev, current) = (current, next);
+        }
+    }
+
+    #[test]
+    fn general_sequences_match_recurrence() {
+        let n = 1000000007u64;
+        let context = MontgomeryContext::new(n);
+        // P = 1, Q = -1 gives the Fibonacci and Lucas numbers.
+        let (p, q) = (1, n - 1);
+        let (p_form, q_form) = (context.to_montgomery(p), context.to_montgomery(q));
+        let (mut u, mut u_next, mut v, mut v_next, mut q_k) = (0, 1, 2, p, 1);
+        for k in 0..100 {
+            let (actual_u, actual_v, actual_q_k) = lucas_sequences(&context, p_form, q_form, k);
+            assert_eq!(context.from_montgomery(actual_u), u, "U_{k}");
+            assert_eq!(context.from_montgomery(actual_v), v, "V_{k}");
+            assert_eq!(context.from_montgomery(actual_q_k), q_k, "Q^{k}");
+            let step = |x: u64, x_next: u64| {
+                let p_x = p.mul_mod(x_next, n);
+                let q_x = q.mul_mod(x, n);
+                (x_next, (p_x + n - q_x) % n)
+            };
+            (u, u_next) = step(u, u_next);
+            (v, v_next) = step(v, v_next);
+            q_k = q_k.mul_mod(q, n);
+        }
+    }
+
+    #[test]
+    fn composition() {
+        let n = U512::from(1000000007u64);
+        let p = U512::from(7u8);
+        assert_eq!(
+            lucas_v(&lucas_v(&p, 12, &n), 35, &n),
+            lucas_v(&p, 12 * 35, &n)
+        );
+    }
+}
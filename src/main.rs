@@ -1,14 +1,33 @@
 use rustic_factors::cli;
 use std::env;
+use std::process::ExitCode;
 
-fn main() {
+fn main() -> ExitCode {
     let args: Vec<String> = env::args().collect();
-    match cli::run(&args) {
-        Ok(result) => println!("{result}"),
-        Err(cli::Error::ParseIntErr) => eprintln!("Please provide a number in range [0, 2⁵¹²)"),
-        Err(cli::Error::IncorrectNumArgs) => eprintln!("Usage: {} <command> <number>", args[0]),
-        Err(cli::Error::CommandNotFound(commands)) => {
+    let error = match cli::run(&args) {
+        Ok(result) => {
+            println!("{result}");
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => error,
+    };
+    match &error {
+        cli::Error::ParseIntErr => eprintln!("Please provide a number in range [0, 2⁵¹²)"),
+        cli::Error::IncorrectNumArgs => eprintln!(
+            "Usage: {0} [--timeout <seconds>] <command> <number>\n       \
+             {0} [--timeout <seconds>] --pipeline <stage,...> <number>",
+            args[0]
+        ),
+        cli::Error::InvalidPipeline(pipeline_error) => {
+            eprintln!("Invalid pipeline: {pipeline_error}")
+        }
+        cli::Error::InvalidTimeout => {
+            eprintln!("Please provide the timeout as a non-negative number of seconds")
+        }
+        cli::Error::CommandNotFound(commands) => {
             eprintln!("Unknown command. Available options: {commands}.")
         }
+        cli::Error::FactorizationFailed(factorization_error) => eprintln!("{factorization_error}"),
     }
+    ExitCode::from(error.exit_code())
 }
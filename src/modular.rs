@@ -0,0 +1,351 @@
+use crate::word::Word;
+use bnum::cast::As;
+use bnum::types::{U1024, U512};
+use num_integer::Integer;
+
+/// Computes `a * b mod n` through a `U1024` intermediate, so that the product cannot wrap
+/// around for any modulus below `2^512`.
+pub fn mul_mod(a: &U512, b: &U512, n: &U512) -> U512 {
+    if a.bits() + b.bits() <= U512::BITS {
+        return a * b % n;
+    }
+    ((*a).as_::<U1024>() * (*b).as_::<U1024>() % (*n).as_::<U1024>()).as_::<U512>()
+}
+
+pub fn pow_mod(base: &U512, exp: &U512, n: &U512) -> U512 {
+    let mut result = U512::ONE % n;
+    let mut base = base % n;
+    let mut exp = *exp;
+    while !exp.is_zero() {
+        if exp.is_odd() {
+            result = mul_mod(&result, &base, n);
+        }
+        base = mul_mod(&base, &base, n);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Computes `a + b mod n` for `a, b < n`.
+pub fn add_mod(a: &U512, b: &U512, n: &U512) -> U512 {
+    sub_mod(a, &(n - b), n)
+}
+
+/// Computes `a - b mod n` for `a, b < n`.
+pub fn sub_mod(a: &U512, b: &U512, n: &U512) -> U512 {
+    if a >= b {
+        a - b
+    } else {
+        n - (b - a)
+    }
+}
+
+/// Returns `a^-1 mod n`, or `None` if `a` and `n` have a common factor.
+pub fn inverse(a: &U512, n: &U512) -> Option<U512> {
+    let (mut r0, mut r1) = (*n, a % n);
+    let (mut t0, mut t1) = (U512::ZERO, U512::ONE);
+    while !r1.is_zero() {
+        let (q, r) = r0.div_rem(&r1);
+        (r0, r1) = (r1, r);
+        (t0, t1) = (t1, sub_mod(&t0, &mul_mod(&q, &t1, n), n));
+    }
+    (r0 == U512::ONE).then_some(t0)
+}
+
+/// Returns a square root of `a` modulo an odd prime `p` with the Tonelli–Shanks algorithm,
+/// or `None` if `a` is not a square. If `p` is composite, the result may be `None` or a
+/// number whose square is not `a`.
+pub fn sqrt_mod(a: &U512, p: &U512) -> Option<U512> {
+    const NON_RESIDUE_SEARCH_LIMIT: u64 = 1_000;
+    let a = a % p;
+    if a.is_zero() {
+        return Some(U512::ZERO);
+    }
+    if jacobi_symbol(a, *p) != 1 {
+        return None;
+    }
+    let (mut odd_factor, mut exponent_of_2) = (p - U512::ONE, 0);
+    while odd_factor.is_even() {
+        odd_factor >>= 1;
+        exponent_of_2 += 1;
+    }
+    let non_residue = (2..NON_RESIDUE_SEARCH_LIMIT)
+        .map(U512::from)
+        .find(|&z| jacobi_symbol(z, *p) == -1)?;
+    let mut c = pow_mod(&non_residue, &odd_factor, p);
+    let mut t = pow_mod(&a, &odd_factor, p);
+    let mut root = pow_mod(&a, &((odd_factor + U512::ONE) >> 1), p);
+    while t != U512::ONE {
+        // The least i with t^(2^i) = 1, which is below the previous one for prime p.
+        let mut i = 0;
+        let mut t_power = t;
+        while t_power != U512::ONE {
+            t_power = mul_mod(&t_power, &t_power, p);
+            i += 1;
+            if i == exponent_of_2 {
+                return None;
+            }
+        }
+        let b = (0..exponent_of_2 - i - 1).fold(c, |b, _| mul_mod(&b, &b, p));
+        exponent_of_2 = i;
+        c = mul_mod(&b, &b, p);
+        t = mul_mod(&t, &c, p);
+        root = mul_mod(&root, &b, p);
+    }
+    Some(root)
+}
+
+/// Arithmetic modulo an odd `n` on residues in Montgomery form `xR mod n`, where
+/// `R = 2^W::BITS`.
+///
+/// In this form a product is reduced with two more multiplications and a subtraction instead
+/// of a division, which is far cheaper for wide words. Residues have to be converted with
+/// [`to_montgomery`](Self::to_montgomery) before use, but `gcd(xR mod n, n) = gcd(x, n)`, so
+/// algorithms that only take gcds with `n` never need to convert back.
+pub struct MontgomeryContext<W> {
+    n: W,
+    /// `n^-1 mod R`.
+    n_inverse: W,
+    /// `R mod n`, the Montgomery form of one.
+    one: W,
+    /// `R² mod n`.
+    r_squared: W,
+}
+
+impl<W: Word> MontgomeryContext<W> {
+    pub fn new(n: W) -> Self {
+        assert!(n.is_odd(), "Montgomery arithmetic requires an odd modulus");
+        // Newton's iteration doubles the number of correct low bits, starting from the three
+        // bits given by `n * n = 1 mod 8`.
+        let mut n_inverse = n;
+        let mut correct_bits = 3;
+        while correct_bits < W::BITS {
+            n_inverse = n_inverse.wrapping_mul(W::two().wrapping_sub(n.wrapping_mul(n_inverse)));
+            correct_bits *= 2;
+        }
+        let one = W::zero().wrapping_sub(n) % n;
+        Self {
+            n,
+            n_inverse,
+            one,
+            r_squared: one.mul_mod(one, n),
+        }
+    }
+
+    pub fn modulus(&self) -> W {
+        self.n
+    }
+
+    pub fn one(&self) -> W {
+        self.one
+    }
+
+    pub fn to_montgomery(&self, x: W) -> W {
+        self.mul(x % self.n, self.r_squared)
+    }
+
+    pub fn from_montgomery(&self, x: W) -> W {
+        self.reduce(x, W::zero())
+    }
+
+    pub fn mul(&self, a: W, b: W) -> W {
+        let (low, high) = a.mul_wide(b);
+        self.reduce(low, high)
+    }
+
+    pub fn square(&self, a: W) -> W {
+        self.mul(a, a)
+    }
+
+    pub fn pow(&self, base: W, mut exp: W) -> W {
+        let mut result = self.one;
+        let mut base = base;
+        while !exp.is_zero() {
+            if exp.is_odd() {
+                result = self.mul(result, base);
+            }
+            base = self.square(base);
+            exp = exp / W::two();
+        }
+        result
+    }
+
+    pub fn add(&self, a: W, b: W) -> W {
+        a.add_mod(b, self.n)
+    }
+
+    pub fn sub(&self, a: W, b: W) -> W {
+        if a >= b {
+            a - b
+        } else {
+            self.n - (b - a)
+        }
+    }
+
+    /// Computes `a / 2 mod n`, which is the same operation in Montgomery form.
+    pub fn halve(&self, a: W) -> W {
+        if a.is_even() {
+            a / W::two()
+        } else {
+            // (a + n) / 2 without overflowing, since both are odd.
+            a / W::two() + self.n / W::two() + W::one()
+        }
+    }
+
+    /// Montgomery reduction of `T = high R + low < nR`, returning `T R^-1 mod n`.
+    ///
+    /// With `m = low n^-1 mod R`, `mn` has the same low half as `T`, so `T - mn` is a multiple
+    /// of `R` and `(T - mn) / R = high - (mn >> W::BITS)`, which lies in `(-n, n)`.
+    fn reduce(&self, low: W, high: W) -> W {
+        let m = low.wrapping_mul(self.n_inverse);
+        let (_, mn_high) = m.mul_wide(self.n);
+        self.sub(high, mn_high)
+    }
+}
+
+/// Computes the Jacobi symbol `(a/n)` for an odd `n`, which is `0` if `a` and `n` have a
+/// common factor and otherwise `±1`, extending the Legendre symbol to composite `n`.
+pub fn jacobi_symbol<W: Word>(a: W, n: W) -> i8 {
+    let (mut a, mut n) = (a % n, n);
+    let mut result = 1;
+    while !a.is_zero() {
+        while a.is_even() {
+            a = a / W::two();
+            if matches!(n.rem_u64(8), 3 | 5) {
+                result = -result;
+            }
+        }
+        std::mem::swap(&mut a, &mut n);
+        if a.rem_u64(4) == 3 && n.rem_u64(4) == 3 {
+            result = -result;
+        }
+        a = a % n;
+    }
+    if n.is_one() {
+        result
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The largest prime below `2^512`.
+    fn p512() -> U512 {
+        U512::MAX - U512::from(568u16)
+    }
+
+    #[test]
+    fn products_near_the_top_of_the_range() {
+        let n = p512();
+        let minus_one = n - U512::ONE;
+        assert_eq!(mul_mod(&minus_one, &minus_one, &n), U512::ONE);
+        assert_eq!(mul_mod(&minus_one, &U512::TWO, &n), n - U512::TWO);
+    }
+
+    #[test]
+    fn fermats_little_theorem_for_512_bit_prime() {
+        let n = p512();
+        for base in [2u8, 3, 65] {
+            let base = U512::from(base);
+            assert_eq!(pow_mod(&base, &(n - U512::ONE), &n), U512::ONE);
+        }
+    }
+
+    fn check_montgomery_context<W: Word>(n: W) {
+        let context = MontgomeryContext::new(n);
+        let samples = [
+            W::zero(),
+            W::one(),
+            W::two(),
+            n / W::two(),
+            n - W::two(),
+            n - W::one(),
+        ];
+        for &a in &samples {
+            let a_form = context.to_montgomery(a);
+            assert_eq!(context.from_montgomery(a_form), a);
+            for &b in &samples {
+                let product = context.mul(a_form, context.to_montgomery(b));
+                assert_eq!(
+                    context.from_montgomery(product),
+                    a.mul_mod(b, n),
+                    "{a} * {b}"
+                );
+            }
+        }
+        let exp = n - W::one();
+        assert_eq!(
+            context.from_montgomery(context.pow(context.to_montgomery(W::two()), exp)),
+            W::two().pow_mod(exp, n)
+        );
+    }
+
+    #[test]
+    fn montgomery_context_agrees_with_mul_mod() {
+        check_montgomery_context(1000000007u64);
+        check_montgomery_context(u64::MAX - 58);
+        check_montgomery_context(u128::MAX - 158);
+        check_montgomery_context(U512::from(1000000007u64));
+        check_montgomery_context(p512());
+    }
+
+    #[test]
+    fn halving_in_montgomery_form() {
+        let n = u64::MAX - 58;
+        let context = MontgomeryContext::new(n);
+        for x in [0, 1, 2, 3, n - 2, n - 1] {
+            let half = context.from_montgomery(context.halve(context.to_montgomery(x)));
+            assert_eq!(half.mul_mod(2, n), x);
+        }
+    }
+
+    #[test]
+    fn jacobi_symbol_matches_euler_criterion() {
+        let p = 1000003u64;
+        for a in [1, 2, 3, 5, 7, 1000, 999999, p - 1] {
+            let expected = if a.pow_mod((p - 1) / 2, p) == 1 {
+                1
+            } else {
+                -1
+            };
+            assert_eq!(jacobi_symbol(a, p), expected, "({a}/{p})");
+        }
+        assert_eq!(jacobi_symbol(21u64, 35), 0);
+        // 2 is a non-residue modulo 3 and 5, so (2/15) = 1 although 2 is no square modulo 15.
+        assert_eq!(jacobi_symbol(2u64, 15), 1);
+        assert_eq!(jacobi_symbol(U512::from(1001u16), U512::from(9907u16)), -1);
+    }
+
+    #[test]
+    fn inverses() {
+        let n = p512();
+        for a in [U512::ONE, U512::TWO, U512::from(12345u16), n - U512::ONE] {
+            assert_eq!(mul_mod(&inverse(&a, &n).unwrap(), &a, &n), U512::ONE);
+        }
+        assert_eq!(inverse(&U512::from(6u8), &U512::from(15u8)), None);
+    }
+
+    #[test]
+    fn square_roots() {
+        // 1000000009 - 1 = 2³ × 125000001, so that Tonelli–Shanks needs several steps.
+        for p in [U512::from(1000000009u64), p512()] {
+            for a in [2u8, 3, 5, 10, 11].map(U512::from) {
+                match sqrt_mod(&a, &p) {
+                    Some(root) => assert_eq!(mul_mod(&root, &root, &p), a),
+                    None => assert_eq!(jacobi_symbol(a, p), -1),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn addition_and_subtraction_wrap_around() {
+        let n = p512();
+        let a = n - U512::TWO;
+        assert_eq!(add_mod(&a, &U512::FIVE, &n), U512::THREE);
+        assert_eq!(sub_mod(&U512::THREE, &U512::FIVE, &n), a);
+    }
+}
@@ -0,0 +1,89 @@
+use bnum::types::U512;
+use std::fmt;
+
+/// Receives the [`Event`]s of a running factorization, such as to show its progress. The
+/// observer is set with [`Budget::with_observer`](crate::budget::Budget::with_observer),
+/// since the budget already reaches every orchestrator and algorithm.
+///
+/// Orchestrators may report from several threads at once.
+pub trait FactorizationObserver: Send + Sync {
+    fn observe(&self, event: &Event);
+}
+
+impl<F> FactorizationObserver for F
+where
+    F: Fn(&Event) + Send + Sync,
+{
+    fn observe(&self, event: &Event) {
+        self(event)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// A stage of a [`Pipeline`](crate::orchestration::Pipeline) started on a composite.
+    StageStarted { stage: String, n: U512 },
+    /// A non-trivial divisor of the composite `n` was found.
+    FactorFound { n: U512, factor: U512 },
+    /// The primality test showed that a cofactor is prime, so that it is left as it is.
+    CofactorPrime { cofactor: U512 },
+    /// The elliptic curve method finished with a curve without finding a factor.
+    CurveDone { curve: usize, curves: usize },
+    /// A sieve collected more of the relations it needs to find a congruence of squares.
+    RelationsCollected { relations: usize, wanted: usize },
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::StageStarted { stage, n } => {
+                write!(f, "Running {stage} on a {}-digit composite", digits(n))
+            }
+            Event::FactorFound { n, factor } => write!(f, "Found factor {factor} of {n}"),
+            Event::CofactorPrime { cofactor } => write!(f, "{cofactor} is prime"),
+            Event::CurveDone { curve, curves } => write!(f, "Curve {curve} of {curves} done"),
+            Event::RelationsCollected { relations, wanted } => {
+                write!(f, "Collected {relations} of {wanted} relations")
+            }
+        }
+    }
+}
+
+fn digits(n: &U512) -> usize {
+    n.to_str_radix(10).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn closures_are_observers() {
+        let events = Mutex::new(vec![]);
+        let observer = |event: &Event| events.lock().unwrap().push(event.clone());
+        let event = Event::CurveDone {
+            curve: 1,
+            curves: 25,
+        };
+        observer.observe(&event);
+        assert_eq!(events.into_inner().unwrap(), [event]);
+    }
+
+    #[test]
+    fn display() {
+        let event = Event::StageStarted {
+            stage: "ecm:50000:5000000:25".to_string(),
+            n: U512::from(1000000016000000063u64),
+        };
+        assert_eq!(
+            event.to_string(),
+            "Running ecm:50000:5000000:25 on a 19-digit composite"
+        );
+        let event = Event::FactorFound {
+            n: U512::from(8051u16),
+            factor: U512::from(83u8),
+        };
+        assert_eq!(event.to_string(), "Found factor 83 of 8051");
+    }
+}
@@ -1,3 +1,7 @@
+pub mod parallel;
+pub mod pipeline;
 pub mod recursive;
 
+pub use parallel::ParallelPrimeFactorization;
+pub use pipeline::{Pipeline, Stage};
 pub use recursive::RecursivePrimeFactorization;
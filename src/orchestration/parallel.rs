@@ -0,0 +1,294 @@
+use super::RecursivePrimeFactorization;
+use crate::budget::{Budget, CancellationToken, GaveUp};
+use crate::factorization::FactorizationError;
+use crate::observer::Event;
+use crate::traits::{Factorize, PrimalityTest, PrimeFactorization};
+use crate::word;
+use bnum::types::U512;
+use num_integer::Integer;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+
+/// Does the same as [`RecursivePrimeFactorization`] on several threads. The two parts
+/// of every split are factored concurrently, and each composite is attacked by `threads`
+/// workers that call the randomized factorizer with different random choices, such as the
+/// constant of Pollard's rho or the curve of the elliptic curve method. The workers stop
+/// starting new attempts as soon as one of them finds a factor.
+pub struct ParallelPrimeFactorization<F, P>
+where
+    F: Factorize,
+    P: PrimalityTest,
+{
+    threads: usize,
+    max_successive_fails: usize,
+    // Only the static methods of `F` and `P` are used, so the orchestrator is shared
+    // between threads whatever the types are.
+    _factorizer: PhantomData<fn() -> F>,
+    _prime_tester: PhantomData<fn() -> P>,
+}
+
+impl<Factorizer, PrimeTester> PrimeFactorization
+    for ParallelPrimeFactorization<Factorizer, PrimeTester>
+where
+    Factorizer: Factorize,
+    PrimeTester: PrimalityTest,
+{
+    fn prime_factorization(n: &U512) -> Vec<U512> {
+        Self::default().parallel_factorization(*n)
+    }
+
+    fn try_prime_factorization(n: &U512) -> Result<Vec<U512>, FactorizationError> {
+        Self::default().try_parallel_factorization(*n)
+    }
+
+    fn try_prime_factorization_with_budget(
+        n: &U512,
+        budget: &Budget,
+    ) -> Result<Vec<U512>, FactorizationError> {
+        Self::default().try_parallel_factorization_with_budget(*n, budget)
+    }
+}
+
+/// Uses every available thread, and gives up on a composite after
+/// [`RecursivePrimeFactorization::DEFAULT_MAX_SUCCESSIVE_FAILS`] failed attempts.
+impl<Factorizer, PrimeTester> Default for ParallelPrimeFactorization<Factorizer, PrimeTester>
+where
+    Factorizer: Factorize,
+    PrimeTester: PrimalityTest,
+{
+    fn default() -> Self {
+        let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+        Self::new(
+            threads,
+            RecursivePrimeFactorization::<Factorizer, PrimeTester>::DEFAULT_MAX_SUCCESSIVE_FAILS,
+        )
+    }
+}
+
+impl<Factorizer, PrimeTester> ParallelPrimeFactorization<Factorizer, PrimeTester>
+where
+    Factorizer: Factorize,
+    PrimeTester: PrimalityTest,
+{
+    /// Runs `threads` workers on every composite, which together make up to
+    /// `max_successive_fails` attempts to split it.
+    pub fn new(threads: usize, max_successive_fails: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            max_successive_fails,
+            _factorizer: PhantomData,
+            _prime_tester: PhantomData,
+        }
+    }
+
+    /// Panics if a composite cannot be split, see
+    /// [`ParallelPrimeFactorization::try_parallel_factorization`].
+    pub fn parallel_factorization(&self, n: U512) -> Vec<U512> {
+        self.try_parallel_factorization(n).unwrap_or_else(|error| {
+            panic!(
+                "Failed to find factor after {} successive attempts. {error}",
+                self.max_successive_fails
+            )
+        })
+    }
+
+    pub fn try_parallel_factorization(&self, n: U512) -> Result<Vec<U512>, FactorizationError> {
+        self.try_parallel_factorization_with_budget(n, &Budget::unlimited())
+    }
+
+    /// Does the same as [`ParallelPrimeFactorization::try_parallel_factorization`], with the
+    /// budget shared by all workers. Gives up on the whole of `n` if the budget has already
+    /// run out.
+    pub fn try_parallel_factorization_with_budget(
+        &self,
+        mut n: U512,
+        budget: &Budget,
+    ) -> Result<Vec<U512>, FactorizationError> {
+        if budget.check().is_err() {
+            return Err(FactorizationError::gave_up_on(&n));
+        }
+        let number = n;
+        let progress = Mutex::new(Progress::default());
+        let mut twos = vec![];
+        while n.is_even() && !n.is_zero() {
+            twos.push(U512::TWO);
+            n /= U512::TWO;
+        }
+        self.factor(n, budget, &progress);
+        let Progress {
+            factors,
+            cofactors,
+            gave_up,
+        } = progress.into_inner().unwrap();
+        let factors = [twos, factors].concat();
+        if cofactors.is_empty() {
+            Ok(factors)
+        } else {
+            Err(FactorizationError {
+                number,
+                factors,
+                cofactors,
+                gave_up,
+            })
+        }
+    }
+
+    fn factor(&self, n: U512, budget: &Budget, progress: &Mutex<Progress>) {
+        if n <= U512::ONE {
+            return;
+        }
+        if word::is_prime_narrowest::<PrimeTester>(&n) {
+            budget.report(Event::CofactorPrime { cofactor: n });
+            progress.lock().unwrap().factors.push(n);
+            return;
+        }
+        match self.split(&n, budget) {
+            Ok(Some(d)) => {
+                budget.report(Event::FactorFound { n, factor: d });
+                thread::scope(|scope| {
+                    scope.spawn(|| self.factor(d, budget, progress));
+                    self.factor(n / d, budget, progress);
+                })
+            }
+            Ok(None) => progress.lock().unwrap().cofactors.push(n),
+            Err(GaveUp) => {
+                let mut progress = progress.lock().unwrap();
+                progress.cofactors.push(n);
+                progress.gave_up = true;
+            }
+        }
+    }
+
+    /// Returns the first non-trivial divisor of the composite `n` found by any worker. Once
+    /// one is found, the attempts still running are cancelled.
+    fn split(&self, n: &U512, budget: &Budget) -> Result<Option<U512>, GaveUp> {
+        let found = OnceLock::new();
+        let attempts = AtomicUsize::new(0);
+        let token = CancellationToken::new();
+        let attempt_budget = budget.clone().with_cancellation(token.clone());
+        thread::scope(|scope| {
+            for _ in 0..self.threads {
+                scope.spawn(|| {
+                    while found.get().is_none()
+                        && attempts.fetch_add(1, Ordering::Relaxed) < self.max_successive_fails
+                    {
+                        let Ok(d) =
+                            word::factorize_narrowest_with_budget::<Factorizer>(n, &attempt_budget)
+                        else {
+                            break;
+                        };
+                        if !d.is_one() && d != *n && found.set(d).is_ok() {
+                            token.cancel();
+                        }
+                    }
+                });
+            }
+        });
+        match found.into_inner() {
+            Some(d) => Ok(Some(d)),
+            None => budget.check().map(|()| None),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Progress {
+    factors: Vec<U512>,
+    cofactors: Vec<U512>,
+    gave_up: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{EllipticCurveMethod, PollardsRhoBrent};
+    use crate::primality_test::{BailliePsw, MillerRabin};
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+
+    type ParallelRho = ParallelPrimeFactorization<PollardsRhoBrent, MillerRabin>;
+    type ParallelEcm =
+        ParallelPrimeFactorization<EllipticCurveMethod<2_000, 100_000, 4>, BailliePsw>;
+
+    #[test]
+    fn default() {
+        CheckTestBuilder::default()
+            .build::<ParallelRho>()
+            .check_cases()
+    }
+
+    #[test]
+    fn splits_are_factored_concurrently() {
+        // The first split leaves a composite cofactor for another worker to pick up.
+        CheckTestBuilder::new()
+            .case(
+                1000000016000000063 * 1000000021,
+                &[1000000007, 1000000009, 1000000021],
+            )
+            .build::<ParallelRho>()
+            .check_cases()
+    }
+
+    #[test]
+    fn elliptic_curve_method() {
+        CheckTestBuilder::new()
+            .case(1001000000350333000005661, &[1000000000333, 1001000000017])
+            .build::<ParallelEcm>()
+            .check_cases()
+    }
+
+    /// Splits off the smallest prime factor up to 5, and fails on anything else.
+    struct SmallFactors;
+
+    impl Factorize for SmallFactors {
+        fn factorize(n: &U512) -> U512 {
+            [U512::TWO, U512::THREE, U512::FIVE]
+                .into_iter()
+                .find(|p| (n % p).is_zero())
+                .unwrap_or(*n)
+        }
+    }
+
+    #[test]
+    fn reports_unsplit_cofactors() {
+        let orchestrator = ParallelPrimeFactorization::<SmallFactors, BailliePsw>::new(4, 20);
+        let n = U512::from(2 * 3 * 5 * 539u16);
+        let error = orchestrator.try_parallel_factorization(n).unwrap_err();
+        let mut factors = error.factors;
+        factors.sort();
+        assert_eq!(factors, [U512::TWO, U512::THREE, U512::FIVE]);
+        assert_eq!(error.cofactors, [U512::from(539u16)]);
+    }
+
+    #[test]
+    fn attempts_are_shared_between_workers() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        struct CountingFailure;
+
+        impl Factorize for CountingFailure {
+            fn factorize(n: &U512) -> U512 {
+                CALLS.fetch_add(1, Ordering::Relaxed);
+                *n
+            }
+        }
+
+        let orchestrator = ParallelPrimeFactorization::<CountingFailure, BailliePsw>::new(8, 30);
+        assert!(orchestrator
+            .try_parallel_factorization(U512::from(77u8))
+            .is_err());
+        assert_eq!(CALLS.load(Ordering::Relaxed), 30);
+    }
+
+    #[test]
+    fn gives_up_when_the_budget_runs_out() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let budget = Budget::unlimited().with_cancellation(token);
+        let n = U512::from(2 * 8051u16);
+        let error = ParallelRho::new(4, 100).try_parallel_factorization_with_budget(n, &budget);
+        // Not even the factors of 2 are divided out once the budget has run out.
+        assert_eq!(error, Err(FactorizationError::gave_up_on(&n)));
+    }
+}
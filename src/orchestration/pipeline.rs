@@ -0,0 +1,582 @@
+use crate::algorithms::{
+    self, elliptic_curve_method, pollards_p_minus_1, PollardsRhoBrent, TrialDivision,
+};
+use crate::budget::{Budget, GaveUp};
+use crate::factorization::{Factorization, FactorizationError};
+use crate::observer::Event;
+use crate::primality_test::MillerRabin;
+use crate::traits::{Command, Factorize, PrimalityTest};
+use crate::word;
+use bnum::types::U512;
+use num_integer::Integer;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// A factorization method run by a [`Pipeline`] on every cofactor left composite by the
+/// stages before it.
+#[derive(Clone, Debug)]
+pub enum Stage {
+    /// Divides out every prime up to `limit`.
+    TrialDivision {
+        limit: u64,
+    },
+    /// Pollard's rho with Brent's cycle detection, giving up after `iterations` steps.
+    PollardsRho {
+        iterations: u64,
+    },
+    PollardsPMinus1 {
+        b1: u64,
+        b2: u64,
+    },
+    EllipticCurveMethod {
+        b1: u64,
+        b2: u64,
+        curves: usize,
+    },
+    /// Any other factorization strategy, see [`Stage::strategy`].
+    Strategy {
+        name: &'static str,
+        factorize: fn(&U512, &Budget) -> Result<U512, GaveUp>,
+    },
+}
+
+impl Stage {
+    pub const DEFAULT_TRIAL_DIVISION_LIMIT: u64 = 1_000_000;
+    pub const DEFAULT_RHO_ITERATIONS: u64 = 100_000;
+
+    /// Runs [`Factorize::factorize_with_budget`] of `F` as a stage, under the given name.
+    pub fn strategy<F: Factorize>(name: &'static str) -> Self {
+        Stage::Strategy {
+            name,
+            factorize: F::factorize_with_budget,
+        }
+    }
+
+    /// Returns a divisor of the odd composite `n`, which is trivial if the stage failed.
+    fn split(&self, n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        match *self {
+            Stage::TrialDivision { .. } => {
+                unreachable!("trial division strips every small factor at once in run_stage")
+            }
+            Stage::PollardsRho { iterations } => {
+                let attempt = budget.clone().with_max_steps(iterations);
+                match PollardsRhoBrent::factorize_with_budget(n, &attempt) {
+                    // Running out of iterations only fails this attempt.
+                    Err(GaveUp) if budget.check().is_ok() => Ok(*n),
+                    result => result,
+                }
+            }
+            Stage::PollardsPMinus1 { b1, b2 } => {
+                pollards_p_minus_1::factorize_with_bounds(n, b1, b2, budget)
+            }
+            Stage::EllipticCurveMethod { b1, b2, curves } => {
+                elliptic_curve_method::factorize_with_bounds(n, b1, b2, curves, budget)
+            }
+            Stage::Strategy { factorize, .. } => factorize(n, budget),
+        }
+    }
+
+    /// Replaces the leading parameters of the stage, failing if there are too many.
+    fn with_parameters(self, parameters: &[u64]) -> Option<Self> {
+        let mut parameters = parameters.iter().copied();
+        let mut next_or = |default| parameters.next().unwrap_or(default);
+        let stage = match self {
+            Stage::TrialDivision { limit } => Stage::TrialDivision {
+                limit: next_or(limit),
+            },
+            Stage::PollardsRho { iterations } => Stage::PollardsRho {
+                iterations: next_or(iterations),
+            },
+            Stage::PollardsPMinus1 { b1, b2 } => Stage::PollardsPMinus1 {
+                b1: next_or(b1),
+                b2: next_or(b2),
+            },
+            Stage::EllipticCurveMethod { b1, b2, curves } => Stage::EllipticCurveMethod {
+                b1: next_or(b1),
+                b2: next_or(b2),
+                curves: usize::try_from(next_or(curves as u64)).ok()?,
+            },
+            strategy @ Stage::Strategy { .. } => strategy,
+        };
+        parameters.next().is_none().then_some(stage)
+    }
+}
+
+/// Shown as the name and parameters that [`Stage::from_str`] accepts.
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::TrialDivision { limit } => write!(f, "trial_division:{limit}"),
+            Stage::PollardsRho { iterations } => write!(f, "pollards_rho:{iterations}"),
+            Stage::PollardsPMinus1 { b1, b2 } => write!(f, "pollards_p_minus_1:{b1}:{b2}"),
+            Stage::EllipticCurveMethod { b1, b2, curves } => write!(f, "ecm:{b1}:{b2}:{curves}"),
+            Stage::Strategy { name, .. } => write!(f, "{name}"),
+        }
+    }
+}
+
+/// Parses a stage name followed by optional parameters separated by colons, such as
+/// `trial_division:1000000`, `pollards_rho:100000`, `pollards_p_minus_1:10000:1000000` and
+/// `ecm:50000:5000000:25`. Missing parameters take their defaults. The strategies without
+/// parameters are `cfrac`, `dixon`, `hart_one_line`, `lehman`, `quadratic_sieve`, `squfof`
+/// and `williams_p_plus_1`.
+impl FromStr for Stage {
+    type Err = ParsePipelineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(':');
+        let name = parts.next().unwrap_or_default();
+        let parameters = parts
+            .map(|p| p.parse::<u64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ParsePipelineError::InvalidParameters(s.to_string()))?;
+        let stage = match name {
+            "trial_division" => Stage::TrialDivision {
+                limit: Self::DEFAULT_TRIAL_DIVISION_LIMIT,
+            },
+            "pollards_rho" => Stage::PollardsRho {
+                iterations: Self::DEFAULT_RHO_ITERATIONS,
+            },
+            "pollards_p_minus_1" => Stage::PollardsPMinus1 {
+                b1: 10_000,
+                b2: 1_000_000,
+            },
+            "ecm" => Stage::EllipticCurveMethod {
+                b1: 50_000,
+                b2: 5_000_000,
+                curves: 25,
+            },
+            "cfrac" => Stage::strategy::<algorithms::Cfrac>("cfrac"),
+            "dixon" => Stage::strategy::<algorithms::Dixon>("dixon"),
+            "hart_one_line" => Stage::strategy::<algorithms::HartOneLine>("hart_one_line"),
+            "lehman" => Stage::strategy::<algorithms::Lehman>("lehman"),
+            "quadratic_sieve" => Stage::strategy::<algorithms::QuadraticSieve>("quadratic_sieve"),
+            "squfof" => Stage::strategy::<algorithms::Squfof>("squfof"),
+            "williams_p_plus_1" => {
+                Stage::strategy::<algorithms::WilliamsPPlus1>("williams_p_plus_1")
+            }
+            _ => return Err(ParsePipelineError::UnknownStage(name.to_string())),
+        };
+        stage
+            .with_parameters(&parameters)
+            .ok_or_else(|| ParsePipelineError::InvalidParameters(s.to_string()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParsePipelineError {
+    Empty,
+    UnknownStage(String),
+    InvalidParameters(String),
+}
+
+impl fmt::Display for ParsePipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePipelineError::Empty => write!(f, "the pipeline has no stages"),
+            ParsePipelineError::UnknownStage(name) => write!(f, "unknown stage `{name}`"),
+            ParsePipelineError::InvalidParameters(stage) => {
+                write!(f, "invalid parameters in stage `{stage}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParsePipelineError {}
+
+/// Runs a sequence of [`Stage`]s, handing every cofactor that a stage could not split to the
+/// next one. Each cofactor found is tested with `P`, so that only composites move on, and a
+/// stage keeps splitting the composites it finds until it fails on all of them.
+pub struct Pipeline<P: PrimalityTest = MillerRabin> {
+    stages: Vec<Stage>,
+    _prime_tester: PhantomData<P>,
+}
+
+impl<P: PrimalityTest> Pipeline<P> {
+    /// The number of times each stage is run on a cofactor before it is handed on, which
+    /// gives the randomized methods a few different starting points.
+    pub const ATTEMPTS: usize = 3;
+
+    pub fn new() -> Self {
+        Self {
+            stages: vec![],
+            _prime_tester: PhantomData,
+        }
+    }
+
+    pub fn stage(mut self, stage: Stage) -> Self {
+        self.stages.push(stage);
+        self
+    }
+
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+
+    /// Panics if some cofactor is left composite after the last stage, see
+    /// [`Pipeline::try_prime_factorization`].
+    pub fn prime_factorization(&self, n: &U512) -> Vec<U512> {
+        self.try_prime_factorization(n)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_prime_factorization(&self, n: &U512) -> Result<Vec<U512>, FactorizationError> {
+        self.try_prime_factorization_with_budget(n, &Budget::unlimited())
+    }
+
+    /// Does the same as [`Pipeline::try_prime_factorization`], but skips the remaining stages
+    /// once the budget runs out. Gives up on the whole of `n` if the budget has already run
+    /// out.
+    pub fn try_prime_factorization_with_budget(
+        &self,
+        n: &U512,
+        budget: &Budget,
+    ) -> Result<Vec<U512>, FactorizationError> {
+        if budget.check().is_err() {
+            return Err(FactorizationError::gave_up_on(n));
+        }
+        if n <= &U512::ONE {
+            return Ok(vec![*n]);
+        }
+        let mut factors = vec![];
+        let mut m = *n;
+        while m.is_even() {
+            factors.push(U512::TWO);
+            m /= U512::TWO;
+        }
+        let mut composites = vec![];
+        self.sort_out(m, budget, &mut factors, &mut composites);
+        let mut gave_up = false;
+        for stage in &self.stages {
+            let mut unsplit = vec![];
+            for composite in composites {
+                if gave_up {
+                    unsplit.push(composite);
+                    continue;
+                }
+                gave_up = self
+                    .run_stage(stage, composite, budget, &mut factors, &mut unsplit)
+                    .is_err();
+            }
+            composites = unsplit;
+        }
+        if composites.is_empty() {
+            Ok(factors)
+        } else {
+            Err(FactorizationError {
+                number: *n,
+                factors,
+                cofactors: composites,
+                gave_up,
+            })
+        }
+    }
+
+    /// Leaves the composites that the stage could not split in `unsplit`, including those it
+    /// had not got to when the budget ran out.
+    fn run_stage(
+        &self,
+        stage: &Stage,
+        n: U512,
+        budget: &Budget,
+        factors: &mut Vec<U512>,
+        unsplit: &mut Vec<U512>,
+    ) -> Result<(), GaveUp> {
+        budget.report(Event::StageStarted {
+            stage: stage.to_string(),
+            n,
+        });
+        if let Stage::TrialDivision { limit } = *stage {
+            let (small_factors, rest) = TrialDivision::strip_small_factors(&n, limit);
+            for &factor in &small_factors {
+                budget.report(Event::FactorFound { n, factor });
+            }
+            factors.extend(small_factors);
+            self.sort_out(rest, budget, factors, unsplit);
+            return Ok(());
+        }
+        let mut pending = vec![n];
+        while let Some(n) = pending.pop() {
+            let mut divisor = None;
+            for _ in 0..Self::ATTEMPTS {
+                match stage.split(&n, budget) {
+                    Ok(d) if !d.is_one() && d != n => {
+                        divisor = Some(d);
+                        break;
+                    }
+                    Ok(_) => {}
+                    Err(gave_up) => {
+                        unsplit.push(n);
+                        unsplit.append(&mut pending);
+                        return Err(gave_up);
+                    }
+                }
+            }
+            match divisor {
+                Some(d) => {
+                    budget.report(Event::FactorFound { n, factor: d });
+                    self.sort_out(n / d, budget, factors, &mut pending);
+                    self.sort_out(d, budget, factors, &mut pending);
+                }
+                None => unsplit.push(n),
+            }
+        }
+        Ok(())
+    }
+
+    fn sort_out(
+        &self,
+        n: U512,
+        budget: &Budget,
+        factors: &mut Vec<U512>,
+        composites: &mut Vec<U512>,
+    ) {
+        if n.is_one() {
+            return;
+        }
+        if word::is_prime_narrowest::<P>(&n) {
+            budget.report(Event::CofactorPrime { cofactor: n });
+            factors.push(n);
+        } else {
+            composites.push(n);
+        }
+    }
+}
+
+/// Trial division up to 10⁶, Pollard's rho, Pollard's p − 1 and then the elliptic curve
+/// method, each with the default parameters of [`Stage::from_str`].
+impl<P: PrimalityTest> Default for Pipeline<P> {
+    fn default() -> Self {
+        "trial_division,pollards_rho,pollards_p_minus_1,ecm"
+            .parse()
+            .unwrap()
+    }
+}
+
+/// Parses comma-separated stages, such as `trial_division:1000000,pollards_rho,ecm`.
+impl<P: PrimalityTest> FromStr for Pipeline<P> {
+    type Err = ParsePipelineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParsePipelineError::Empty);
+        }
+        s.split(',')
+            .map(str::parse)
+            .try_fold(Self::new(), |pipeline, stage| Ok(pipeline.stage(stage?)))
+    }
+}
+
+impl<P: PrimalityTest> fmt::Display for Pipeline<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stages = self
+            .stages
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "{}", stages.join(","))
+    }
+}
+
+impl<P: PrimalityTest> Command for Pipeline<P> {
+    fn run(&self, n: &U512) -> String {
+        match self.try_run(n, &Budget::unlimited()) {
+            Ok(output) => output,
+            Err(error) => error.to_string(),
+        }
+    }
+
+    fn try_run(&self, n: &U512, budget: &Budget) -> Result<String, FactorizationError> {
+        let factors = self.try_prime_factorization_with_budget(n, budget)?;
+        Ok(Factorization::new(n, factors).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budget::CancellationToken;
+    use crate::primality_test::BailliePsw;
+    use std::sync::{Arc, Mutex};
+
+    fn sorted(mut factors: Vec<U512>) -> Vec<U512> {
+        factors.sort();
+        factors
+    }
+
+    fn numbers(numbers: &[u128]) -> Vec<U512> {
+        numbers.iter().map(|&n| U512::from(n)).collect()
+    }
+
+    #[test]
+    fn default_pipeline() {
+        let pipeline = Pipeline::<BailliePsw>::default();
+        for (n, factors) in [
+            (1u128, vec![1]),
+            (2, vec![2]),
+            (8051, vec![83, 97]),
+            (4096, vec![2; 12]),
+            (1000000016000000063, vec![1000000007, 1000000009]),
+            (
+                1001000000350333000005661,
+                vec![1000000000333, 1001000000017],
+            ),
+        ] {
+            assert_eq!(
+                sorted(pipeline.prime_factorization(&U512::from(n))),
+                numbers(&factors),
+                "n = {n}"
+            );
+        }
+    }
+
+    #[test]
+    fn cofactors_move_to_later_stages() {
+        // Trial division finds 3 and 5, and leaves 1000000007 × 1000000009 to Pollard's rho.
+        let pipeline = Pipeline::<BailliePsw>::new()
+            .stage(Stage::TrialDivision { limit: 100 })
+            .stage(Stage::PollardsRho {
+                iterations: 1_000_000,
+            });
+        let n = U512::from(15 * 1000000016000000063u128);
+        assert_eq!(
+            sorted(pipeline.prime_factorization(&n)),
+            numbers(&[3, 5, 1000000007, 1000000009])
+        );
+    }
+
+    #[test]
+    fn reports_cofactors_left_after_the_last_stage() {
+        let pipeline = Pipeline::<BailliePsw>::new().stage(Stage::TrialDivision { limit: 100 });
+        let n = U512::from(12 * 1000000016000000063u128);
+        let error = pipeline.try_prime_factorization(&n).unwrap_err();
+        assert_eq!(sorted(error.factors), numbers(&[2, 2, 3]));
+        assert_eq!(error.cofactors, numbers(&[1000000016000000063]));
+        assert_eq!(error.number, n);
+    }
+
+    #[test]
+    fn gives_up_when_the_budget_runs_out() {
+        let pipeline = Pipeline::<BailliePsw>::new()
+            .stage(Stage::TrialDivision { limit: 100 })
+            .stage(Stage::strategy::<algorithms::FermatsFactorizationMethod>(
+                "fermats_factorization_method",
+            ))
+            .stage(Stage::PollardsRho {
+                iterations: 1_000_000,
+            });
+        // Fermat's method would need about 10¹⁸ steps for 1000003 × 1000000016000000063.
+        let n = U512::from(7 * 1000003 * 1000000016000000063u128);
+        let budget = Budget::unlimited().with_max_steps(10_000);
+        let error = pipeline
+            .try_prime_factorization_with_budget(&n, &budget)
+            .unwrap_err();
+        assert!(error.gave_up);
+        assert_eq!(error.factors, numbers(&[7]));
+        assert_eq!(error.cofactors, numbers(&[1000003 * 1000000016000000063]));
+    }
+
+    #[test]
+    fn gives_up_at_once_on_an_exhausted_budget() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let budget = Budget::unlimited().with_cancellation(token);
+        let n = U512::from(2 * 8051u16);
+        let error =
+            Pipeline::<BailliePsw>::default().try_prime_factorization_with_budget(&n, &budget);
+        // Not even the factors of 2 are divided out once the budget has run out.
+        assert_eq!(error, Err(FactorizationError::gave_up_on(&n)));
+    }
+
+    #[test]
+    fn reports_progress() {
+        let events = Arc::new(Mutex::new(vec![]));
+        let observed = Arc::clone(&events);
+        let budget = Budget::unlimited().with_observer(Arc::new(move |event: &Event| {
+            observed.lock().unwrap().push(event.clone())
+        }));
+        let pipeline: Pipeline<BailliePsw> = "trial_division:10,pollards_rho".parse().unwrap();
+        let n = U512::from(7 * 8051u32);
+        pipeline
+            .try_prime_factorization_with_budget(&n, &budget)
+            .unwrap();
+        let events = events.lock().unwrap();
+        let stages = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::StageStarted { stage, n } => Some((stage.as_str(), *n)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            stages,
+            [
+                ("trial_division:10", n),
+                ("pollards_rho:100000", U512::from(8051u16))
+            ]
+        );
+        assert!(events.contains(&Event::FactorFound {
+            n,
+            factor: U512::SEVEN
+        }));
+        let primes = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::CofactorPrime { cofactor } => Some(*cofactor),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(sorted(primes), numbers(&[83, 97]));
+    }
+
+    #[test]
+    fn strategy_stages() {
+        let pipeline = Pipeline::<BailliePsw>::new()
+            .stage(Stage::strategy::<algorithms::Squfof>("squfof"))
+            .stage(Stage::strategy::<algorithms::Lehman>("lehman"));
+        assert_eq!(
+            sorted(pipeline.prime_factorization(&U512::from(455459u32))),
+            numbers(&[613, 743])
+        );
+        assert_eq!(pipeline.to_string(), "squfof,lehman");
+    }
+
+    #[test]
+    fn parse_stages() {
+        let pipeline = "trial_division:1000,pollards_rho,ecm:2000:100000"
+            .parse::<Pipeline>()
+            .unwrap();
+        assert_eq!(
+            pipeline.to_string(),
+            "trial_division:1000,pollards_rho:100000,ecm:2000:100000:25"
+        );
+        assert_eq!(
+            Pipeline::<MillerRabin>::default().to_string(),
+            "trial_division:1000000,pollards_rho:100000,pollards_p_minus_1:10000:1000000,\
+             ecm:50000:5000000:25"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |s: &str| s.parse::<Pipeline>().err();
+        assert_eq!(parse(""), Some(ParsePipelineError::Empty));
+        assert_eq!(
+            parse("trial_division,rho"),
+            Some(ParsePipelineError::UnknownStage("rho".to_string()))
+        );
+        assert_eq!(
+            parse("trial_division:ten"),
+            Some(ParsePipelineError::InvalidParameters(
+                "trial_division:ten".to_string()
+            ))
+        );
+        assert_eq!(
+            parse("squfof:5"),
+            Some(ParsePipelineError::InvalidParameters(
+                "squfof:5".to_string()
+            ))
+        );
+    }
+}
@@ -1,4 +1,8 @@
+use crate::budget::Budget;
+use crate::factorization::FactorizationError;
+use crate::observer::Event;
 use crate::traits::{Factorize, PrimalityTest, PrimeFactorization};
+use crate::word::Word;
 use bnum::types::U512;
 use num_integer::Integer;
 use std::marker::PhantomData;
@@ -20,8 +24,19 @@ where
     PrimeTester: PrimalityTest,
 {
     fn prime_factorization(n: &U512) -> Vec<U512> {
-        let max_successive_failures = 100;
-        Self::new(max_successive_failures).recursive_factorization(*n)
+        Self::new(Self::DEFAULT_MAX_SUCCESSIVE_FAILS).recursive_factorization(*n)
+    }
+
+    fn try_prime_factorization(n: &U512) -> Result<Vec<U512>, FactorizationError> {
+        Self::new(Self::DEFAULT_MAX_SUCCESSIVE_FAILS).try_recursive_factorization(*n)
+    }
+
+    fn try_prime_factorization_with_budget(
+        n: &U512,
+        budget: &Budget,
+    ) -> Result<Vec<U512>, FactorizationError> {
+        Self::new(Self::DEFAULT_MAX_SUCCESSIVE_FAILS)
+            .try_recursive_factorization_with_budget(*n, budget)
     }
 }
 
@@ -30,7 +45,11 @@ where
     Factorizer: Factorize,
     PrimeTester: PrimalityTest,
 {
-    fn new(max_successive_fails: usize) -> Self {
+    pub const DEFAULT_MAX_SUCCESSIVE_FAILS: usize = 100;
+
+    /// Gives up on a composite once the factorizer fails to split it `max_successive_fails`
+    /// times in a row.
+    pub fn new(max_successive_fails: usize) -> Self {
         Self {
             max_successive_fails,
             _factorizer: PhantomData,
@@ -38,65 +57,141 @@ where
         }
     }
 
-    fn recursive_factorization(&self, mut n: U512) -> Vec<U512> {
-        let mut factors = vec![];
-        while n.is_even() {
-            factors.push(U512::TWO);
+    /// Panics if a composite cannot be split, see
+    /// [`RecursivePrimeFactorization::try_recursive_factorization`].
+    pub fn recursive_factorization(&self, n: U512) -> Vec<U512> {
+        self.try_recursive_factorization(n).unwrap_or_else(|error| {
+            panic!(
+                "Failed to find factor after {} successive attempts. {error}",
+                self.max_successive_fails
+            )
+        })
+    }
+
+    /// Carries on with the other cofactors when a composite cannot be split, and returns the
+    /// prime factors found together with the composites that were given up on.
+    pub fn try_recursive_factorization(&self, n: U512) -> Result<Vec<U512>, FactorizationError> {
+        self.try_recursive_factorization_with_budget(n, &Budget::unlimited())
+    }
+
+    /// Does the same as [`RecursivePrimeFactorization::try_recursive_factorization`], but
+    /// also gives up on the composites left when the budget runs out. Gives up on the whole of
+    /// `n` if the budget has already run out.
+    pub fn try_recursive_factorization_with_budget(
+        &self,
+        mut n: U512,
+        budget: &Budget,
+    ) -> Result<Vec<U512>, FactorizationError> {
+        if budget.check().is_err() {
+            return Err(FactorizationError::gave_up_on(&n));
+        }
+        let number = n;
+        let mut progress = Progress::default();
+        while n.is_even() && !n.is_zero() {
+            progress.factors.push(U512::TWO);
             n /= &U512::TWO;
         }
-        self.recursion_step(n, &mut factors, 0);
-        factors
+        self.recursion_step(n, budget, &mut progress, 0);
+        if progress.cofactors.is_empty() {
+            Ok(progress.factors)
+        } else {
+            Err(FactorizationError {
+                number,
+                factors: progress.factors,
+                cofactors: progress.cofactors,
+                gave_up: progress.gave_up,
+            })
+        }
     }
 
-    fn recursion_step(&self, n: U512, factors: &mut Vec<U512>, retried: usize) {
-        if retried == self.max_successive_fails {
-            panic![
-                "Failed to find factor after {0} succesive attempts",
-                self.max_successive_fails
-            ]
+    /// Continues on the narrowest of `u64`, `u128` and `U512` that holds `n`, so that small
+    /// cofactors are handled with native arithmetic.
+    fn recursion_step(&self, n: U512, budget: &Budget, progress: &mut Progress, retried: usize) {
+        if let Some(n) = u64::from_u512(&n) {
+            self.word_recursion_step(n, budget, progress, retried)
+        } else if let Some(n) = u128::from_u512(&n) {
+            self.word_recursion_step(n, budget, progress, retried)
+        } else {
+            self.word_recursion_step(n, budget, progress, retried)
+        }
+    }
+
+    fn word_recursion_step<W: Word>(
+        &self,
+        n: W,
+        budget: &Budget,
+        progress: &mut Progress,
+        retried: usize,
+    ) {
+        if n <= W::one() {
+            return;
+        }
+        if PrimeTester::is_prime_word(n) {
+            budget.report(Event::CofactorPrime {
+                cofactor: n.to_u512(),
+            });
+            progress.factors.push(n.to_u512());
+            return;
         }
-        if n <= U512::ONE {
+        if retried == self.max_successive_fails {
+            progress.cofactors.push(n.to_u512());
             return;
         }
-        if PrimeTester::is_prime(&n) {
-            factors.push(n);
+        let Ok(factor) = Factorizer::factorize_word_with_budget(n, budget) else {
+            progress.cofactors.push(n.to_u512());
+            progress.gave_up = true;
             return;
+        };
+        let divisor = self.classify_factor(factor, n);
+        if !matches!(divisor, DivisorOfN::Trivial) {
+            budget.report(Event::FactorFound {
+                n: n.to_u512(),
+                factor: factor.to_u512(),
+            });
         }
-        match self.classify_factor(Factorizer::factorize(&n), &n) {
-            DivisorOfN::Trivial => self.recursion_step(n, factors, retried + 1),
+        match divisor {
+            DivisorOfN::Trivial => self.word_recursion_step(n, budget, progress, retried + 1),
             DivisorOfN::Prime(p) => {
-                factors.push(p);
-                self.recursion_step(n / p, factors, 0);
+                progress.factors.push(p.to_u512());
+                self.recursion_step((n / p).to_u512(), budget, progress, 0);
             }
             DivisorOfN::Composite(d) => {
-                self.recursion_step(n / d, factors, 0);
-                self.recursion_step(d, factors, 0);
+                self.recursion_step((n / d).to_u512(), budget, progress, 0);
+                self.recursion_step(d.to_u512(), budget, progress, 0);
             }
         }
     }
 
-    fn classify_factor(&self, factor: U512, n: &U512) -> DivisorOfN {
-        if PrimeTester::is_prime(&factor) {
+    fn classify_factor<W: Word>(&self, factor: W, n: W) -> DivisorOfN<W> {
+        if PrimeTester::is_prime_word(factor) {
             return DivisorOfN::Prime(factor);
         }
-        if factor.is_one() || &factor == n {
-            return DivisorOfN::Trivial
-;
+        if factor.is_one() || factor == n {
+            return DivisorOfN::Trivial;
         }
         DivisorOfN::Composite(factor)
     }
 }
 
-enum DivisorOfN {
-    Prime(U512),
-    Composite(U512),
+#[derive(Default)]
+struct Progress {
+    factors: Vec<U512>,
+    cofactors: Vec<U512>,
+    gave_up: bool,
+}
+
+enum DivisorOfN<W> {
+    Prime(W),
+    Composite(W),
     Trivial,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::budget::CancellationToken;
     use crate::test_framework::prime_factorization::CheckTestBuilder;
+    use rustic_factors_derive::RecursivePrimeFactorization;
 
     struct FakePrimeTester;
 
@@ -165,4 +260,85 @@ mod tests {
             .build::<MyTestOrchestrator>()
             .check_cases()
     }
+
+    #[test]
+    fn reports_unsplit_cofactors() {
+        // 7 × 7 × 11 is left whole, since the fake factorizer cannot split it.
+        let n = U512::from(2 * 3 * 5 * 539u16);
+        let error = MyTestOrchestrator::try_prime_factorization(&n).unwrap_err();
+        let mut factors = error.factors;
+        factors.sort();
+        assert_eq!(factors, [U512::TWO, U512::THREE, U512::FIVE]);
+        assert_eq!(error.cofactors, [U512::from(539u16)]);
+        assert_eq!(error.number, n);
+    }
+
+    #[test]
+    fn try_prime_factorization_succeeds() {
+        assert_eq!(
+            MyTestOrchestrator::try_prime_factorization(&U512::from(30u8)),
+            Ok(vec![U512::TWO, U512::THREE, U512::FIVE])
+        );
+    }
+
+    #[test]
+    fn gives_up_when_the_budget_runs_out() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let budget = Budget::unlimited().with_cancellation(token);
+        let n = U512::from(2 * 3 * 5u8);
+        let error = MyTestOrchestrator::try_prime_factorization_with_budget(&n, &budget);
+        // Not even the factors of 2 are divided out once the budget has run out.
+        assert_eq!(error, Err(FactorizationError::gave_up_on(&n)));
+    }
+
+    #[derive(RecursivePrimeFactorization)]
+    struct DerivedWithDefaults;
+
+    #[derive(RecursivePrimeFactorization)]
+    #[prime_tester(FakePrimeTester)]
+    #[max_successive_fails = 3]
+    struct DerivedWithFakePrimeTester;
+
+    impl Factorize for DerivedWithDefaults {
+        fn factorize(n: &U512) -> U512 {
+            FakeFactorizer::factorize(n)
+        }
+    }
+
+    impl Factorize for DerivedWithFakePrimeTester {
+        fn factorize(n: &U512) -> U512 {
+            FakeFactorizer::factorize(n)
+        }
+    }
+
+    #[test]
+    fn derived_with_default_prime_tester() {
+        CheckTestBuilder::new()
+            .case(30, &[2, 3, 5])
+            .case(105, &[3, 5, 7])
+            .build::<DerivedWithDefaults>()
+            .check_cases()
+    }
+
+    #[test]
+    fn derived_with_prime_tester_attribute() {
+        CheckTestBuilder::new()
+            .case(30, &[2, 3, 5])
+            .build::<DerivedWithFakePrimeTester>()
+            .check_cases()
+    }
+
+    #[test]
+    #[should_panic(expected = "after 3 successive attempts")]
+    fn derived_with_max_successive_fails_attribute() {
+        // The fake prime tester does not recognize 7, which the fake factorizer cannot split.
+        DerivedWithFakePrimeTester::prime_factorization(&U512::from(105u8));
+    }
+
+    #[test]
+    fn derived_try_prime_factorization_uses_max_successive_fails() {
+        let error = DerivedWithFakePrimeTester::try_prime_factorization(&U512::from(105u8));
+        assert_eq!(error.unwrap_err().cofactors, [U512::SEVEN]);
+    }
 }
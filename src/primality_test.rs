@@ -1,2 +1,14 @@
+mod aks;
+mod baillie_psw;
+pub mod certificate;
+mod fermat;
 mod miller_rabin;
+mod quadratic_frobenius;
+mod solovay_strassen;
+pub use aks::Aks;
+pub use baillie_psw::BailliePsw;
+pub use certificate::PrimalityCertificate;
+pub use fermat::FermatTest;
 pub use miller_rabin::MillerRabin;
+pub use quadratic_frobenius::QuadraticFrobenius;
+pub use solovay_strassen::SolovayStrassen;
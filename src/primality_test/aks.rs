@@ -0,0 +1,158 @@
+use crate::traits::PrimalityTest;
+use crate::word::{self, Word};
+use bnum::types::U512;
+use num_integer::Integer;
+use rustic_factors_derive::PrimalityCommand;
+
+/// The Agrawal–Kayal–Saxena test, the first deterministic primality test proven to run in
+/// polynomial time.
+///
+/// With the least `r` such that the order of `n` modulo `r` exceeds `log₂² n`, it rules out
+/// factors up to `r` and checks `(x + a)^n = x^n + a` in `(Z/nZ)[x] / (x^r - 1)` for every
+/// `a` up to `sqrt(φ(r)) log₂ n`. The polynomials have `r` coefficients, so this reference
+/// implementation is only practical for numbers of a few dozen bits.
+#[derive(PrimalityCommand)]
+pub struct Aks;
+
+impl PrimalityTest for Aks {
+    fn is_prime(p: &U512) -> bool {
+        word::is_prime_narrowest::<Self>(p)
+    }
+
+    fn is_prime_word<W: Word>(n: W) -> bool {
+        if n < W::two() || is_perfect_power(n) {
+            return false;
+        }
+        let log2_n = log2(n);
+        let r = smallest_modulus_of_large_order(n, log2_n * log2_n);
+        for a in (2..=r).map(W::from).take_while(|&a| a < n) {
+            if a.gcd(&n) != W::one() {
+                return false;
+            }
+        }
+        if n <= W::from(r) {
+            return true;
+        }
+        let limit = ((euler_phi(r) as f64).sqrt() * log2_n) as u64;
+        (1..=limit).all(|a| satisfies_congruence(n, W::from(a), r as usize))
+    }
+}
+
+fn is_perfect_power<W: Word>(n: W) -> bool {
+    (2..W::BITS).any(|exponent| {
+        let root = n.nth_root(exponent);
+        root > W::one() && (0..exponent).fold(W::one(), |power, _| power * root) == n
+    })
+}
+
+fn log2<W: Word>(n: W) -> f64 {
+    let n = n.to_u512();
+    let shift = n.bits().saturating_sub(f64::MANTISSA_DIGITS);
+    (u64::try_from(n >> shift).unwrap() as f64).log2() + shift as f64
+}
+
+/// Returns the least `r` coprime to `n` such that the order of `n` modulo `r` exceeds `bound`.
+fn smallest_modulus_of_large_order<W: Word>(n: W, bound: f64) -> u64 {
+    let max_order = bound as u64;
+    (2..)
+        .find(|&r| {
+            let n_mod_r = n.rem_u64(r);
+            if n_mod_r.gcd(&r) != 1 {
+                return false;
+            }
+            let mut power = 1;
+            (1..=max_order).all(|_| {
+                power = power * n_mod_r % r;
+                power != 1
+            })
+        })
+        .unwrap()
+}
+
+fn euler_phi(mut r: u64) -> u64 {
+    let mut phi = r;
+    let mut p = 2;
+    while p * p <= r {
+        if r.is_multiple_of(p) {
+            while r.is_multiple_of(p) {
+                r /= p;
+            }
+            phi -= phi / p;
+        }
+        p += 1;
+    }
+    if r > 1 {
+        phi -= phi / r;
+    }
+    phi
+}
+
+/// Checks `(x + a)^n = x^(n mod r) + a` modulo `x^r - 1` and `n`.
+fn satisfies_congruence<W: Word>(n: W, a: W, r: usize) -> bool {
+    let a = a % n;
+    let mut base = vec![W::zero(); r];
+    base[0] = a;
+    base[1] = W::one();
+    let mut power = vec![W::zero(); r];
+    power[0] = W::one();
+    let mut exp = n;
+    while !exp.is_zero() {
+        if exp.is_odd() {
+            power = multiply(&power, &base, n);
+        }
+        base = multiply(&base, &base, n);
+        exp = exp / W::two();
+    }
+    let mut expected = vec![W::zero(); r];
+    expected[n.rem_u64(r as u64) as usize] = W::one();
+    expected[0] = expected[0].add_mod(a, n);
+    power == expected
+}
+
+/// Multiplies polynomials of degree below `r` modulo `x^r - 1` and `n`.
+fn multiply<W: Word>(a: &[W], b: &[W], n: W) -> Vec<W> {
+    let r = a.len();
+    let mut product = vec![W::zero(); r];
+    for (i, &x) in a.iter().enumerate().filter(|(_, x)| !x.is_zero()) {
+        for (j, &y) in b.iter().enumerate() {
+            let k = (i + j) % r;
+            product[k] = product[k].add_mod(x.mul_mod(y, n), n);
+        }
+    }
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_framework::pseudoprimes;
+
+    #[test]
+    fn agrees_with_sieve() {
+        let primes = crate::primes::sieve_of_eratosthenes(200);
+        for n in 0..200u64 {
+            assert_eq!(
+                Aks::is_prime_word(n),
+                primes.binary_search(&n).is_ok(),
+                "{n}"
+            );
+        }
+    }
+
+    #[test]
+    fn composites_without_small_factors() {
+        // The factors exceed r = 409, so only the polynomial congruence catches these.
+        for composite in [1009u64 * 1013, 1019 * 1021] {
+            assert!(!Aks::is_prime_word(composite), "{composite}");
+        }
+        assert!(!satisfies_congruence(1009u64 * 1013, 1, 409));
+        assert!(!Aks::is_prime_word(1031u64 * 1031 * 1031));
+    }
+
+    #[test]
+    fn carmichael_numbers() {
+        for carmichael in pseudoprimes::CARMICHAEL_NUMBERS {
+            assert!(!Aks::is_prime_word(carmichael), "{carmichael}");
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use super::miller_rabin::composite_evidence::{CompositeEvidence, Decomposed};
+use crate::lucas;
+use crate::modular::{self, MontgomeryContext};
+use crate::traits::PrimalityTest;
+use crate::word::{self, Word};
+use bnum::types::U512;
+use rustic_factors_derive::PrimalityCommand;
+
+/// The Baillie–PSW test: a strong probable prime test to base 2 followed by a strong Lucas
+/// probable prime test with Selfridge's parameters.
+///
+/// Unlike [`MillerRabin`](super::MillerRabin) it is deterministic and needs only the
+/// equivalent of about three rounds. No composite passing both tests is known, and there is
+/// none below `2^64`.
+#[derive(PrimalityCommand)]
+pub struct BailliePsw;
+
+impl PrimalityTest for BailliePsw {
+    fn is_prime(p: &U512) -> bool {
+        word::is_prime_narrowest::<Self>(p)
+    }
+
+    fn is_prime_word<W: Word>(p: W) -> bool {
+        if let Some(is_prime) = check_small_primes(p) {
+            return is_prime;
+        }
+        !CompositeEvidence::new(p).witnessed_by(&W::two()) && is_strong_lucas_probable_prime(p)
+    }
+}
+
+const SMALL_PRIMES: [u64; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+/// Decides `p` by trial division by the primes below 53 if that settles it, which it does
+/// whenever `p < 53²` or `p` has a factor below 53.
+pub(super) fn check_small_primes<W: Word>(p: W) -> Option<bool> {
+    if p < W::two() {
+        return Some(false);
+    }
+    for small_prime in SMALL_PRIMES {
+        if p == W::from(small_prime) {
+            return Some(true);
+        }
+        if p.rem_u64(small_prime) == 0 {
+            return Some(false);
+        }
+    }
+    (p < W::from(53u64 * 53)).then_some(true)
+}
+
+/// Expects an odd `n` without small prime factors.
+fn is_strong_lucas_probable_prime<W: Word>(n: W) -> bool {
+    let Some((p, q)) = selfridge_parameters(n) else {
+        return false;
+    };
+    let context = MontgomeryContext::new(n);
+    let (p, q) = (context.to_montgomery(p), context.to_montgomery(q));
+    let n_plus_1 = Decomposed::new(n + W::one());
+    let (u, mut v, mut q_k) = lucas::lucas_sequences(&context, p, q, n_plus_1.odd_factor);
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+    for _ in 1..n_plus_1.exponent_of_2 {
+        v = context.sub(context.square(v), context.add(q_k, q_k));
+        if v.is_zero() {
+            return true;
+        }
+        q_k = context.square(q_k);
+    }
+    false
+}
+
+/// Selfridge's method A: `P = 1` and `Q = (1 - D) / 4` for the first `D` in
+/// `5, -7, 9, -11, ...` with `(D/n) = -1`, returned as residues modulo `n`. Returns `None`
+/// if a `D` sharing a factor with `n`, or the fact that `n` is a perfect square, shows that
+/// `n` is composite.
+pub(super) fn selfridge_parameters<W: Word>(n: W) -> Option<(W, W)> {
+    let root = n.sqrt();
+    if root * root == n {
+        return None;
+    }
+    let residue = |value: i64| {
+        let magnitude = W::from(value.unsigned_abs()) % n;
+        if value < 0 && !magnitude.is_zero() {
+            n - magnitude
+        } else {
+            magnitude
+        }
+    };
+    let mut d = 5i64;
+    loop {
+        match modular::jacobi_symbol(residue(d), n) {
+            -1 => return Some((W::one(), residue((1 - d) / 4))),
+            0 => return None,
+            _ => d = if d > 0 { -(d + 2) } else { -d + 2 },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::PollardsRhoBrent;
+    use crate::orchestration::RecursivePrimeFactorization;
+    use crate::primality_test::MillerRabin;
+    use crate::test_framework::prime_factorization::CheckTestBuilder;
+    use crate::test_framework::pseudoprimes;
+
+    fn check(p: u64, expected: bool) {
+        assert_eq!(
+            BailliePsw::is_prime(&U512::from(p)),
+            expected,
+            "Test failed for {}",
+            p
+        )
+    }
+
+    #[test]
+    fn agrees_with_sieve() {
+        let primes = crate::primes::sieve_of_eratosthenes(20_000);
+        for n in 0..20_000 {
+            check(n, primes.binary_search(&n).is_ok());
+        }
+    }
+
+    #[test]
+    fn strong_pseudoprimes_to_base_2() {
+        for composite in pseudoprimes::STRONG_PSEUDOPRIMES_TO_BASE_2 {
+            check(composite, false);
+        }
+    }
+
+    #[test]
+    fn strong_lucas_pseudoprimes() {
+        for composite in pseudoprimes::STRONG_LUCAS_PSEUDOPRIMES {
+            check(composite, false);
+        }
+    }
+
+    #[test]
+    fn primes_and_squares_of_primes() {
+        for prime in [1000000007, 4294967291, u64::MAX - 58] {
+            check(prime, true);
+            let prime = U512::from(prime);
+            assert!(!BailliePsw::is_prime(&(prime * prime)));
+        }
+        assert!(BailliePsw::is_prime_word(u128::MAX - 158));
+    }
+
+    #[test]
+    fn primes_near_512_bits() {
+        let primes = [
+            U512::MAX - U512::from(568u16),
+            U512::power_of_two(511) - U512::from(187u8),
+            U512::power_of_two(500) - U512::from(863u16),
+        ];
+        for prime in primes {
+            assert!(BailliePsw::is_prime(&prime), "Test failed for {}", prime);
+            assert!(!BailliePsw::is_prime(&(prime - U512::TWO)));
+        }
+    }
+
+    #[test]
+    fn agrees_with_miller_rabin() {
+        let start = u64::MAX - 20_000;
+        for n in (start..u64::MAX).step_by(2) {
+            assert_eq!(
+                BailliePsw::is_prime_word(n),
+                <MillerRabin>::is_prime_word(n),
+                "{n}"
+            );
+        }
+    }
+
+    #[test]
+    fn as_prime_tester_for_recursive_factorization() {
+        CheckTestBuilder::default()
+            .build::<RecursivePrimeFactorization<PollardsRhoBrent, BailliePsw>>()
+            .check_cases()
+    }
+}
@@ -0,0 +1,361 @@
+mod ecpp;
+mod n_minus_1;
+
+use super::BailliePsw;
+use crate::traits::{Command, PrimalityTest};
+use bnum::types::U512;
+use num_integer::Roots;
+use std::fmt;
+
+/// A proof that `n` is prime, which [`PrimalityCertificate::verify`] checks without relying
+/// on any probable prime test.
+///
+/// The prime factors used by a proof carry certificates of their own, so a certificate is a
+/// tree whose leaves are small primes proven by trial division.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PrimalityCertificate {
+    /// `n` has no divisor in `2..=sqrt(n)`, which is only accepted for
+    /// `n <= TRIAL_DIVISION_LIMIT`.
+    TrialDivision { n: U512 },
+    /// Pratt's certificate: the complete factorization of `n - 1` and a `witness` whose order
+    /// modulo `n` is `n - 1`, so that the multiplicative group modulo `n` has `n - 1` elements.
+    Pratt {
+        n: U512,
+        witness: U512,
+        factors: Vec<ProvenFactor>,
+    },
+    /// Pocklington's theorem, as extended by Brillhart, Lehmer and Selfridge: a factored part
+    /// `F` of `n - 1` with `F³ >= n`, and for every prime `q | F` a witness `a` with
+    /// `a^(n-1) = 1` and `gcd(a^((n-1)/q) - 1, n) = 1`. Every prime factor of `n` is then
+    /// `1 mod F`, which rules out all but one factorization of `n`.
+    Pocklington {
+        n: U512,
+        factors: Vec<WitnessedFactor>,
+    },
+    /// Atkin and Morain's elliptic curve certificate, see [`EllipticCurveProof`].
+    Ecpp(Box<EllipticCurveProof>),
+}
+
+/// A point `P` on `y² = x³ + ax + b` modulo `n` such that `[cofactor]P` is not the point at
+/// infinity but `[q][cofactor]P` is, for a prime `q > (n^(1/4) + 1)²` proven by the nested
+/// certificate. Modulo a prime factor `p <= sqrt(n)` of `n`, `[cofactor]P` would have order
+/// `q`, which exceeds the largest possible number of points `(sqrt(p) + 1)²`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EllipticCurveProof {
+    pub n: U512,
+    pub a: U512,
+    pub b: U512,
+    pub point: (U512, U512),
+    pub cofactor: U512,
+    pub certificate: PrimalityCertificate,
+}
+
+/// A prime power `q^exponent` dividing `n - 1`, where the certificate proves that `q` is prime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProvenFactor {
+    pub exponent: u32,
+    pub certificate: PrimalityCertificate,
+}
+
+impl ProvenFactor {
+    pub fn prime(&self) -> &U512 {
+        self.certificate.n()
+    }
+}
+
+/// A factor of `F` in a [`PrimalityCertificate::Pocklington`] certificate, together with the
+/// witness for its prime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WitnessedFactor {
+    pub factor: ProvenFactor,
+    pub witness: U512,
+}
+
+impl PrimalityCertificate {
+    pub const TRIAL_DIVISION_LIMIT: u64 = 1 << 20;
+
+    /// Numbers up to this size are left to [`prove`](Self::prove) by
+    /// [`prove_ecpp`](Self::prove_ecpp), since `n - 1` can always be factored.
+    pub const ECPP_HANDOVER_BITS: u32 = 64;
+
+    /// Returns a certificate for `n`, or `None` if `n` is composite or no proof was found.
+    /// When too little of `n - 1` can be factored, this falls back to ECPP.
+    pub fn prove(n: &U512) -> Option<Self> {
+        if *n <= U512::from(Self::TRIAL_DIVISION_LIMIT) {
+            return is_prime_by_trial_division(n).then_some(Self::TrialDivision { n: *n });
+        }
+        if !BailliePsw::is_prime(n) {
+            return None;
+        }
+        n_minus_1::prove(n).or_else(|| ecpp::prove(n))
+    }
+
+    /// Returns a chain of ECPP certificates for `n` down to a prime of at most
+    /// [`ECPP_HANDOVER_BITS`](Self::ECPP_HANDOVER_BITS), or `None` if `n` is composite or no
+    /// proof was found.
+    pub fn prove_ecpp(n: &U512) -> Option<Self> {
+        if n.bits() <= Self::ECPP_HANDOVER_BITS {
+            return Self::prove(n);
+        }
+        if !BailliePsw::is_prime(n) {
+            return None;
+        }
+        ecpp::prove(n)
+    }
+
+    /// Checks the certificate and the certificates of all primes it relies on.
+    pub fn verify(&self) -> bool {
+        match self {
+            Self::TrialDivision { n } => {
+                *n <= U512::from(Self::TRIAL_DIVISION_LIMIT) && is_prime_by_trial_division(n)
+            }
+            Self::Pratt {
+                n,
+                witness,
+                factors,
+            } => {
+                n_minus_1::verify_pratt(n, witness, factors)
+                    && factors.iter().all(|f| f.certificate.verify())
+            }
+            Self::Pocklington { n, factors } => {
+                n_minus_1::verify_pocklington(n, factors)
+                    && factors.iter().all(|f| f.factor.certificate.verify())
+            }
+            Self::Ecpp(proof) => ecpp::verify(proof) && proof.certificate.verify(),
+        }
+    }
+
+    /// The number that the certificate proves to be prime.
+    pub fn n(&self) -> &U512 {
+        match self {
+            Self::TrialDivision { n } | Self::Pratt { n, .. } | Self::Pocklington { n, .. } => n,
+            Self::Ecpp(proof) => &proof.n,
+        }
+    }
+
+    fn write_tree(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+        match self {
+            Self::TrialDivision { n } => writeln!(f, "{indent}{n} is prime by trial division"),
+            Self::Pratt {
+                n,
+                witness,
+                factors,
+            } => {
+                writeln!(
+                    f,
+                    "{indent}{n} is prime by Pratt's certificate with witness {witness}, where {}",
+                    n_minus_1::format_product(&(n - U512::ONE), factors.iter())
+                )?;
+                factors
+                    .iter()
+                    .try_for_each(|factor| factor.certificate.write_tree(f, depth + 1))
+            }
+            Self::Pocklington { n, factors } => {
+                let proven_factors = factors.iter().map(|f| &f.factor);
+                let factored_part = n_minus_1::product(proven_factors.clone()).unwrap();
+                let witnesses = factors
+                    .iter()
+                    .map(|f| format!("{} for {}", f.witness, f.factor.prime()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                writeln!(
+                    f,
+                    "{indent}{n} is prime by Pocklington's theorem with witnesses {witnesses}, \
+                     where {} divides n - 1",
+                    n_minus_1::format_product(&factored_part, proven_factors)
+                )?;
+                factors
+                    .iter()
+                    .try_for_each(|factor| factor.factor.certificate.write_tree(f, depth + 1))
+            }
+            Self::Ecpp(proof) => {
+                let EllipticCurveProof {
+                    n,
+                    a,
+                    b,
+                    point: (x, y),
+                    cofactor,
+                    certificate,
+                } = proof.as_ref();
+                writeln!(
+                    f,
+                    "{indent}{n} is prime by ECPP on y² = x³ + {a}x + {b} with the point \
+                     ({x}, {y}), whose multiple by {cofactor} has order {}",
+                    certificate.n()
+                )?;
+                certificate.write_tree(f, depth + 1)
+            }
+        }
+    }
+}
+
+impl fmt::Display for PrimalityCertificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_tree(f, 0)
+    }
+}
+
+fn is_prime_by_trial_division(n: &U512) -> bool {
+    let Ok(n) = u64::try_from(*n) else {
+        return false;
+    };
+    n >= 2 && (2..=n.sqrt()).all(|d| n % d != 0)
+}
+
+/// Prints a [`PrimalityCertificate`] for the given number.
+pub struct ProvePrime;
+
+impl Command for ProvePrime {
+    fn run(&self, n: &U512) -> String {
+        match PrimalityCertificate::prove(n) {
+            Some(certificate) => certificate.to_string().trim_end().to_string(),
+            None if !BailliePsw::is_prime(n) => format!("{} is composite", n),
+            None => format!("Failed to prove that {} is prime", n),
+        }
+    }
+}
+
+/// Prints a chain of ECPP certificates for the given number.
+///
+/// The descent only uses the discriminants in the fixed table of class polynomials, so for a
+/// rare prime none of its curve orders has a large enough prime part, even after Pollard's rho,
+/// and the command reports that it failed to prove the number prime.
+pub struct Ecpp;
+
+impl Command for Ecpp {
+    fn run(&self, n: &U512) -> String {
+        match PrimalityCertificate::prove_ecpp(n) {
+            Some(certificate) => certificate.to_string().trim_end().to_string(),
+            None if !BailliePsw::is_prime(n) => format!("{} is composite", n),
+            None => format!("Failed to prove that {} is prime", n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustic_factors_derive::PrimalityCommand;
+
+    fn prove(n: &str) -> PrimalityCertificate {
+        let n = n.parse().unwrap();
+        let certificate = PrimalityCertificate::prove(&n).expect("should find a certificate");
+        assert_eq!(certificate.n(), &n);
+        assert!(certificate.verify(), "{certificate}");
+        certificate
+    }
+
+    #[test]
+    fn small_primes_by_trial_division() {
+        for p in ["2", "3", "1021", "1048573"] {
+            assert!(matches!(
+                prove(p),
+                PrimalityCertificate::TrialDivision { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn composites_have_no_certificate() {
+        for n in ["0", "1", "561", "2047", "1048575", "3825123056546413051"] {
+            assert_eq!(
+                PrimalityCertificate::prove(&n.parse().unwrap()),
+                None,
+                "{n}"
+            );
+        }
+    }
+
+    #[test]
+    fn pratt_certificate_with_nested_certificates() {
+        let certificate = prove("1000000007");
+        let PrimalityCertificate::Pratt { factors, .. } = &certificate else {
+            panic!("expected a Pratt certificate, got {certificate}");
+        };
+        let primes: Vec<_> = factors.iter().map(|f| f.prime().to_string()).collect();
+        assert_eq!(primes, ["2", "500000003"]);
+        assert!(matches!(
+            factors[1].certificate,
+            PrimalityCertificate::Pratt { .. }
+        ));
+    }
+
+    #[test]
+    fn pocklington_certificates() {
+        // n - 1 = 2⁵ × 3 × 10253 × 29333 × 4454477 × 42113237 × 62826870453001.
+        prove("340282366920938463463374607431768211297");
+        // 267 × 2³⁰⁰ + 1, where 2³⁰⁰ alone exceeds the square root.
+        let n = U512::from(267u16) * U512::power_of_two(300) + U512::ONE;
+        prove(&n.to_string());
+    }
+
+    #[test]
+    fn brillhart_lehmer_selfridge_certificate() {
+        // 2²⁰⁰ R + 1 for a 300-bit prime R, so that the factored part 2²⁰⁰ lies between the
+        // cube root and the square root of n.
+        let certificate = prove(
+            "163669530394807093500659484841379957610832102302153239474164568404806689820\
+             2337277441798990803041513540324959662326957936805750988977398400100216078337",
+        );
+        assert!(matches!(
+            certificate,
+            PrimalityCertificate::Pocklington { .. }
+        ));
+    }
+
+    #[test]
+    fn tampered_certificates_are_rejected() {
+        let certificate = prove("1000000007");
+        let PrimalityCertificate::Pratt { n, factors, .. } = certificate.clone() else {
+            unreachable!()
+        };
+        let wrong_witness = PrimalityCertificate::Pratt {
+            n,
+            witness: U512::ONE,
+            factors: factors.clone(),
+        };
+        assert!(!wrong_witness.verify());
+        let wrong_n = PrimalityCertificate::Pratt {
+            n: n + U512::TWO,
+            witness: U512::FIVE,
+            factors: factors.clone(),
+        };
+        assert!(!wrong_n.verify());
+        let too_few_factors = PrimalityCertificate::Pocklington {
+            n,
+            factors: vec![WitnessedFactor {
+                factor: factors[0].clone(),
+                witness: U512::FIVE,
+            }],
+        };
+        assert!(!too_few_factors.verify());
+        let large = PrimalityCertificate::TrialDivision {
+            n: U512::from(1000000007u64),
+        };
+        assert!(!large.verify());
+    }
+
+    #[derive(PrimalityCommand)]
+    #[primality_command(certificate)]
+    struct CertifiedBailliePsw;
+
+    impl PrimalityTest for CertifiedBailliePsw {
+        fn is_prime(p: &U512) -> bool {
+            BailliePsw::is_prime(p)
+        }
+    }
+
+    #[test]
+    fn primality_command_with_certificate() {
+        let output = CertifiedBailliePsw.run(&U512::from(1000000007u64));
+        assert!(
+            output.starts_with("1000000007 is prime\n1000000007 is prime by Pratt's certificate"),
+            "{output}"
+        );
+        assert_eq!(
+            CertifiedBailliePsw.run(&U512::from(561u16)),
+            "561 is composite"
+        );
+    }
+}
@@ -0,0 +1,401 @@
+mod class_polynomials;
+mod polynomial;
+mod weierstrass;
+
+use super::{EllipticCurveProof, PrimalityCertificate};
+use crate::algorithms::{PollardsRhoBrent, TrialDivision};
+use crate::budget::Budget;
+use crate::modular;
+use crate::primality_test::BailliePsw;
+use crate::traits::{Factorize, PrimalityTest};
+use bnum::cast::As;
+use bnum::types::{U1024, U512};
+use class_polynomials::CLASS_POLYNOMIALS;
+use num_integer::{Integer, Roots};
+use rand::Rng;
+use weierstrass::{Point, WeierstrassCurve};
+
+/// Small factors stripped from a curve order before the rest is tested for primality.
+const TRIAL_DIVISION_BOUND: u64 = 1 << 16;
+/// The limited effort of Pollard's rho on a composite rest of a curve order, which finds
+/// most of its factors up to about `RHO_STEPS²`.
+const RHO_STEPS: u64 = 1 << 16;
+const RHO_ATTEMPTS: usize = 4;
+/// The curves `y² = x³ + b` for `j = 0` and `y² = x³ + ax` for `j = 1728` have six and four
+/// twists, which are found among the first few coefficients.
+const CURVE_ATTEMPTS: u64 = 50;
+const POINT_ATTEMPTS: usize = 20;
+
+/// An order `m = n + 1 - t` of a curve with complex multiplication by the discriminant of the
+/// class polynomial, and what is left of it after trial division.
+struct CurveOrder {
+    class_polynomial: &'static [&'static str],
+    order: U512,
+    rest: U512,
+}
+
+/// A curve order with a probable prime factor `q` large enough for the proof.
+struct Candidate {
+    class_polynomial: &'static [&'static str],
+    order: U512,
+    q: U512,
+}
+
+/// How hard to try at splitting the rest of a curve order left by trial division.
+#[derive(Clone, Copy)]
+enum Effort {
+    TrialDivision,
+    Rho,
+}
+
+/// Proves a probable prime `n` coprime to 6 by finding a curve modulo `n` whose order has a
+/// probable prime factor `q > (n^(1/4) + 1)²`, and then proving `q` recursively. The
+/// candidate orders are tried from the smallest `q` up, and when `q` cannot be proven the
+/// next one is used.
+///
+/// Usually the rest of some order after trial division is such a `q`. Only when none of
+/// those lead to a proof is Pollard's rho run on the composite rests, since for a few
+/// percent of `n` no order in the table of class polynomials has a prime rest.
+pub(super) fn prove(n: &U512) -> Option<PrimalityCertificate> {
+    let orders = curve_orders(n);
+    let mut all_candidates = candidates(n, &orders, Effort::TrialDivision)
+        .into_iter()
+        .chain(std::iter::once_with(|| candidates(n, &orders, Effort::Rho)).flatten());
+    all_candidates.find_map(|candidate| {
+        let (a, b, point, cofactor) = find_curve(n, &candidate)?;
+        let certificate = PrimalityCertificate::prove_ecpp(&candidate.q)?;
+        Some(PrimalityCertificate::Ecpp(Box::new(EllipticCurveProof {
+            n: *n,
+            a,
+            b,
+            point,
+            cofactor,
+            certificate,
+        })))
+    })
+}
+
+/// Checks an ECPP certificate apart from the certificate of `q`.
+pub(super) fn verify(proof: &EllipticCurveProof) -> bool {
+    let EllipticCurveProof {
+        n,
+        a,
+        b,
+        point,
+        cofactor,
+        certificate,
+    } = proof;
+    let q = certificate.n();
+    if *n <= U512::ONE || n.gcd(&U512::SIX) != U512::ONE || *q < order_bound(n) {
+        return false;
+    }
+    let curve = WeierstrassCurve::new(n, a, b);
+    let (x, y) = point;
+    if !curve.is_nonsingular() || !curve.contains(x, y) {
+        return false;
+    }
+    match curve.multiply(&Point::Affine(*x, *y), cofactor) {
+        Some(p @ Point::Affine(..)) => curve.multiply(&p, q) == Some(Point::Infinity),
+        _ => false,
+    }
+}
+
+/// `(⌊n^(1/4)⌋ + 2)²`, which exceeds `(n^(1/4) + 1)²`, the largest order of a curve modulo a
+/// prime `p <= sqrt(n)` by Hasse's theorem.
+fn order_bound(n: &U512) -> U512 {
+    let root = n.nth_root(4) + U512::TWO;
+    root * root
+}
+
+/// Returns the orders of the curves with complex multiplication by the discriminants for
+/// which `n` is a norm.
+fn curve_orders(n: &U512) -> Vec<CurveOrder> {
+    let mut curve_orders = vec![];
+    for &(discriminant, class_polynomial) in CLASS_POLYNOMIALS {
+        let d = discriminant.unsigned_abs();
+        let residue = n - U512::from(d) % n;
+        if modular::jacobi_symbol(residue, *n) != 1 {
+            continue;
+        }
+        let Some((u, v)) = cornacchia(n, d) else {
+            continue;
+        };
+        for order in orders(n, d, &u, &v) {
+            let (_, rest) = TrialDivision::strip_small_factors(&order, TRIAL_DIVISION_BOUND);
+            curve_orders.push(CurveOrder {
+                class_polynomial,
+                order,
+                rest,
+            });
+        }
+    }
+    curve_orders
+}
+
+fn candidates(n: &U512, orders: &[CurveOrder], effort: Effort) -> Vec<Candidate> {
+    let bound = order_bound(n);
+    let mut candidates = orders
+        .iter()
+        .filter_map(|curve_order| {
+            let q = match effort {
+                Effort::TrialDivision => curve_order.rest,
+                // Prime rests were already candidates after trial division.
+                Effort::Rho if BailliePsw::is_prime(&curve_order.rest) => return None,
+                Effort::Rho => split_with_rho(&curve_order.rest, &bound)?,
+            };
+            (q >= bound && q < *n && BailliePsw::is_prime(&q)).then_some(Candidate {
+                class_polynomial: curve_order.class_polynomial,
+                order: curve_order.order,
+                q,
+            })
+        })
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| a.q.cmp(&b.q));
+    candidates
+}
+
+/// Splits factors off the composite `rest` with a limited effort of Pollard's rho, keeping
+/// the larger part each time, until it is a probable prime. Returns `None` if rho fails or
+/// the part drops below `bound`.
+fn split_with_rho(rest: &U512, bound: &U512) -> Option<U512> {
+    let mut rest = *rest;
+    for _ in 0..RHO_ATTEMPTS {
+        if rest < *bound {
+            return None;
+        }
+        if BailliePsw::is_prime(&rest) {
+            return Some(rest);
+        }
+        let budget = Budget::unlimited().with_max_steps(RHO_STEPS);
+        let d = PollardsRhoBrent::factorize_with_budget(&rest, &budget).ok()?;
+        if d == U512::ONE || d == rest {
+            return None;
+        }
+        rest = d.max(rest / d);
+    }
+    None
+}
+
+/// Solves `u² + dv² = 4n` for a prime `n` with Cornacchia's algorithm, where `d = 0 or 3
+/// mod 4`. The products are taken in `U1024`, since `4n` may not fit into `U512`.
+fn cornacchia(n: &U512, d: u64) -> Option<(U512, U512)> {
+    let mut root = modular::sqrt_mod(&(n - U512::from(d) % n), n)?;
+    if root.bit(0) != d.is_odd() {
+        root = n - root;
+    }
+    let wide_n = (*n).as_::<U1024>();
+    let four_n = wide_n * U1024::FOUR;
+    let limit = four_n.sqrt();
+    let (mut a, mut b) = (wide_n * U1024::TWO, root.as_::<U1024>());
+    while b > limit {
+        (a, b) = (b, a % b);
+    }
+    let (v_squared, remainder) = (four_n - b * b).div_rem(&U1024::from(d));
+    let v = v_squared.sqrt();
+    (remainder.is_zero() && v * v == v_squared).then(|| (b.as_::<U512>(), v.as_::<U512>()))
+}
+
+/// Returns the possible orders `n + 1 - t` of curves with complex multiplication by `-d`,
+/// given `u² + dv² = 4n`. The trace `t` is `±u`, and for the extra units of the orders of
+/// discriminant `-4` and `-3` also `±2v` and `±(u ± 3v) / 2`.
+fn orders(n: &U512, d: u64, u: &U512, v: &U512) -> Vec<U512> {
+    let mut traces = vec![*u];
+    match d {
+        4 => traces.push(v << 1),
+        3 => {
+            let three_v = v * U512::THREE;
+            traces.extend([(u + three_v) >> 1, u.abs_diff(three_v) >> 1]);
+        }
+        _ => {}
+    }
+    let n_plus_1 = n + U512::ONE;
+    traces
+        .iter()
+        .flat_map(|t| [n_plus_1.checked_add(*t), n_plus_1.checked_sub(*t)])
+        .flatten()
+        .collect()
+}
+
+/// Looks for a curve of the candidate's order and a point `P` on it with `[m / q]P` not the
+/// point at infinity, returning `(a, b, P, m / q)`.
+fn find_curve(n: &U512, candidate: &Candidate) -> Option<(U512, U512, (U512, U512), U512)> {
+    let class_polynomial = candidate
+        .class_polynomial
+        .iter()
+        .map(|c| residue(c, n))
+        .chain([U512::ONE])
+        .collect::<Vec<_>>();
+    let j = polynomial::find_root(&class_polynomial, n)?;
+    let cofactor = candidate.order / candidate.q;
+    for (a, b) in curves_with_j_invariant(&j, n)? {
+        let curve = WeierstrassCurve::new(n, &a, &b);
+        for _ in 0..POINT_ATTEMPTS {
+            let (x, y) = random_point(&curve, n)?;
+            let point = Point::Affine(x, y);
+            if curve.multiply(&point, &candidate.order)? != Point::Infinity {
+                // The curve is a twist with a different order.
+                break;
+            }
+            if curve.multiply(&point, &cofactor)? != Point::Infinity {
+                return Some((a, b, (x, y), cofactor));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the curves with the given `j`-invariant up to isomorphism, which differ in their
+/// orders. For `j ≠ 0, 1728`, these are `y² = x³ + 3kx + 2k` with `k = j / (1728 - j)` and
+/// its quadratic twist by a non-residue `c`.
+fn curves_with_j_invariant(j: &U512, n: &U512) -> Option<Vec<(U512, U512)>> {
+    let mut small = (1..=CURVE_ATTEMPTS).map(U512::from);
+    if j.is_zero() {
+        return Some(small.map(|b| (U512::ZERO, b)).collect());
+    }
+    let j_1728 = U512::from(1728u16);
+    if *j == j_1728 % n {
+        return Some(small.map(|a| (a, U512::ZERO)).collect());
+    }
+    let k = modular::mul_mod(
+        j,
+        &modular::inverse(&modular::sub_mod(&j_1728, j, n), n)?,
+        n,
+    );
+    let (a, b) = (
+        modular::mul_mod(&U512::THREE, &k, n),
+        modular::mul_mod(&U512::TWO, &k, n),
+    );
+    let c = small.find(|&c| modular::jacobi_symbol(c, *n) == -1)?;
+    let c_squared = modular::mul_mod(&c, &c, n);
+    let twist = (
+        modular::mul_mod(&a, &c_squared, n),
+        modular::mul_mod(&b, &modular::mul_mod(&c_squared, &c, n), n),
+    );
+    Some(vec![(a, b), twist])
+}
+
+fn random_point(curve: &WeierstrassCurve, n: &U512) -> Option<(U512, U512)> {
+    let mut rng = rand::thread_rng();
+    (0..POINT_ATTEMPTS).find_map(|_| {
+        let x = rng.gen_range(U512::ZERO..*n);
+        let y = modular::sqrt_mod(&curve.rhs(&x), n)?;
+        Some((x, y))
+    })
+}
+
+/// Reduces a signed decimal coefficient of the class polynomial table modulo `n`.
+fn residue(coefficient: &str, n: &U512) -> U512 {
+    let (is_negative, digits) = match coefficient.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, coefficient),
+    };
+    let magnitude = digits.parse::<U512>().unwrap() % n;
+    if is_negative {
+        modular::sub_mod(&U512::ZERO, &magnitude, n)
+    } else {
+        magnitude
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn representations_of_4n() {
+        let n = U512::from(1000000007u64);
+        for d in [3, 4, 7, 8, 11, 19, 43, 67, 163] {
+            if let Some((u, v)) = cornacchia(&n, d) {
+                assert_eq!(u * u + U512::from(d) * v * v, n * U512::FOUR, "d = {d}");
+            }
+        }
+        // 4 × 1000000007 = 2² × 1000000007 has no representation with d = 4 since
+        // 1000000007 = 3 mod 4.
+        assert_eq!(cornacchia(&n, 4), None);
+    }
+
+    #[test]
+    fn class_polynomials_split_modulo_primes_of_the_norm_form() {
+        // Whenever 4n = u² + |D|v², the class polynomial of D splits into linear factors
+        // modulo n.
+        let n = U512::power_of_two(127) - U512::ONE;
+        let mut found = 0;
+        for &(discriminant, coefficients) in CLASS_POLYNOMIALS {
+            if cornacchia(&n, discriminant.unsigned_abs()).is_none() {
+                continue;
+            }
+            let class_polynomial = coefficients
+                .iter()
+                .map(|c| residue(c, &n))
+                .chain([U512::ONE])
+                .collect::<Vec<_>>();
+            assert!(
+                polynomial::find_root(&class_polynomial, &n).is_some(),
+                "D = {discriminant}"
+            );
+            found += 1;
+        }
+        assert!(found > 0);
+    }
+
+    #[test]
+    fn certificate_chain() {
+        let n = U512::power_of_two(127) - U512::ONE;
+        let certificate = prove(&n).expect("should find a certificate");
+        assert!(certificate.verify(), "{certificate}");
+        let mut steps = 0;
+        let mut link = &certificate;
+        while let PrimalityCertificate::Ecpp(proof) = link {
+            steps += 1;
+            link = &proof.certificate;
+        }
+        assert!(steps >= 1 && link.n().bits() <= 64, "{certificate}");
+    }
+
+    #[test]
+    fn rho_splits_orders_that_trial_division_leaves_composite() {
+        // After trial division, the rest of every order of a curve modulo this prime with a
+        // discriminant in the table is composite.
+        let n = U512::from_str_radix(
+            "105743776620325305996452392871709745129994740737550206818775303943653085838267",
+            10,
+        )
+        .unwrap();
+        let orders = curve_orders(&n);
+        assert!(candidates(&n, &orders, Effort::TrialDivision).is_empty());
+        assert!(!candidates(&n, &orders, Effort::Rho).is_empty());
+    }
+
+    #[test]
+    fn tampered_certificates_are_rejected() {
+        let n = U512::from_str_radix("1267650600228229401496703205653", 10).unwrap();
+        let Some(PrimalityCertificate::Ecpp(proof)) = prove(&n) else {
+            panic!("expected an ECPP certificate");
+        };
+        assert!(verify(&proof));
+        let (x, y) = proof.point;
+        let off_curve = EllipticCurveProof {
+            point: (x, modular::add_mod(&y, &U512::ONE, &n)),
+            ..(*proof).clone()
+        };
+        assert!(!verify(&off_curve));
+        let wrong_cofactor = EllipticCurveProof {
+            cofactor: proof.cofactor + U512::ONE,
+            ..(*proof).clone()
+        };
+        assert!(!verify(&wrong_cofactor));
+        // Only the number proven by the nested certificate is used here.
+        let wrong_q = EllipticCurveProof {
+            certificate: PrimalityCertificate::TrialDivision {
+                n: proof.certificate.n() + U512::TWO,
+            },
+            ..(*proof).clone()
+        };
+        assert!(!verify(&wrong_q));
+        let composite = EllipticCurveProof {
+            n: n * U512::from(7u8),
+            ..(*proof).clone()
+        };
+        assert!(!verify(&composite));
+    }
+}
@@ -0,0 +1,5223 @@
+/// Hilbert class polynomials `H_D` of all discriminants `-3000 < D < 0` with class number at
+/// most eight, as the decimal coefficients of `x^0, x^1, ...` below the leading one. The roots
+/// of `H_D` modulo a prime are the `j`-invariants of the curves with complex multiplication
+/// by the order of discriminant `D`.
+pub const CLASS_POLYNOMIALS: &[(i64, &[&str])] = &[
+    (
+        -3,
+        &[
+            "0",
+        ],
+    ),
+    (
+        -4,
+        &[
+            "-1728",
+        ],
+    ),
+    (
+        -7,
+        &[
+            "3375",
+        ],
+    ),
+    (
+        -8,
+        &[
+            "-8000",
+        ],
+    ),
+    (
+        -11,
+        &[
+            "32768",
+        ],
+    ),
+    (
+        -12,
+        &[
+            "-54000",
+        ],
+    ),
+    (
+        -15,
+        &[
+            "-121287375",
+            "191025",
+        ],
+    ),
+    (
+        -16,
+        &[
+            "-287496",
+        ],
+    ),
+    (
+        -19,
+        &[
+            "884736",
+        ],
+    ),
+    (
+        -20,
+        &[
+            "-681472000",
+            "-1264000",
+        ],
+    ),
+    (
+        -23,
+        &[
+            "12771880859375",
+            "-5151296875",
+            "3491750",
+        ],
+    ),
+    (
+        -24,
+        &[
+            "14670139392",
+            "-4834944",
+        ],
+    ),
+    (
+        -27,
+        &[
+            "12288000",
+        ],
+    ),
+    (
+        -28,
+        &[
+            "-16581375",
+        ],
+    ),
+    (
+        -31,
+        &[
+            "1566028350940383",
+            "-58682638134",
+            "39491307",
+        ],
+    ),
+    (
+        -32,
+        &[
+            "12167000000",
+            "-52250000",
+        ],
+    ),
+    (
+        -35,
+        &[
+            "-134217728000",
+            "117964800",
+        ],
+    ),
+    (
+        -36,
+        &[
+            "-1790957481984",
+            "-153542016",
+        ],
+    ),
+    (
+        -39,
+        &[
+            "20919104368024767633",
+            "109873509788637459",
+            "-429878960946",
+            "331531596",
+        ],
+    ),
+    (
+        -40,
+        &[
+            "9103145472000",
+            "-425692800",
+        ],
+    ),
+    (
+        -43,
+        &[
+            "884736000",
+        ],
+    ),
+    (
+        -44,
+        &[
+            "-653249011576832",
+            "270413882112",
+            "-1122662608",
+        ],
+    ),
+    (
+        -47,
+        &[
+            "16042929600623870849609375",
+            "-14982472850828613281250",
+            "5115161850595703125",
+            "-9987963828125",
+            "2257834125",
+        ],
+    ),
+    (
+        -48,
+        &[
+            "6549518250000",
+            "-2835810000",
+        ],
+    ),
+    (
+        -51,
+        &[
+            "6262062317568",
+            "5541101568",
+        ],
+    ),
+    (
+        -52,
+        &[
+            "-567663552000000",
+            "-6896880000",
+        ],
+    ),
+    (
+        -55,
+        &[
+            "-18577989025032784359375",
+            "172576736359017890625",
+            "-20948398473375",
+            "13136684625",
+        ],
+    ),
+    (
+        -56,
+        &[
+            "10064086044321563803648",
+            "2257767342088912896",
+            "2059647197077504",
+            "-16220384512",
+        ],
+    ),
+    (
+        -59,
+        &[
+            "374643194001883136",
+            "-140811576541184",
+            "30197678080",
+        ],
+    ),
+    (
+        -60,
+        &[
+            "153173312762625",
+            "-37018076625",
+        ],
+    ),
+    (
+        -63,
+        &[
+            "-6256903954262253662109375",
+            "4558451243295023437500",
+            "-193068841781250",
+            "67515199875",
+        ],
+    ),
+    (
+        -64,
+        &[
+            "-7367066619912",
+            "-82226316240",
+        ],
+    ),
+    (
+        -67,
+        &[
+            "147197952000",
+        ],
+    ),
+    (
+        -68,
+        &[
+            "-2089297506304000000000000",
+            "-318507038720000000000",
+            "-75843692160000000",
+            "-178211040000",
+        ],
+    ),
+    (
+        -71,
+        &[
+            "737707086760731113357714241006081263",
+            "-425319473946139603274605151187659",
+            "5138800366453976780323726329446",
+            "-823534263439730779968091389",
+            "98394038810047812049302",
+            "-3091990138604570",
+            "313645809715",
+        ],
+    ),
+    (
+        -72,
+        &[
+            "232381513792000000",
+            "-377674768000",
+        ],
+    ),
+    (
+        -75,
+        &[
+            "5209253090426880",
+            "654403829760",
+        ],
+    ),
+    (
+        -76,
+        &[
+            "-827237892283232256",
+            "1128678666363648",
+            "-784074438864",
+        ],
+    ),
+    (
+        -79,
+        &[
+            "5458041030919737322344464663391",
+            "-5859423003994491322155950334",
+            "1793441424178093483069839",
+            "-6366718450945836",
+            "1339190283240",
+        ],
+    ),
+    (
+        -80,
+        &[
+            "422286883970526784000000",
+            "-171263969177632000000",
+            "-13028555239824000",
+            "-1597177172000",
+        ],
+    ),
+    (
+        -83,
+        &[
+            "549755813888000000000",
+            "-41490055168000000",
+            "2691907584000",
+        ],
+    ),
+    (
+        -84,
+        &[
+            "-5133201653210986057826304",
+            "88821246589810089394176",
+            "-5663679223085309952",
+            "-3196800946944",
+        ],
+    ),
+    (
+        -87,
+        &[
+            "549806430204864490157810211181640625",
+            "432181202257616392838287353515625",
+            "497577733884372638735595703125",
+            "28321090578679361484375000",
+            "85585228375218750",
+            "5321761711875",
+        ],
+    ),
+    (
+        -88,
+        &[
+            "15798135578688000000",
+            "-6294842640000",
+        ],
+    ),
+    (
+        -91,
+        &[
+            "-3845689020776448",
+            "10359073013760",
+        ],
+    ),
+    (
+        -92,
+        &[
+            "-6267542200571287109375",
+            "-263033266852296875",
+            "-12207823849750",
+        ],
+    ),
+    (
+        -95,
+        &[
+            "107789694576540010002976771996177148681640625",
+            "2110631639116675267953915424764056884765625",
+            "-1437415939871573574572839010971248046875",
+            "352163322858664726762725228294921875",
+            "-13089776536501963407329479984375",
+            "395013575867144519258203125",
+            "-688170786018119250",
+            "19874477919500",
+        ],
+    ),
+    (
+        -96,
+        &[
+            "-984163224549635621646336",
+            "447805364111967209472",
+            "670421055192156288",
+            "-23340144296736",
+        ],
+    ),
+    (
+        -99,
+        &[
+            "-56171326053810176",
+            "37616060956672",
+        ],
+    ),
+    (
+        -100,
+        &[
+            "-292143758886942437376",
+            "-44031499226496",
+        ],
+    ),
+    (
+        -103,
+        &[
+            "28826612937014029067466156005859375",
+            "13355527720114165506172119140625",
+            "4941005649165514137656250000",
+            "85475283659296875",
+            "70292286280125",
+        ],
+    ),
+    (
+        -104,
+        &[
+            "65437179730333545242323676123103232",
+            "-25735039642229334200564710375424",
+            "1378339984770204584193868955648",
+            "31013571054009020830449664",
+            "739545196164376195072",
+            "-82028232174464",
+        ],
+    ),
+    (
+        -107,
+        &[
+            "337618789203968000000000",
+            "-6764523159552000000",
+            "129783279616000",
+        ],
+    ),
+    (
+        -108,
+        &[
+            "-1879994705688000000000",
+            "224179462188000000",
+            "-151013228706000",
+        ],
+    ),
+    (
+        -111,
+        &[
+            "27524793815819191410861831167197250556510894417",
+            "-64773995403104720702864091375403035855442761",
+            "88953282358528708595648019437144660946708",
+            "-25675269514993965918445147228203062874",
+            "2987537813865962860773420720531252",
+            "56129700127461627298044206619",
+            "12257744369763349962",
+            "236917342626795",
+        ],
+    ),
+    (
+        -112,
+        &[
+            "1337635747140890625",
+            "-274917323970000",
+        ],
+    ),
+    (
+        -115,
+        &[
+            "130231327260672000",
+            "427864611225600",
+        ],
+    ),
+    (
+        -116,
+        &[
+            "-100730316193548175256338136121783353344",
+            "143376986667050616958401264069115904",
+            "-66527716583835083670963399688192",
+            "-835102260960042427461140480",
+            "-11056847669496432594944",
+            "-495202728828032",
+        ],
+    ),
+    (
+        -120,
+        &[
+            "4934510722321469030006784000000",
+            "-2588458316335175909376000000",
+            "26329406807264910336000",
+            "-883067971104000",
+        ],
+    ),
+    (
+        -123,
+        &[
+            "148809594175488000000",
+            "1354146840576000",
+        ],
+    ),
+    (
+        -124,
+        &[
+            "-599530686551745232383",
+            "-874125972104525910",
+            "-1559739536377947",
+        ],
+    ),
+    (
+        -127,
+        &[
+            "319730671478833667491273673675537109375",
+            "-64331030949386896516600669921875000",
+            "5642626198092219066070054687500",
+            "-30614197896114609375",
+            "2375421230598750",
+        ],
+    ),
+    (
+        -128,
+        &[
+            "-345363656226658026765625000000",
+            "-55499520947716391500000000",
+            "-395258439243352250000",
+            "-2729960418308000",
+        ],
+    ),
+    (
+        -131,
+        &[
+            "144530638394690224075155326369792",
+            "-60354680538951673475558801408",
+            "107205484283838454093053952",
+            "-671177121829224448000",
+            "4130485792112640",
+        ],
+    ),
+    (
+        -132,
+        &[
+            "1656636925108948992000000000000",
+            "54984539729717250048000000000",
+            "-325211610485778048000000",
+            "-4736863498464000",
+        ],
+    ),
+    (
+        -135,
+        &[
+            "3284527439242119311242957750346113869140625",
+            "4321223868213674595045534006061857421875",
+            "628735820731639650833126829398718750",
+            "50727257383070661992492657625000",
+            "77686119211324699125",
+            "7122306993287625",
+        ],
+    ),
+    (
+        -136,
+        &[
+            "2422829169428572504087521656832",
+            "-1834607111282472051029311488",
+            "735960027609078992953344",
+            "-8151279336430848",
+        ],
+    ),
+    (
+        -139,
+        &[
+            "67408489017571610198016",
+            "-53041786755137667072",
+            "12183160834031616",
+        ],
+    ),
+    (
+        -140,
+        &[
+            "242830180406000275493501698048000000000",
+            "-96864973869318094511286681600000000",
+            "2848295663082788926282752000000",
+            "-790870172407252503705600000",
+            "-3270237719203124384000",
+            "-13916138006442400",
+        ],
+    ),
+    (
+        -144,
+        &[
+            "571751321233328637579104256",
+            "490453856866850787293184",
+            "269499185406087942528",
+            "-23578503968570400",
+        ],
+    ),
+    (
+        -147,
+        &[
+            "11356800389480448000000",
+            "34848505552896000",
+        ],
+    ),
+    (
+        -148,
+        &[
+            "-7898242515936467904000000",
+            "-39660183801072000",
+        ],
+    ),
+    (
+        -151,
+        &[
+            "3269200340379000902458720113257045278788199227087",
+            "271248134304567044479896903675912851345002767",
+            "779394774943277357155375818745718823538863",
+            "69605133153244389737334180535377491802",
+            "3399966616467533664248409155353722",
+            "1107018219296858557941",
+            "58309232586862950",
+        ],
+    ),
+    (
+        -152,
+        &[
+            "472390748138731280269312000000000000000000",
+            "-1380504171426125758791680000000000000000",
+            "2783058624787093614292992000000000000",
+            "6854544294799483688960000000000",
+            "17024071380555203520000000",
+            "-66246265919280000",
+        ],
+    ),
+    (
+        -155,
+        &[
+            "37425860028464856284790784000000",
+            "20396251654725321097216000000",
+            "-44477871096357453824000",
+            "96905542950912000",
+        ],
+    ),
+    (
+        -156,
+        &[
+            "421266000645144840703921125633",
+            "-164993592496972989327022035",
+            "53074935443801207676942",
+            "-109914552886955148",
+        ],
+    ),
+    (
+        -160,
+        &[
+            "-293835053960432980416000000",
+            "13208221536779701382400000",
+            "-2940735389875294896000",
+            "-181195519824640800",
+        ],
+    ),
+    (
+        -163,
+        &[
+            "262537412640768000",
+        ],
+    ),
+    (
+        -164,
+        &[
+            "-852636173252919999445568788749874942641540406706176",
+            "716292304882512928715138362472485709784740265984",
+            "58876580988711431943771690012552346623541248",
+            "-82923859178811827895415538602091992842240",
+            "-72018009354152588972347870534871023616",
+            "-107971538556531472065498397540352",
+            "-161936389233870440957755392",
+            "-296853791160440320",
+        ],
+    ),
+    (
+        -168,
+        &[
+            "496644064976895846912000000000000000",
+            "-264691184105480095991808000000000",
+            "336511679671210230144000000",
+            "-483435712076832000",
+        ],
+    ),
+    (
+        -171,
+        &[
+            "-1311901521779155773721411584",
+            "8391550371275812148084736",
+            "472103267541360574464",
+            "694282057876537344",
+        ],
+    ),
+    (
+        -172,
+        &[
+            "-692660810326239000000000000",
+            "1164707517403692000000",
+            "-782759106183330000",
+        ],
+    ),
+    (
+        -175,
+        &[
+            "27017288450887144631231387755756779460197062625",
+            "1368302291061523680379707879639549158890532250",
+            "-59496933313401566319649813402788210673425",
+            "1253156381651642217978286627708618800",
+            "-54813228576976021387185",
+            "1119444674983992405",
+        ],
+    ),
+    (
+        -176,
+        &[
+            "984315149136933710414929915123613725364224",
+            "-233832181396031563359165936367916838912",
+            "56139914410303801525997336800408320",
+            "-1417657940638726253547455241728",
+            "-1311227225704547834164432",
+            "-1260369120052221040",
+        ],
+    ),
+    (
+        -179,
+        &[
+            "69366107283027836458026686806432415744",
+            "-23408814596997033103434472837087232",
+            "2672564790656716736213209317376",
+            "-2200273236852299356176384",
+            "1795194552944492544",
+        ],
+    ),
+    (
+        -180,
+        &[
+            "-6776421923145961044033929216000000",
+            "16660473763558887652278272000000",
+            "-2867757758882006477169664000",
+            "-2018504138609120000",
+        ],
+    ),
+    (
+        -183,
+        &[
+            "30451733341148937584624248315225887141980230808258056640625",
+            "-26922618461790759850037872887492462842807292938232421875",
+            "18238748993199475597203528068101439981700897216796875",
+            "655855629401644394905657823337825299835205078125",
+            "12093440927683360441327407340610611816406250",
+            "8201294924243209292049624110812500000",
+            "4218620940804013154578125",
+            "2863790268422945625",
+        ],
+    ),
+    (
+        -184,
+        &[
+            "114574710497270997578522590458150912",
+            "38705419208160503264676104110080",
+            "5767007465145198439020847104",
+            "-3215890895076912384",
+        ],
+    ),
+    (
+        -187,
+        &[
+            "-3845689020776448000000",
+            "4545336381788160000",
+        ],
+    ),
+    (
+        -188,
+        &[
+            "-124343484762728525316005706787109375",
+            "51644103814690479844366699218750",
+            "-15093437571402131169817626953125",
+            "-8766069746614632866828125",
+            "-5097838276115828125",
+        ],
+    ),
+    (
+        -192,
+        &[
+            "-1080060886113159937649308593750000",
+            "826335556188178615474500000000",
+            "15705521635909735050750000",
+            "-8041801037378436000",
+        ],
+    ),
+    (
+        -195,
+        &[
+            "-233490285492432753672585216000000",
+            "104773100319600336175104000000",
+            "25349140792043819237376000",
+            "11284411506057216000",
+        ],
+    ),
+    (
+        -196,
+        &[
+            "-2108010653658430719613224868701536256",
+            "250850701957837760512539510177792",
+            "-44864481851299856707307347968",
+            "-12626092121367165696",
+        ],
+    ),
+    (
+        -200,
+        &[
+            "1139359927820736630329093876556526883461660672000",
+            "181651879545544879923314552485535205556224000",
+            "640004261883602853633325553571308188467200",
+            "236628493411489493484987107953868800",
+            "87605036675549431339528253440",
+            "-19733105507276110720",
+        ],
+    ),
+    (
+        -203,
+        &[
+            "31913605837856413057024000000000000",
+            "250634002097696556449792000000000",
+            "-83053272156952592384000000",
+            "27502410406723584000",
+        ],
+    ),
+    (
+        -204,
+        &[
+            "28508041377034538166862450172153093456658432",
+            "6020337293681148983229932704488367325184",
+            "534484930703209896960446929872814080",
+            "775121756231241041610849730560",
+            "95864841637996112067555072",
+            "-30703802307926880672",
+        ],
+    ),
+    (
+        -207,
+        &[
+            "-183426864580818496179793649372867188930511474609375",
+            "12041028291910181818274355885092809398864746093750",
+            "-210672109851582446065248197114115955810546875",
+            "1819343755841562591564610147379736328125",
+            "-5002547112103664005187500",
+            "42653766018394018375",
+        ],
+    ),
+    (
+        -208,
+        &[
+            "1463592841477827633000000000000",
+            "-3908668494888708948000000000",
+            "4032372412181255526000000",
+            "-47568078792050004000",
+        ],
+    ),
+    (
+        -211,
+        &[
+            "5310823021408898698117644288",
+            "277390576406111100862464",
+            "65873587288630099968",
+        ],
+    ),
+    (
+        -212,
+        &[
+            "-67450134022842979455115194007552000000000000000000",
+            "39924086528997881772669622484992000000000000000",
+            "-11008353578715780277672803110912000000000000",
+            "-2630171369254890916959016960000000000",
+            "-628986407384453487358016000000",
+            "-73387074029381328000",
+        ],
+    ),
+    (
+        -216,
+        &[
+            "9316863967448371969962043382716305179148288",
+            "34441927383131420224661352250608420126720",
+            "129141069874109492050243812631108386816",
+            "-9056150910670523557375044574248960",
+            "1197383573848845385478924955648",
+            "-112760061542456628096",
+        ],
+    ),
+    (
+        -219,
+        &[
+            "110979720274963942538198675506593792",
+            "-15979705448736682450562851012608",
+            "831039118453558669939310592",
+            "155212323706544357376",
+        ],
+    ),
+    (
+        -220,
+        &[
+            "4189527305843979870968496890625",
+            "-6015443509589489085440390625",
+            "2531540097646020954716625",
+            "-172572544407169076625",
+        ],
+    ),
+    (
+        -223,
+        &[
+            "2606386098587221959562486420442180281995713710784912109375",
+            "-3017942224498278012503966427816688964673110961914062500",
+            "904981240117595334764254951261845701135925292968750",
+            "-10118589468858067354789356763548186038818359375",
+            "56100625266918564788759127557586158203125",
+            "-43072684586065004589140625",
+            "236855705574161972250",
+        ],
+    ),
+    (
+        -224,
+        &[
+            "4573574179879344596745560367912999678227803908603904",
+            "333907584600306671024017785849535798840531183730688",
+            "-134143607306227801938718107847574206949591351296",
+            "-23454276662221670119469240221271015345094656",
+            "-1547817012108377539813804203697900199936",
+            "-11042808304392149169199170364149760",
+            "-1723077455096553031935888128",
+            "-263096730270583432768",
+        ],
+    ),
+    (
+        -227,
+        &[
+            "5085472193216544027705344000000000000000",
+            "-2111118203460821622718464000000000000",
+            "18227340807938993794580480000000000",
+            "-2562327002832961536000000000",
+            "360082897644683264000",
+        ],
+    ),
+    (
+        -228,
+        &[
+            "120020259495560805847424176128000000000000",
+            "58827548670433207062445836288000000000",
+            "-7985216535621460489954944000000",
+            "-399605224650084576000",
+        ],
+    ),
+    (
+        -232,
+        &[
+            "14871070713157137145512000000000",
+            "-604729957849891344000",
+        ],
+    ),
+    (
+        -235,
+        &[
+            "11946621170462723407872000",
+            "823177419449425920000",
+        ],
+    ),
+    (
+        -240,
+        &[
+            "51848746810441819437662737568196890625",
+            "-3213137488352330508627918491550000",
+            "15510636623637225985530243375",
+            "-1370337635584848362400",
+        ],
+    ),
+    (
+        -243,
+        &[
+            "3338586724673519616000000000",
+            "-3750657365033091072000000",
+            "1855762905734664192000",
+        ],
+    ),
+    (
+        -244,
+        &[
+            "-9815190670232173018201554731440614047465078784",
+            "-24417475317780070950649666808040757791817728",
+            "-31292753080096691789898512325924416913408",
+            "-2691275293785918359227938328726732800",
+            "-92973717558373200586964869091328",
+            "-2052295773725248986240",
+        ],
+    ),
+    (
+        -247,
+        &[
+            "-407336295332190846580777495118233696120388820648193359375",
+            "400348022833121004028281794619328068026346954345703125",
+            "2475076441475565987510057965501956793452880859375",
+            "7686260773063033411724550958439950781250000",
+            "888629892547516768433109375",
+            "2772410642909877080250",
+        ],
+    ),
+    (
+        -248,
+        &[
+            "1323449723347621474969758725859966976000000000000000000000000",
+            "-1093432823745012115729536788054671360000000000000000000000",
+            "271994089256402280576009987295281152000000000000000000",
+            "-27750969592459084458872706174812160000000000000000",
+            "35762831246449484056560587036188672000000000000",
+            "2461626754066908714341150658560000000000",
+            "169518269276842782112073472000000",
+            "-3063517083860376640000",
+        ],
+    ),
+    (
+        -251,
+        &[
+            "1937587239465703269672056660685864050152464252403712",
+            "-1791911545705841840084320427251134859220759871488",
+            "416131608793437401577832999781610387970981888",
+            "7966552994949346594041401247164174172160",
+            "1062008880270126105976008028408774656",
+            "-66204185373144403998280777728",
+            "4128446190315309498368",
+        ],
+    ),
+    (
+        -252,
+        &[
+            "398963060554172791168212890625",
+            "-2443574658947106282398437500",
+            "10887176246183122533468750",
+            "-4558302700896532039875",
+        ],
+    ),
+    (
+        -256,
+        &[
+            "-1064410681181869521037208505239142408",
+            "26925623396663008311375890966784",
+            "-1826592673506207200904172752",
+            "-6761166974781862161312",
+        ],
+    ),
+    (
+        -259,
+        &[
+            "4384296738486457527093398159228928",
+            "5493320206929896679139197321216",
+            "-368189472100537894019530752",
+            "9068999694311625523200",
+        ],
+    ),
+    (
+        -260,
+        &[
+            "-3302947505675715028946774256661472679426359558144000000000000",
+            "305486088367929951707960768526477860306636557516800000000000",
+            "-85980083235988029405783249092189509918128078848000000000",
+            "14865557804649865113150034077076664167379763200000000",
+            "-463238908732347767153420578775505775886336000000",
+            "-21507054600723946274941348498171494400000",
+            "-999896161895842101863690217472000",
+            "-9997874035270492198400",
+        ],
+    ),
+    (
+        -264,
+        &[
+            "327886345447155202813840576100201205111813144244435638288384",
+            "-171749422417263603359883069647289043394815149890303164416",
+            "189771022593359719599623857042603680684355481140985856",
+            "-19114071480061200751208790258848908645703796916224",
+            "-1576759051947634872250887243973927048713338880",
+            "-43813781353344480858785503406172212822016",
+            "1789885567319176457551625511223296",
+            "-14739806897587232709120",
+        ],
+    ),
+    (
+        -267,
+        &[
+            "531429662672621376897024000000",
+            "19683091854079488000000",
+        ],
+    ),
+    (
+        -268,
+        &[
+            "-3189376432736929569384216000000000",
+            "32240842762858236972000000",
+            "-21667237292024856738000",
+        ],
+    ),
+    (
+        -272,
+        &[
+            "9265833623430102037137881938273000000000000000000000000",
+            "-7751659760972060765625755308904000000000000000000000",
+            "-7711135475352672738710945448900000000000000000000",
+            "3861901192470234862993839021544000000000000000",
+            "13460227027917301519133262366182000000000000",
+            "-31424909544599612739578321240000000000",
+            "-992292506997017283600644000000",
+            "-31759326199909682088000",
+        ],
+    ),
+    (
+        -275,
+        &[
+            "-3984711300201636241319486354007863066624",
+            "51213041627075282291106746090041376768",
+            "-1470671864720383632491493195776",
+            "42230108051959368384512",
+        ],
+    ),
+    (
+        -276,
+        &[
+            "11579958468886822266431515535986431304105856851373387044356096",
+            "-6523546308273811582074020329970859860219102556953002377216",
+            "767440733750724125562378402812781678248671343699558400",
+            "407334970460053160180107543216439671099066662518784",
+            "-7594738933192260668250057199680097904322674688",
+            "359684269203806519420937768802377441214464",
+            "-10000254249186327541943559851360256",
+            "-46421840776490384352768",
+        ],
+    ),
+    (
+        -280,
+        &[
+            "1775168961518724506399346503073398784000000",
+            "-708555761206745670461365038563328000000",
+            "17602516524144666384420962098176000",
+            "-67667966893419063840000",
+        ],
+    ),
+    (
+        -283,
+        &[
+            "201371843156955365376000000000",
+            "90839236535446929408000000",
+            "89611323386832801792000",
+        ],
+    ),
+    (
+        -284,
+        &[
+            "-709822597815616788561460999971455889888479357689323263",
+            "904330206367752622526324160408849797910089300094965",
+            "-282310474518147042483634599702449209357160103606",
+            "-12543980353722772760179537174109237891242365",
+            "-209903939873446020495519316596118272758",
+            "-4546951697981923176956448676986",
+            "-98373700603090523199715",
+        ],
+    ),
+    (
+        -288,
+        &[
+            "40994594700208456153393000000000000",
+            "136478143044657426076564000000000",
+            "-87330008255955399131086000000",
+            "-142637765058468510772000",
+        ],
+    ),
+    (
+        -291,
+        &[
+            "21782000952710117887925312635418808680448",
+            "285389231946718842181542553187254272",
+            "10786588141336392324590050738176",
+            "188155567079341753466880",
+        ],
+    ),
+    (
+        -292,
+        &[
+            "-380259461042512404779990642688000000000000",
+            "45521551386379385369629968384000000000",
+            "-93693622511929038759497066112000000",
+            "-206287709860428304608000",
+        ],
+    ),
+    (
+        -295,
+        &[
+            "822204343689207610829131926678660596532719371553249925736718994140625",
+            "-1122282566856104887683461567415963897525574528336611314070556640625",
+            "1811885751513084753220927364888013159601085995323528927843750000",
+            "41940062336716757201181279559382045880935056981401849609375",
+            "78688417471647009524122019473588050719938852358765625",
+            "73767807010599056699488845989197941140892890625",
+            "289315392383740839332561391375",
+            "271602295664902418108250",
+        ],
+    ),
+    (
+        -299,
+        &[
+            "-18273883965326272223717626628647422907813731016193733558272",
+            "45797528808215150136248975363201860724351225694802411520",
+            "-19207839443594488822936988943836177115227877227364352",
+            "6417141278133218665289808655954275181523718111232",
+            "-186547260770756829961971675685151791296544768",
+            "2094055410006322146651491130721133658112",
+            "-28635280874816126174326167699456",
+            "391086320728105978429440",
+        ],
+    ),
+    (
+        -300,
+        &[
+            "66661978554978958501295319312489107870472732672000",
+            "62082816308629282586712746552975312469884928000",
+            "21122955530832902270001123584504233628467200",
+            "-233405320133674124312518469774131200",
+            "32278855882815402576742692253440",
+            "-428244362959801779810720",
+        ],
+    ),
+    (
+        -304,
+        &[
+            "15932156097435253410695381729009112036477505536",
+            "-7791225706498882399085516838831185545949184",
+            "1528346968785104896068323613180201480960",
+            "45378991837771215221573080886813184",
+            "491686666270349693036542004016",
+            "-614772722255836378993776",
+        ],
+    ),
+    (
+        -307,
+        &[
+            "8987619631060626702336000000000",
+            "-5083646425734146162688000000",
+            "805016812009981390848000",
+        ],
+    ),
+    (
+        -308,
+        &[
+            "-2165234140612455295554925190568825369657344000000000000000000000000",
+            "3577524548867479876566112791807107740467200000000000000000000000",
+            "-1171071469575324412445157804913269173911552000000000000000000",
+            "118013359737143520552180913373061918228480000000000000000",
+            "-7587984891492127439982947268653129637888000000000000",
+            "-79176840236445969178233162764226560000000000",
+            "-826239775451714421096341416192000000",
+            "-880456353881469533120000",
+        ],
+    ),
+    (
+        -312,
+        &[
+            "1698899690981885675579246225669492736000000000000",
+            "-152340504750882110373595179663329280000000000",
+            "1411168483733488619338991640960000000",
+            "-1258031100283439093280000",
+        ],
+    ),
+    (
+        -315,
+        &[
+            "16319518649540946810407747584000000",
+            "256755031834901461034493542400000",
+            "116880346596078822090604544000",
+            "1641640623633325773619200",
+        ],
+    ),
+    (
+        -316,
+        &[
+            "-4020052207065827322924749407724450555391",
+            "-2607636724575654685306407800818058910",
+            "-9055029354307022862746345001271887",
+            "80751461998466194163273841876",
+            "-1793430629453261849626872",
+        ],
+    ),
+    (
+        -320,
+        &[
+            "-57164948756214588760973077375749617090767457040719000000000000",
+            "40944260711262093619342150509430807605037564840000000000000",
+            "-7574662452833544808267664613170196102721059092000000000",
+            "4708256714605528132855853397305987244611432000000000",
+            "-56386716220649524857687143869160670299546000000",
+            "-48038363639231170572648019974508552000000",
+            "-351576186089909152920032851988000",
+            "-2550974942438428429832000",
+        ],
+    ),
+    (
+        -323,
+        &[
+            "-121974636783103604190112617857024000000000000",
+            "73804562114102168041788801024000000000000",
+            "-494846073292941121091010560000000",
+            "3317765887009185280000000",
+        ],
+    ),
+    (
+        -324,
+        &[
+            "-5061569724026170821913022827171999159041259361796096",
+            "23450077655748415730757884569939472490942286528512",
+            "-44745106433723692853594036259593171592240168960",
+            "-895899811825836113012019702876374414917632",
+            "-6889085634124971952835571514757910528",
+            "-3620548496603402008963200",
+        ],
+    ),
+    (
+        -328,
+        &[
+            "88955608603044673650138130944000000000000000",
+            "54802167111836784369290132453376000000000",
+            "11610744584144462730131436503424000000",
+            "-5127512346913614444576000",
+        ],
+    ),
+    (
+        -331,
+        &[
+            "56176242840389398230218488594563072",
+            "368729929041040103875232661504",
+            "6647404730173793386463232",
+        ],
+    ),
+    (
+        -336,
+        &[
+            "356139343139022726874047596959786206788352553881997374652416",
+            "-347950410707819273313423258639842274603630233028126572544",
+            "243646964256505612639601181466740517292489442075869184",
+            "-41883539580229682994226324655826279489238730539008",
+            "299479829812492786489431657403218202573430784",
+            "-4122531170951933374034612225161882890240",
+            "2236729401475844126906097351528192",
+            "-10219547616980464664207424",
+        ],
+    ),
+    (
+        -339,
+        &[
+            "419198194184232019280311537075670994855640493457408",
+            "114053138969457254141239955759498317338331054080",
+            "33494559320437814886965525300815718579699712",
+            "-527926973475401681480399895797881110528",
+            "3119834163056249586908843992940544",
+            "13207870721923966705729536",
+        ],
+    ),
+    (
+        -340,
+        &[
+            "43039377624755967291385639037347037184000000",
+            "5906485031594874833231597894020684185600000",
+            "-54548817402421378465247510316573696000",
+            "-14383245771217510630675200",
+        ],
+    ),
+    (
+        -343,
+        &[
+            "4791576562341747034548276661270093305105027267573103845119476318359375",
+            "-3972653601649066484326573605251406741304015473521796878814697265625",
+            "1480797351289795967859364968037513969226011238564633514404296875",
+            "1009848457088842748174122781381460720529620832094970703125",
+            "344514398594838596665876837347342843995647646484375",
+            "54379116263846797396254926859375",
+            "18561099067532582351348250",
+        ],
+    ),
+    (
+        -347,
+        &[
+            "184912732321277851630780880519168000000000000000",
+            "-76862513895106262259943954448384000000000000",
+            "2286617351979618165608274471157760000000000",
+            "-7715358558261498003922616320000000",
+            "26032472194627246481408000",
+        ],
+    ),
+    (
+        -348,
+        &[
+            "692471208858544440424465339478399814605712890625",
+            "-557628723010969157792086142065436920166015625",
+            "175290702692535339653340200368891845703125",
+            "-29003873161616986468921515894234375000",
+            "8632839017824414061478365881968750",
+            "-28321147554737698717243875",
+        ],
+    ),
+    (
+        -352,
+        &[
+            "233814863662235465757810993000000000000",
+            "149685745558232337507189972000000000",
+            "-99328134588221245548396654000000",
+            "-39625012256717991533364000",
+        ],
+    ),
+    (
+        -355,
+        &[
+            "167490001660588917859010199158784000000",
+            "-24013762453779394698078584832000000",
+            "6828932041616339922516443136000",
+            "50912008581334742581248000",
+        ],
+    ),
+    (
+        -360,
+        &[
+            "1426271450575358799707287311662232043638547808256000000000000",
+            "-3605298995795783483578591919397362740207445606400000000000",
+            "7790473141693937112715892499559316037285445632000000000",
+            "-5014253154692679554211489347981698555025817600000000",
+            "1056941438764906800130371220070859781177344000000",
+            "-109201441323221855310782334283691478220800000",
+            "677379083861505776249205316176795648000",
+            "-77129618436806598479116800",
+        ],
+    ),
+    (
+        -363,
+        &[
+            "1577314437358442913340940353536000000000000",
+            "-496864268553728774541064273920000000000",
+            "45688143672322270430861721600000000",
+            "98823634118413525094400000",
+        ],
+    ),
+    (
+        -364,
+        &[
+            "171457302312675538238450398825818559998229834899652608",
+            "-26779040013783862224508884712755413392899723755520",
+            "-280762269320568601992086689163982842916569088",
+            "-1114180967095768084290666504793175015424",
+            "-26945321066119881266121528480000",
+            "-107310393727516367835689376",
+        ],
+    ),
+    (
+        -368,
+        &[
+            "13801356390308204318085687229009161734928134918212890625",
+            "64648812618671323095979973667040653072044433593750000",
+            "146380756273380282900056220504251687443860595703125",
+            "-41615501434303103741344598557507373625000000",
+            "-78492653237795758158907987231593750",
+            "-149030963654426202395206000",
+        ],
+    ),
+    (
+        -371,
+        &[
+            "6481710157163553710911373989049976082636826853040877396933214208",
+            "-9437193278662036228901778588613351480444207439554487798202368",
+            "7955226967369897728024063900920070991817063643388946939904",
+            "2070241046195436172786577952290406334822086904677138432",
+            "7172240473520895634328741679079956332987799306240",
+            "63021516974791355216056421758428516701962240",
+            "-109540453196503351362058007909236736",
+            "190434010944411944081817600",
+        ],
+    ),
+    (
+        -372,
+        &[
+            "41393149892607462736698558825033501904896000000000000",
+            "1755509254864401819594526832548625909760000000000",
+            "-2969541010382978868435960918595200000000",
+            "-206603714804587147622880000",
+        ],
+    ),
+    (
+        -376,
+        &[
+            "80898104235942224796361592859684324800100361568262519760355328",
+            "-797108085258416768239159045199404096196155929498778840596480",
+            "6354570979875197989882015972560265130690833840153389170688",
+            "179932812124131487070187094018692086667311846447382528",
+            "184827248158834773720244437191295069873105813897216",
+            "-586249792527212606449056068824115539869696000",
+            "4834578472269821416642158436559525756928",
+            "-285916404518354292888918528",
+        ],
+    ),
+    (
+        -379,
+        &[
+            "15443600047689011948024601807415148544",
+            "-121567791009880876719538528321536",
+            "364395404104624239018246144",
+        ],
+    ),
+    (
+        -380,
+        &[
+            "154215879682420877567016388990140832239111930071845605712890625",
+            "-128787850158846708103261774834423267545187484192877197265625",
+            "45758458895429777332163802772987429018048423368283203125",
+            "-8964225534615584378848687224200406866569893419921875",
+            "44558014626112116039445790693994428646901831265625",
+            "-212582712625033364976551927046111751635203125",
+            "-289732368808418290869258730884639250",
+            "-394994873978647592617839500",
+        ],
+    ),
+    (
+        -384,
+        &[
+            "-22711410532604569171733553168033738705419224051361861808943104",
+            "3743499007599796904236793148179525863855817609254935309680640",
+            "1133360732759543594222383565809483298021580285170794285056",
+            "114136603746893420728906514073872982254460934618165248",
+            "816256863839287996560655743292894359216948004992",
+            "2312253770394848226808182209508728124292608",
+            "442082035651764829560867879833566944",
+            "-544762334416885782913376064",
+        ],
+    ),
+    (
+        -387,
+        &[
+            "587533119951491680960512000000000000",
+            "747948304897905210163200000000000",
+            "-1543710173513675490459648000000",
+            "692535742940813486100480000",
+        ],
+    ),
+    (
+        -388,
+        &[
+            "-1121692648948590091501551223636881408000000000000",
+            "208224136957169320201407896480139264000000000",
+            "-20542159225989612130996373047535232000000",
+            "-750062398364686994581728000",
+        ],
+    ),
+    (
+        -392,
+        &[
+            "100819613411003977098553037150153312012282626048000000000000000000000000000",
+            "-108030268193771819583343361520403706045703236091904000000000000000000000",
+            "30036789099331980675088636409295029819907647733760000000000000000000",
+            "911286677087351758886930931055935520715359649792000000000000000",
+            "33788644461689194999008656146120452671373860864000000000000",
+            "33445080645611538227880551984384751538176000000000",
+            "33105241098434341203696292582622976000000",
+            "-1031035485922418252150848000",
+        ],
+    ),
+    (
+        -395,
+        &[
+            "75842586946704938664020551497033224470255633910726656000000000000",
+            "-65554873109416897528089562414278300430987083907072000000000000",
+            "9302488005249450440540192118814845845056811696128000000000",
+            "1711401607152157535896445361731998420396146688000000000",
+            "419520145342308306493930293009244561902403584000000",
+            "1563027318051423630273876232667142291456000000",
+            "-1429395109545314884513819323793408000",
+            "1307506076380207488835584000",
+        ],
+    ),
+    (
+        -396,
+        &[
+            "7704857441223851094181212264701978984938736673278132224",
+            "-3882916848674920220024612236964484759319495663681536",
+            "9042581842659781853871610042261233665687093248",
+            "-53329107003371259961886231710373417500672",
+            "242487456790940831195516923473664",
+            "-1414968042064378970672787872",
+        ],
+    ),
+    (
+        -400,
+        &[
+            "87448873738295790450948276123544550117376",
+            "-19075061455767889406477974994607212544",
+            "12869286863161864184636279443710336",
+            "-1938773508354872717845384224",
+        ],
+    ),
+    (
+        -403,
+        &[
+            "-108844203402491055833088000000",
+            "2452811389229331391979520000",
+        ],
+    ),
+    (
+        -408,
+        &[
+            "13375974716483932888129605820405217248677888000000000000",
+            "-334918514756463762318006309600841904719872000000000",
+            "218066148024051247931306674050097536000000",
+            "-3622859125108878497350176000",
+        ],
+    ),
+    (
+        -411,
+        &[
+            "73029635693775668009059727434983158067960210151050313728",
+            "-4679673657864301179943258144578870967000652618661888",
+            "870431545791433602355093719805848213678257602560",
+            "1544633353160505212381702428744859800043520",
+            "7591033806233449501451135280463478784",
+            "4572839098768838399956942848",
+        ],
+    ),
+    (
+        -412,
+        &[
+            "-4191705834203632196398028235232269287109375",
+            "1091737124643141462807028029359619140625",
+            "9115476296360278267158084562500000",
+            "-45283522158500555084406507703125",
+            "-4941005510420834249209618125",
+        ],
+    ),
+    (
+        -420,
+        &[
+            "-6774993267880302065499271589077244607702692106378720313344000000000000",
+            "3322057458803342041196516880396913435501050824830746624000000000000",
+            "343101579425550237027264306100046973285019210099445465088000000000",
+            "-25522767602086427249222314481632218756271917729054720000000000",
+            "-654322371647418124227660658480955270871337023307776000000",
+            "1830759213424837201179800381566577321252585472000000",
+            "-875149953790588830141513408467102803968000",
+            "-9149442836345200514625984000",
+        ],
+    ),
+    (
+        -424,
+        &[
+            "256124659472476156429866214718645776584030123766932075184128",
+            "-473081446853521752764184578414407578553597912291215409152",
+            "236813677534123887255838256365810161940182080793083904",
+            "926676088876656917610604147887399839119029829632",
+            "1384659323070129593431064385863072408432640",
+            "-12423061195029429537745759104",
+        ],
+    ),
+    (
+        -427,
+        &[
+            "155041756222618916546936832000000",
+            "15611455512523783919812608000",
+        ],
+    ),
+    (
+        -432,
+        &[
+            "42889619864187195342544128412237640625000000000000",
+            "3869372376492639837782614434923625000000000000",
+            "34904627315764077727184412247908187500000000",
+            "1007059405271040783775694468925000000000",
+            "280179539493990596285512318134750000",
+            "-22804995243537595825782822000",
+        ],
+    ),
+    (
+        -435,
+        &[
+            "-12512019875237835915942574589201734434816000000",
+            "42866222697779107335351550466659555737600000",
+            "87465379468169320817492479772196864000",
+            "28597298728131202056826060800",
+        ],
+    ),
+    (
+        -436,
+        &[
+            "-3309564689920675611841021602429307330976712868047012658937856",
+            "4190391048071364469026866970440363614326632840014231240704",
+            "-1352721253689086960917768809906285566915367850230153216",
+            "1693419722764462128370611200560660741876060520448",
+            "-5414046507161941300684943471721179845005312",
+            "-30832919939688372877918428288",
+        ],
+    ),
+    (
+        -443,
+        &[
+            "1580383899632304069192804677639613710336000000000000000",
+            "-645677619572710007907896290848702201856000000000000",
+            "726664457760516471225292785548752060416000000000",
+            "-194566138410048201097018632830976000000",
+            "52095503201744864610381824000",
+        ],
+    ),
+    (
+        -444,
+        &[
+            "140475666819520368329021729766569070209916109529535976620891612417",
+            "35757887140074712903193903880466181893290971969408014389848265",
+            "7941658857796452610472278491206058572032514051868247012788",
+            "-880653261173369502922635628724470508870394419131363494",
+            "65188663235550404583293908008034924632905295580644",
+            "192270529336519685224292414265922554522160677",
+            "214860636923611150365915653407923542874",
+            "-56129827213179693251640492651",
+        ],
+    ),
+    (
+        -448,
+        &[
+            "-8964424282273362890505339044524081787109375",
+            "18314847446238545696830716579562500000000",
+            "-1251995474985759392628697477841250000",
+            "-75579535015741588088518020000",
+        ],
+    ),
+    (
+        -451,
+        &[
+            "248738232385414940352605447987918942762879391105024",
+            "6538354632239239965706431356575791482338510110720",
+            "584470709556329910881460450936625902429143040",
+            "37281139264035594329231801543794366611456",
+            "37732368326837192349624395555143680",
+            "94391735188170044104985346048",
+        ],
+    ),
+    (
+        -452,
+        &[
+            "-11416662004233744365076104225688575806170783104288948224000000000000000000000000",
+            "7142314818334140879780574556716393873385301585470423040000000000000000000000",
+            "-3434757541245962455455585314121174603013951177855336448000000000000000000",
+            "50734273795448900729776882856979627119952543763660800000000000000000",
+            "-705631739214792579865177249718891252289626202677248000000000000",
+            "-151206805620428018201992258723610356612075520000000000",
+            "-32401499673044080264344593618377378048000000",
+            "-101634035376709591598457280000",
+        ],
+    ),
+    (
+        -456,
+        &[
+            "1248918496784071679311916182672622781361979369435685895809476427409602379776",
+            "1730649898406031932128295806424087269049881796875556937979523821750714368",
+            "8467038189461862871498232972810788205187612911555813330439126242033664",
+            "-124954167491964417442632961316458508327590070661699479522814984192",
+            "126925519715783333920705563880368538661948026476491698077696",
+            "-259658556171402414149211575300863604618960575238504448",
+            "50427256223409204218353800554361001892364288",
+            "-136491683486572922249812990464",
+        ],
+    ),
+    (
+        -459,
+        &[
+            "-313486865139741282237104926958928242066616974573568",
+            "625812947073456566584812280878079219774059970560",
+            "-347902720129642555336433488166664447480299520",
+            "84716558562768338346996644375759262056448",
+            "-194122915779480469587085735929839616",
+            "170132875256900610745209225216",
+        ],
+    ),
+    (
+        -460,
+        &[
+            "12543639480429066293430609355779410430756351541248000000000",
+            "276071822532474892071316268722729067476434124800000000",
+            "55421606473085222373684460941329804874243072000000",
+            "-78327919900899962643340281002349422131200000",
+            "129803048105744559992341062593376000",
+            "-183068125539610033421078032800",
+        ],
+    ),
+    (
+        -463,
+        &[
+            "401958201191385930754881386234468149942131409474710541242526229371547698974609375",
+            "179283783979941926953411418372928305162361740552420304242063526283264160156250",
+            "24803663967697771228357110388585262448987981420325448447373551971435546875",
+            "-1605693344451100703401166090336916249468909839632742598689941406250",
+            "51970685983805377333634014414418185665726831476030496093750",
+            "-7043755690400037749354220629309859375",
+            "227970800726332644342287312625",
+        ],
+    ),
+    (
+        -467,
+        &[
+            "27129499991079414558992889588446791284555776000000000000000000000",
+            "-20525778000912907436431434169493188780228608000000000000000000",
+            "4482616421350859015252361421040804018782208000000000000000",
+            "-193391929303001486531692307095910239698944000000000000",
+            "13826267033925954485102766290610323193856000000000",
+            "-2053828567675288331297977498861568000000",
+            "305086080876305722343886848000",
+        ],
+    ),
+    (
+        -468,
+        &[
+            "-340463994147289934602083608272153016795136000000000000000000000000",
+            "339536133003961513783146637752654693924864000000000000000000000",
+            "-118470851124585049245451596435428635312128000000000000000000",
+            "137371160123909256018788501396644169515008000000000000000",
+            "-30208651678021480926348924594833080688640000000000000",
+            "-4683655786480679372458417401028686483456000000000",
+            "-187914427539105911963195585831988734208000000",
+            "-328075211021936819361135552000",
+        ],
+    ),
+    (
+        -472,
+        &[
+            "609118140629014547427739243406522843136000000000000000000",
+            "7782762847555792408664371720856640749568000000000000000",
+            "89663269021650272593765224657345386704896000000000000",
+            "-6621978932864958986465185964976874629120000000000",
+            "290243510038159955925726906822209766336000000",
+            "-438370860938320369278668592000",
+        ],
+    ),
+    (
+        -475,
+        &[
+            "-241309172434875116180341986667714653978624",
+            "207773856463139688132677943482420035584",
+            "-18897945215696864441083229261266944",
+            "544368813426921255662610284544",
+        ],
+    ),
+    (
+        -480,
+        &[
+            "-39960503632914197368880697337566374378503148939833344000000000000",
+            "-9173187663430788537963032978719772802158627375837184000000000000",
+            "5551510308698596711118503208118747677498009172215808000000000",
+            "607160366912606109268501665359513440486260263424000000000",
+            "-203722161832791982074330873124726391970063405696000000",
+            "206455764473848540638971437457166653697984000000",
+            "7155256596560029198962042678195854112000",
+            "-779808988929606476240022120000",
+        ],
+    ),
+    (
+        -483,
+        &[
+            "-296241507936739247491345278560108544000000000000",
+            "160587932046974848398336021151875072000000000",
+            "9557426544972522152310585774047232000000",
+            "966618711103413979025620992000",
+        ],
+    ),
+    (
+        -484,
+        &[
+            "-790804636706798790802256045970879381853704727181511395005956096",
+            "763766482177380291593937467835962528924999624812141731643392",
+            "-1071309761927862459988530157923643325520271635829972008960",
+            "-1722172017117768441995881689468749598391253068677120",
+            "-1057839251114856607055720360537330350583746560",
+            "-1038197035676506069321210304640",
+        ],
+    ),
+    (
+        -487,
+        &[
+            "57421135565132223891557098632397533770305157302819821962919612248361110687255859375",
+            "5588885680608056383304285092512678508996655379519917655702570596500396728515625",
+            "1874757683029108665155742485334477560543463641951079278616042397338867187500",
+            "78730410932026586018656083147661205139415494421562037554789062500000",
+            "1653192694311607617535078467802284855790125398437544355468750",
+            "61230417235420005106633643915668500000",
+            "1285765411850702320617829512000",
+        ],
+    ),
+    (
+        -492,
+        &[
+            "10931222269636259868789874889218075826344512000000000000000000",
+            "-33515822578148761626323796875644246586347584000000000000000",
+            "30393066643081701907199820448173303282643440000000000000",
+            "-2541265400786050984712679817021050336000000000",
+            "22444442051052113469935717401578204000000",
+            "-1833713665546358542159577412000",
+        ],
+    ),
+    (
+        -496,
+        &[
+            "61992798182727056220505257386995728000704825102649533554689",
+            "5182664622152485722685558956575902930955839208869950432",
+            "134375279197206001252258427214455143691428583838378",
+            "-70831581292550651074090944215955019084774944",
+            "97788423170643342343762148048633899941",
+            "-2432787421339920412482146578800",
+        ],
+    ),
+    (
+        -499,
+        &[
+            "4671133182399954782798673154437441310949376",
+            "-6063717825494266394722392560011051008",
+            "3005101108071026200706725969920",
+        ],
+    ),
+    (
+        -504,
+        &[
+            "2799688042013765974581855092377396098624947792100121681698929056524795904",
+            "-3897600815758292789948726631304282544292227740386913793601062691667968",
+            "2323993000324698254869780822864191044228454555465898180123012628480",
+            "-471574195381302540308529203117979112030331766766182433600569344",
+            "15164525440459057594603775827713458924796503551119156314112",
+            "18788128805322211910551466490718878898951692369264640",
+            "8815760108240906038966529880878498124402769920",
+            "-4267496525087796057406579169792",
+        ],
+    ),
+    (
+        -507,
+        &[
+            "52801843586884277419825606742991962112000000000000",
+            "2658551097838253705341260639403769856000000000",
+            "91537611307702496719179485710123008000000",
+            "5262585338995033221326389248000",
+        ],
+    ),
+    (
+        -508,
+        &[
+            "-23012491049940208859981226392177581787109375",
+            "53837780625702373569505874473236328125000",
+            "-225348493809521765537422792233398437500",
+            "-46144011598251049101578232084609375",
+            "-5642626022844047596709323728750",
+        ],
+    ),
+    (
+        -512,
+        &[
+            "-806852196324512749711177421724928934161602703561365604400634765625000000",
+            "681203773332039691893050931796665219768053212249755859375000000000000",
+            "391490552495267097159787120207959794557048688265606445312500000000",
+            "-223690975937085875433060751264894961767384572256109375000000000",
+            "-148572702855778718412466858841748276236039359231597656250000",
+            "-2851114402367216695790564469103305040242305000000000",
+            "-145963318061678312360174387732317218500000",
+            "-7452683886314845038967022344000",
+        ],
+    ),
+    (
+        -515,
+        &[
+            "44195318902652537887832280872617801166384771408066510848000000000",
+            "-3107115217734912014460860938978340298227921413210112000000000",
+            "6259740439766871889137441931457556788367376087252992000000",
+            "4021108268646819914222211212116150442157670400000000",
+            "-192074704335054414490094597558883057664000",
+            "9175438450996787302014492672000",
+        ],
+    ),
+    (
+        -520,
+        &[
+            "171517475891022372428505519185548559222346497654784000000",
+            "-78006534528871949845908360976579586206001479680000000",
+            "46650003139146307922421888174845453223975936000",
+            "-12958889442406058296422344736000",
+        ],
+    ),
+    (
+        -523,
+        &[
+            "3397618365767017867913805692928000000000000000",
+            "-8335801454396454796105214853120000000000000",
+            "13395061255385032931309223149568000000000",
+            "-236957616665436077155248242688000000",
+            "15928926361335375229020229632000",
+        ],
+    ),
+    (
+        -528,
+        &[
+            "4988393622915667926124020859030025205499033953000000000000000000000000",
+            "-3125453974501003826528122952771963651286860136000000000000000000000",
+            "260074116152839631239617979770103282895825724000000000000000000",
+            "-49399290627400664448231786729782700582640152000000000000000",
+            "1843432957754824762877050635202551236433894000000000000",
+            "-43583958115994354629809488173134159381336000000000",
+            "634407243120218221454715363762308604000000",
+            "-22437876453496773112166179752000",
+        ],
+    ),
+    (
+        -531,
+        &[
+            "253019721297801601360183161111100670441677888450509930496",
+            "89695055074984733543572254320373841113802328038703104",
+            "-5668347118698040482683727597487173897920299663360",
+            "101913303540872546991651789090862425056477184",
+            "31476922594694825843813187240301953024",
+            "27537270228600020374834178392064",
+        ],
+    ),
+    (
+        -532,
+        &[
+            "-19077542993352945680961028994697271308288000000000000",
+            "5131537740610192962070880163006969643272192000000000",
+            "-160054212938390343773833947283393690785408000000",
+            "-29478909019098139074177479136000",
+        ],
+    ),
+    (
+        -539,
+        &[
+            "1144459052643366311610439351265038555439404235994701823391653412879074328576",
+            "4261991149768461489213323496791559948794987690089863268831480604098822144",
+            "-2208425211409708405613626208046070387779355774705344999909565531160576",
+            "292526361722254725823538617267010164162341238195314204668707471360",
+            "-217981732176056066846071789416714359026166245977669041651712",
+            "62109863363003743433903162606853581012680421194858496",
+            "-1716077476743667409888370160098275988865024",
+            "47410121650650301653432335925248",
+        ],
+    ),
+    (
+        -540,
+        &[
+            "94309638503639876530118685319843252440163328712890625",
+            "5566107605838844555689235368518602993799267578125",
+            "8447962021182385992754037292591932158628718750",
+            "-1406534299971645322507825760505592082625000",
+            "111924477234172546946300759710144789125",
+            "-50727256906489049145803328275625",
+        ],
+    ),
+    (
+        -544,
+        &[
+            "3546089830243092385615929023351941513205478912902402742839005333684224",
+            "102086912171771116817796794947834892963506005427904794963501645824",
+            "-35702795631006922750685000006261508710356282765489810248302592",
+            "-432522777991807229621883601376511184601871339411006554112",
+            "-32419065600743445230946572610601158137090004801921024",
+            "-22892806287742237518499426963720721767972442112",
+            "-6248339790094127204968351395966540528384",
+            "-66443353348592333221809555207744",
+        ],
+    ),
+    (
+        -547,
+        &[
+            "83303937570678403968635240448000000000",
+            "-139712328431787827943469744128000000",
+            "81297395539631654721637478400000",
+        ],
+    ),
+    (
+        -548,
+        &[
+            "-590518364908089997157788286780557493338263451790244315136000000000000000000000000000000",
+            "-369511718934717082008120249435842871162487306630914113536000000000000000000000000000",
+            "-68969925509725263658955759656306082347381966434548077950926848000000000000000000",
+            "328860467798330152026138277690382532201612700511391601917952000000000000000",
+            "-1590986068171697316090499222810749646317714109166272880640000000000000",
+            "-35913514563962808407375283139347172053211266813952000000000",
+            "-810679935679825582353227029639493628299520000000",
+            "-86942389891535571387895159232000",
+        ],
+    ),
+    (
+        -552,
+        &[
+            "4014753581735003605099707048940053282890161311449088000000000000000000000000000",
+            "-1491869338681587120775123496943489151278113693267656704000000000000000000000",
+            "17960043006420077918365652794024303539913763116393955328000000000000000000",
+            "784981509198717408666378173537115910201562782880759808000000000000000",
+            "9038247293573332903169489007252044529427146881654784000000000000",
+            "-58691611938818455012339509695021606047668420243456000000000",
+            "1211661287326221121647888045036795713947392000000",
+            "-113654378836535352053072666688000",
+        ],
+    ),
+    (
+        -555,
+        &[
+            "-532755731205331063356397364951543957176713216000000",
+            "19282254568556435196991625190065063388512256000000",
+            "7191013406366483381037450688276469907456000",
+            "138859536630220704987259502592000",
+        ],
+    ),
+    (
+        -564,
+        &[
+            "1410539124587286819594160671955922149433268352692068786895315904358800938785996537856",
+            "-186423153957074627217818566668875191535626845784427349342910228378668486478528512",
+            "579473691440252779828924728815673313990523698707440360772339652903654316310528",
+            "2625550468143840642133580169868794560581148602007440852444168880344006656",
+            "1302812260970235447356611686844899893591737005827828144605136683008",
+            "253450842651286374093742999966154467299881141887690283679744",
+            "-4010633301324997153376681649711556335962683097088",
+            "-252431252389499030278869212407296",
+        ],
+    ),
+    (
+        -568,
+        &[
+            "17903747548118085544966894162888109264474112000000000000",
+            "-20244861194040338252021384794239225557256192000000000",
+            "5960215994584814927107650154330552605647232000000",
+            "-328731508303364809994652861984000",
+        ],
+    ),
+    (
+        -571,
+        &[
+            "15283054453672803818066421650036653646232315192410112",
+            "-16319730975176203906274913715913862844512542392320",
+            "4398250752422094811238689419574422303726895104",
+            "818520809154613065770038265334290448384",
+            "400497845154831586723701480652800",
+        ],
+    ),
+    (
+        -576,
+        &[
+            "-7602490733367488535897668492863137470392617456264775830022452670464",
+            "88116521568151584654036201686737798333142606560802872668139192320",
+            "-1365764709104650008266673293866920077982749326584527839660032",
+            "2480782362943649249382108732853938143628584892255169449984",
+            "-1070353558135299522123047513088476157789564153081704064",
+            "180124328580974236194223495011442865285847657984",
+            "-84148257487716879382693739006011130722848",
+            "-555945849395316019166302041083712",
+        ],
+    ),
+    (
+        -579,
+        &[
+            "464793232343043366687149097962066772372436476624601281935812010755227648",
+            "889018614566106780555955520613217606148244526529209723237842473189376",
+            "1935790582495037184837110530204500965567499242790121844765060235264",
+            "-19228720281929478997861296918696489205537963466212473355370496",
+            "813252088012203280786637779721301238218392085073489821696",
+            "-355122564397718134453623922597744991048394816356352",
+            "59369596213670280006060371111787973029396480",
+            "676387026992113450418827441373184",
+        ],
+    ),
+    (
+        -580,
+        &[
+            "198368107463433017941006791366516074514256704929312208800710656000000000000",
+            "-83739778810631947586278845749607438058966141170065086611456000000000000",
+            "26471525240640094299611509783726774850968702835808962347008000000000",
+            "173217007083074231180921840492523500694094885049440665600000000000",
+            "6306662778286541896224720215753687993996186318789935104000000",
+            "70673010179850126682628372153395761510501489672192000000",
+            "-19399954774822931642109937778300582467033049088000",
+            "-721994371141654983621057960384000",
+        ],
+    ),
+    (
+        -583,
+        &[
+            "1590143082415425099832376722791937259208120703181660837720379331722520166770040988922119140625",
+            "3847861626831776140177907944066571604742540817163324687287108373890033208510875701904296875",
+            "205213621872793706956814679926046328336330763376698513972794088442834571449279785156250",
+            "22831652887675839809425610107112362235050044467123090293579232129459071014404296875",
+            "187579563689438993078275012426193351931475823977067311551061949434570312500",
+            "770559803726795588490479801073714416794993586323673088060339843750",
+            "213687783014367910404538221903981315562500",
+            "877815358561694090436309899526375",
+        ],
+    ),
+    (
+        -587,
+        &[
+            "748765079750903678495365866324569504346756859559936000000000000000000000",
+            "-19523831231348384917508345284946898016410494042112000000000000000000",
+            "35495224444423948749828541418253640332882575622144000000000000000",
+            "-1196192801910538190537501166807952753551892021248000000000000",
+            "12408121464739840095494810222119511810092564480000000000",
+            "-118840621090042353846268441242275676160000000",
+            "1138212574651782271861893763072000",
+        ],
+    ),
+    (
+        -588,
+        &[
+            "2320214404236209026697667252031829533741926076992000000000000000000",
+            "-6126070918172001115197989066875857222316541112384000000000000000",
+            "4515181954964345872129019514813523000853053374960000000000000",
+            "-42689249963097095301009077305839336539616000000000",
+            "129566015701225407418600263236982900444000000",
+            "-1214418339247561600451288629572000",
+        ],
+    ),
+    (
+        -592,
+        &[
+            "62432994413265034555246757429466475569000000000000",
+            "-466359042858849293316032894138251092000000000",
+            "313250432161122948352163248725179238000000",
+            "-1572930194931239851927605196116000",
+        ],
+    ),
+    (
+        -595,
+        &[
+            "-91399742601830803813322386656934773129216000000",
+            "483054636550112292687021684688517332992000000",
+            "8752111455147508300981595950899265536000",
+            "1908606683491595666107623383040000",
+        ],
+    ),
+    (
+        -600,
+        &[
+            "55013380133451393116848595517196372565257506885892427360818803276704780162093285376",
+            "-61282939096755094303393175061204181878387076364637532503966095425149106460295168",
+            "24156512891390177820296550150936191360793744111937453705629023257758265245696",
+            "91451832856249850749235233312508618806959496173472729513610223021981696",
+            "6521729878535446154278409534452392826707332084898957563834425933824",
+            "-18641276292253812460007663722866367267688408822355559817674752",
+            "135017303206384813041472625874556363749460749041664",
+            "-2631836103739116229072915919018496",
+        ],
+    ),
+    (
+        -603,
+        &[
+            "469351599148575084436348037234688000000000000",
+            "3412681538174356730332626026496000000000",
+            "-7118624290306318120963251634176000000",
+            "3189372971004509360884026494976000",
+        ],
+    ),
+    (
+        -604,
+        &[
+            "-7012146253203503912831018430842715194031196057598620127481087",
+            "-21319470454179879685723776607133376307443575059942846246545",
+            "-20749638033764370644667918509817688533672193402905398799",
+            "1972365927959206754270912573078693432452072601116122",
+            "-87265570121736949364749103114574552336478295754",
+            "-27892560713584967621392836420154057195467",
+            "-3399966604866752996234882792516118",
+        ],
+    ),
+    (
+        -612,
+        &[
+            "-15571098856932416432452346693067405520732046753792000000000000000000000000",
+            "64385398716456586511259634496932570397458806341632000000000000000000000",
+            "-60647700418651101075329167193338060993220119101440000000000000000000",
+            "21088996971330859051550580999287716400647200571392000000000000000",
+            "-2290622458564054657603845877685847902615339245568000000000000",
+            "40333788093860130358682695067026076630909972480000000000",
+            "-425804630413623702061423925745792936499742976000000",
+            "-5659876044371959794523441504704000",
+        ],
+    ),
+    (
+        -616,
+        &[
+            "11560940605988608775021317978622345492706919224700295857630419662012226685894656",
+            "2700677818868483334143493918654373479601273935130176142170749678205435641856",
+            "-735940732480031475341295570292750267451601629452483959056301009848500224",
+            "-1509969588210077671850355610553825063590552171300597114606956539019264",
+            "22015145396000446122535780492386767387477172709823430101437513728",
+            "-2324270361846276694520289144246063962274570610361713557504",
+            "622867749031723592493894650264766096595528541257728",
+            "-7293408905015292299958781016209920",
+        ],
+    ),
+    (
+        -619,
+        &[
+            "1646062182335949197810917415545902866747444946845936123904",
+            "28493830345553696446401792570748375365356507652685824",
+            "326238883724948585436745550058572488040138145792",
+            "-87016716912343398450728998742124757254144",
+            "8816350462749494490997859322396672",
+        ],
+    ),
+    (
+        -624,
+        &[
+            "102548297330276179793325830900593766828176427011543250758911519759091095154689",
+            "-131955483795983141701923334415696263798949802626995349783072378622040774032",
+            "111572886623343147663630623008495040639710527701226046358651893948119597",
+            "-39428213197329507391941426442724389924654284081666013188874712056512",
+            "-9621269365319716201375903451806501673947384352719617824411634",
+            "-889635385561600455574614631815638447700551730035013312",
+            "2776009013186625130059222083251004824229999988",
+            "-12081208936232946835974528691267392",
+        ],
+    ),
+    (
+        -627,
+        &[
+            "-1261687189208313891495979730091871567872000000000000",
+            "526326624169690832922357632213666758656000000000",
+            "3563858169242172480409901737583233204224000000",
+            "14586137722924213400310156521472000",
+        ],
+    ),
+    (
+        -628,
+        &[
+            "-193480668290139827978551941260794168997079416832000000000000000000",
+            "2244973573297457770564792736595644257458378178560000000000000000",
+            "-11306325980421358690756668239288149306045473558528000000000000",
+            "-62749081242237832118702516655452609031428167680000000000",
+            "-1935352780955944330159655079539039563020333120000000",
+            "-15530070499668424480113373087440000",
+        ],
+    ),
+    (
+        -632,
+        &[
+            "2455690780734897158024652314220420994123935277811138917072713665216512000000000000000000000000",
+            "-205488254882956257205479218794806299367131414053420769060690765807616000000000000000000000",
+            "55724235915610201148663635533333380165079185083147643595058601525248000000000000000000",
+            "106829316722129775247627565895561429417067966526797538786508275712000000000000000",
+            "207218464905915998193116571927979322720373108284183824226869248000000000000",
+            "764066324240903098557942341866117792957681012391972864000000000",
+            "2817303777314491542198439675939656413173827328000000",
+            "-19947555143901432297225495030848000",
+        ],
+    ),
+    (
+        -640,
+        &[
+            "-581710103322761859517036426143314268891628534114908227919000000000000",
+            "480772669868710949294360006170595491440464592493228248000000000000",
+            "-208224713897606493365400670528073218506373263196739628000000000",
+            "825645933545585884183454303042164761317468456494392000000000",
+            "-12192015970979998111636928370459610807085065697166000000",
+            "113340022902532960700341451328360099391817016000000",
+            "-13709186884725300434264481820110146306292000",
+            "-32831816404527409021029099698184000",
+        ],
+    ),
+    (
+        -643,
+        &[
+            "308052554652302847380880841299197952000000000",
+            "-6300378505047247876499651797450752000000",
+            "39545575162726134099492467011584000",
+        ],
+    ),
+    (
+        -648,
+        &[
+            "420259747133910150639354663643453078777790464000000000000000000000",
+            "945704570192557917123296384393395078314143318016000000000000000",
+            "708496467215673879034146895464634305344685797376000000000000",
+            "91062241689319860183508830989589146658538772480000000000",
+            "12503387249746481540592689090608670703243756480000000",
+            "-53870596955882124816323280316464000",
+        ],
+    ),
+    (
+        -651,
+        &[
+            "-837262586007462653261909466967763200588899395236480392253092673936032792576",
+            "1560118463031527141221515438671888287954223071567667616115946475371888640",
+            "-912999388135258927743103857780845055354520225412727777466772650721280",
+            "194061545533182518530854613232829236130187347228804642343458177024",
+            "2201746287081394599531696187598856165332862423537538188705792",
+            "149807147439202860005904946308171008696904675005300736",
+            "26034366770144460869683948058018263181668909056",
+            "64812026197729426550178459657240576",
+        ],
+    ),
+    (
+        -652,
+        &[
+            "-18095625621665522953693950872675200892692248000000000",
+            "102561728837719322645921325412908000000",
+            "-68925893036109279891085639286946000",
+        ],
+    ),
+    (
+        -660,
+        &[
+            "-956825222980306326585980265538528042697197583016225087193752883841407647744000000000000",
+            "802614337824902100615094698396471270494041411707339702383464010676279705600000000000",
+            "145752537220577621057671362142160834897197172505227599866968670493212672000000000",
+            "-129459690207405843746931738609689674381742368424208527099314604448153600000000",
+            "-174143430251936272102859349073539136097610895959350576901027004416000000",
+            "18239982511248258426444458529164454527146102008187866100531200000",
+            "-37774101659997423005408109970789812947961704097792000",
+            "-112580501740220781071235722688652800",
+        ],
+    ),
+    (
+        -667,
+        &[
+            "-278701754438991300992352387072000000000000000",
+            "-147087485221823269890900432519168000000000",
+            "-3737847346141410401145461932032000000",
+            "172524940705544715709707399634944000",
+        ],
+    ),
+    (
+        -672,
+        &[
+            "-4237992552215997959225380114411105686586915423749279000000000000000000000000",
+            "-9838879748511172986462511565394793711371892625656920000000000000000000000",
+            "7978703763080054938708661423956172316164103102603828000000000000000000",
+            "-38314338299128352942471133016488104735916144615960000000000000000",
+            "-11648430060977090154400304982641226170804037030138000000000000",
+            "100076681410892904733083303960226381255626488280000000000",
+            "143795796879281564339732958685144384310388000000",
+            "-233710087038209530076475413533800000",
+        ],
+    ),
+    (
+        -675,
+        &[
+            "-518934241375857119324489861138068922472670611351011328000",
+            "365545010929303872566806343360090400895165551083520000",
+            "-40403338809853991973278867579964893049937251532800",
+            "3283548433378494044784212121590732589681868800",
+            "36741609284830353098825275576161864253440",
+            "280244748103684391377172529765089280",
+        ],
+    ),
+    (
+        -676,
+        &[
+            "-437940714559143999422451459680237045189874838812636812209273628143801860096",
+            "183121307244468811013362819441915945367491906284343782971561865394520064",
+            "-25139996004850385022058823419251332525548857652725838427880085782528",
+            "1250093798808181921331239024003439064057314451090248756625408",
+            "-162434321923500244963691319577164899941782327177547776",
+            "-297704363274819300973648925452724352",
+        ],
+    ),
+    (
+        -683,
+        &[
+            "26166115688569819428666837825663510027688881422336000000000000000000",
+            "-5025162304773332210314910428254527856822229389017088000000000000",
+            "268100148999161642690747540961044577891601065443328000000000",
+            "-348849132150121827613917484442174019862528000000",
+            "453918858809750227974703697494016000",
+        ],
+    ),
+    (
+        -688,
+        &[
+            "35666765383644406075046298046290446413640625000000000000",
+            "418692965492345733785114777449885492571625000000000000",
+            "2247353146940073239738967954039378310380187500000000",
+            "-1563641315241543882064062754494470169795000000000",
+            "542090882349799145243237595878239747254750000",
+            "-612711818312922195678985083375654000",
+        ],
+    ),
+    (
+        -691,
+        &[
+            "2881012171895295750002031701073564303892269058276549227184128",
+            "-9744515674227833316204230178037052905354521396539031552",
+            "162256308439015306053871060497929160439748974608384",
+            "6703571995070311798431761340831465046278144",
+            "733155214316421345930476736919437312",
+        ],
+    ),
+    (
+        -700,
+        &[
+            "1279550552930955466965967752790818024379275213338521562625",
+            "-857637007838292266512501851631510160613001848780896250",
+            "152994800337304876257175053273288365860639529326575",
+            "6145165148367786469915434874758615187908118800",
+            "131092763407177117096672838357506053862815",
+            "-1253156380350127685465362671290113845",
+        ],
+    ),
+    (
+        -707,
+        &[
+            "45650758520764343599531218962600327069641099781865472000000000000000000000",
+            "6903373536375246004273099602846064408758831459747233792000000000000000",
+            "483991714896097062009964822901019056444300864589398016000000000000",
+            "2906768622940702048127349597557735037973448835989504000000000",
+            "-2348163642809062173079548581285822944247808000000",
+            "1896908196134808026819028803674112000",
+        ],
+    ),
+    (
+        -708,
+        &[
+            "4046686423378034814414234559373865948538701215210194862739456000000000000",
+            "3603887011528002652771717224491220641587422892784070051840000000000",
+            "-2854565250565963840094617979015298078098347812480000000",
+            "-2012303924332635494819557244440800000",
+        ],
+    ),
+    (
+        -712,
+        &[
+            "648241567076450859966234852141519010761029802805690368000000000000000000000000",
+            "-1145543789950432126531816380596120320711223446238920704000000000000000000000",
+            "785817223009850352446749662671181156471363221427585024000000000000000000",
+            "-344229835327193328453009390255310533401770044568633344000000000000000",
+            "101406901678845248734425825236242472665257177466036224000000000000",
+            "267549782848128597871924625562997820500018126688256000000000",
+            "4065864582832242011994655020099723505337949767424000000",
+            "-2547428128763396947913203000755264000",
+        ],
+    ),
+    (
+        -715,
+        &[
+            "13189879204176058896562640516998642620432384000000",
+            "94657547256854352451418607502680693669888000000",
+            "60156378344564221943954774472086041657344000",
+            "3038922093329613647424771157499904000",
+        ],
+    ),
+    (
+        -720,
+        &[
+            "61507529066665547077295393439762642391874670601552764521141702656000000000000",
+            "-4353073491362129429798741859705534468181235201826237399928832000000000000",
+            "-2441944265123302492258018956512015449963136520414891272192000000000",
+            "-3135891451090538129338545856605366334269691931434972672000000000",
+            "-35157722076390404483056409042116830868695186279213696000000",
+            "-119800193754976471093440042600863526672278849984000000",
+            "5872950365773055261283314597325177015222112000",
+            "-4074358963317658037659497739760296000",
+        ],
+    ),
+    (
+        -723,
+        &[
+            "43799003445375960815587788104700084092928000000000000000",
+            "-17437817166277457429521660531780027831812096000000000",
+            "8222450770908698023546828197247145547399168000000",
+            "4855690107103225136120718536060928000",
+        ],
+    ),
+    (
+        -732,
+        &[
+            "19312819940374538093959012358772622468823540804560995161533355712890625",
+            "-18338497339239136844269076809669249520895538810549694538116455078125",
+            "205125367818239586357366782447551048958050275793200634002685546875",
+            "-106966283826789921533027550312322000548643705272569366455078125",
+            "32266017029436711945574899617391240101374329554424316406250",
+            "-1044468437161280752436852160777372621759135920250000000",
+            "16539025024426427599747332149490289739251569328125",
+            "-8201294701505533978881129925587761625",
+        ],
+    ),
+    (
+        -736,
+        &[
+            "60061263990047355807092716181487945219840930463261944563173609758415329951744",
+            "66298044925638540657730379120853125593774429600664803587820813665498562560",
+            "21287278237940740317394910024801922545381848383031525595200716329189376",
+            "-19806036305729627216728529468133217884407295134608720254698520576",
+            "-11859979559323959907927043487764672796249235605608724323475456",
+            "283242345825578385963814784975509432839483190517919744",
+            "-18384487215963377937832461451320115047707708160",
+            "-10341954237504564977090663227248977472",
+        ],
+    ),
+    (
+        -739,
+        &[
+            "70879256868963610140332287398622548391739243260758328344576",
+            "-39465098292737222691630856494517926607508369968554049536",
+            "8435175478606501944760514102198191107943823755444224",
+            "-521202850366310037383168135322581564217360384",
+            "12301647126979210892135760009684713472",
+        ],
+    ),
+    (
+        -747,
+        &[
+            "122610264884195004276135169939960886198272000000000000000000",
+            "-64835561517901382404643579646446405681152000000000000000",
+            "6153305059000738275431089671195439661056000000000000",
+            "867239909489765164489182213498417971200000000000",
+            "-1739910303132650092314136789278261248000000",
+            "19506549129659843880518872730185728000",
+        ],
+    ),
+    (
+        -748,
+        &[
+            "14102760806179287374525913233889892863270846335963712000000000000000000",
+            "-4161062207951390320730581122152303002996237381468224000000000000000",
+            "-35789562604701242403041126998666027131602379470352000000000000",
+            "-93907027012642433024639652171634097345735823840000000000",
+            "-7843166260951182924654299188909364004000000",
+            "-20660082823607096260557693839278404000",
+        ],
+    ),
+    (
+        -760,
+        &[
+            "57390991709103678336339431944416743303984993656228540622045184000000",
+            "-8762694788548498478760416933120597566268079681131589510758400000",
+            "262960509575258849119050573504013616920976671774792704000",
+            "-41045008988631123111685822548134227200",
+        ],
+    ),
+    (
+        -763,
+        &[
+            "1212202634617724845661254714392576000000000000000",
+            "3730143008151395358758986101112700928000000000",
+            "11764579526453656222964578511153528832000000",
+            "48688224497542950284157258615128064000",
+        ],
+    ),
+    (
+        -768,
+        &[
+            "15926143836920796849094002857387135460968690480161221686575776100158691406250000",
+            "-6042818923606714182438083804301870179528875596947614517314453125000000000000",
+            "4900698705373764641365354757988280247136785578572898154329101562500000000",
+            "46721890317786185410700227174952944124137546155237676733203125000000000",
+            "81580198367732340212612911642019252294658707587093110574218750000",
+            "736154608709059015006498116049282929703692588255135000000000",
+            "259399171372225204966661002550162965440584749500000",
+            "-64670563924749466394147714711210760000",
+        ],
+    ),
+    (
+        -771,
+        &[
+            "1158874532512529823117839025885691033242715995831907669464960496690003968",
+            "-1429554314369800014560099942657739809600837745393128794016090721091584",
+            "463885955604298483342404429131145213835946162203272517583917547520",
+            "7594080830144514531813778205972493677835173653113137528832",
+            "325572209586735477136602675439314988370753017085952",
+            "76644978500101583982634373055498485760",
+        ],
+    ),
+    (
+        -772,
+        &[
+            "-4039979678479578220330132982722340932044073244946432000000000000",
+            "-1654219429424921222911088262751088404746562249930752000000000",
+            "-730409189972766569984362477406681962614314316392064000000",
+            "-81104350841312411963776730201270496000",
+        ],
+    ),
+    (
+        -784,
+        &[
+            "807270238402274589348185900788787471062100857196239802698769503154552004673536",
+            "549343005374796832642333544610489884407197978370108333534030549561248841728",
+            "3844427032631139909031306813810132037738046220332405961176762437166170112",
+            "-1313878453588348454235436172116960425379628117793329258035991543808",
+            "1081819995845036197038223842100241602490710281886437446644948992",
+            "39749953136422304796504032104692468442173444903095734272",
+            "555191826737960502328886428204960418989244065536",
+            "-159418202346978959346217517779224787008",
+        ],
+    ),
+    (
+        -787,
+        &[
+            "121799441042213268250468077932063490048000000000000000000",
+            "17969534868961880720450029518624242270208000000000000",
+            "16295872719960594906811990015695057321984000000000",
+            "69003057677093510781188231291411103744000000",
+            "188607826190137802296622247543103488000",
+        ],
+    ),
+    (
+        -792,
+        &[
+            "12768644754055994254973993366065137681048723001341640704000000000000000000000000",
+            "58427294420347510769774516683508948517463699082742595584000000000000000000000",
+            "81022052673617626141650602652866142458780992098656059392000000000000000000",
+            "-24793183997044981061979131341740434855851457826254487552000000000000000",
+            "23663161891476970903060191195435997005441856270786560000000000000",
+            "-21664732727152455018727704474524695006816938100617216000000000",
+            "3939409895697019766396355027869840460446054032918272000000",
+            "-249433117287892229270918840407682112000",
+        ],
+    ),
+    (
+        -795,
+        &[
+            "1580866394929445594613317271657673734190830966521462784000000",
+            "96989374802114211792220362019627433906928110027145216000000",
+            "1962512368737475150054890329369747830206508302336000",
+            "294853904675299611949375562546552832000",
+        ],
+    ),
+    (
+        -808,
+        &[
+            "47935532966149606407423899542710890275091793038645919744000000000000000000",
+            "181128288867528862366782440535423928657211618498508029952000000000000000",
+            "1803501184045414415458226074594817032543094739149616377856000000000000",
+            "-6485648984910606765832741139482781395227930706015758336000000000",
+            "14938062448136132459695331800504856899108496990798784000000",
+            "-606544748743842831563632744122859056000",
+        ],
+    ),
+    (
+        -811,
+        &[
+            "3952215468738319514208754467324345971628622588902185420060055573551382528",
+            "-800651781208977500867676974252244833555687753006234188380537794592768",
+            "319996596219961348581003665482834034884511040424887266928167485440",
+            "-1602886981400187091839127350649694061166802105702833188765696",
+            "2476190510453623046511601604479343457848144109936574464",
+            "25645222687092338722305478236341654296989794304",
+            "715803850277269060143337898717986062336",
+        ],
+    ),
+    (
+        -819,
+        &[
+            "7932649557486827877721052585800733725794481007039923471061215793209845743616",
+            "18085702271142096270607084197564738642542304682778387430224624968248852480",
+            "47531908122934069403840238579506488481423813924084185371336719204352",
+            "-3127174271915303444994090267206245302384335327425454239471632384",
+            "1735600166630284662960529896251419436469964427472366093729792",
+            "4573383074290963647652713532439650961436595502689812480",
+            "-115499647642003452853119475957670250711430987776",
+            "1111636203878351887090798016351101059072",
+        ],
+    ),
+    (
+        -820,
+        &[
+            "204637330106600513741387631240141531344900447928153860219989040516038656000000000000",
+            "-209206135951926489244947052191419600060571429945927215378285293731840000000000000",
+            "326685341487801693986861349083668135208043197693638468385037204062208000000000",
+            "-49385838859794684578617903350009352542131555885099985549832224768000000000",
+            "5921762429084184504482281276631533175886519613599247856347643904000000",
+            "2622032066129308298911642413719025994246636501871201799733248000000",
+            "-40242920602555180754171145386354425306546394747775756288000",
+            "-1174337676197029514663257641696326592000",
+        ],
+    ),
+    (
+        -827,
+        &[
+            "2445553702661102418981136764326445223258766539896504908251136000000000000000000000",
+            "-1076015162843768992957043985457154345188464247512545705852928000000000000000000",
+            "212605001857755402367927910894529265872742648535302639976448000000000000000",
+            "-12601837950859481738652073800208712904901629097618505203712000000000000",
+            "247552058473310741374898566702532407246283982086317539328000000000",
+            "-20650626601662469145660884497034859290382303232000000",
+            "1722661406174248169173628838770601984000",
+        ],
+    ),
+    (
+        -828,
+        &[
+            "994085932928328513269075382688739701324515086338043212890625",
+            "-1055648796239235006353912975802938543893809630310058593750",
+            "303627853695026697747014082081770550922214087158203125",
+            "-6255952940792043686802768545302127864857236328125",
+            "-155241642964074826687355141409694317641437500",
+            "-1819343755551914380120575986714936366375",
+        ],
+    ),
+    (
+        -832,
+        &[
+            "207455704364995393622733817970863249742168401731898681640625000000000000",
+            "108736773033579666864121372867362729243783825595849265625000000000000000",
+            "-97211054401478483386765465438623057829086679366423757812500000000000",
+            "26741394021605886219351747174406277984150994507560625000000000000",
+            "-2613042685916467354712948098864927956499450281041156250000000",
+            "-11601158474487928781533831464284645353443867861000000000",
+            "-15607587817412718675757939483246624787512852500000",
+            "-2262722119966669232161707231304764936000",
+        ],
+    ),
+    (
+        -835,
+        &[
+            "4891900660599003311385391904709529453654625325416448000000000",
+            "-7042823494828064970531335733824610561942964980940800000000",
+            "6664997136310346124828673949691089890037414756352000000",
+            "-2065662052326646805861259358837766884548201676800000",
+            "204438038298621794574204160089592858008354816000",
+            "2663908095665787906008140453642906828800",
+        ],
+    ),
+    (
+        -840,
+        &[
+            "7587169380271379738636919142674280077130439504327732605512510089785122099137867107270656000000000000",
+            "-5112159939990146378938499680802637042771646067107417706535388782137560566356569069977600000000000",
+            "454668527671405657965710869144455214652592634921420559367890411545189775674863255552000000000",
+            "-1111712812272489788109971969097031933551408742194642794550538731744862298072678400000000",
+            "267678830160178923896641219852982233572924885080172883621331723095220158464000000",
+            "-3134769336133353615460866275393209275783941494973163498240275428147200000",
+            "206573882876758009898241769258678546966352946154161788928000",
+            "-3494487845306481075093315600749304691200",
+        ],
+    ),
+    (
+        -843,
+        &[
+            "158692695909564629221707977026748300372656225517568000000000000000000000",
+            "73075310770259371090473394277205354753774863920398336000000000000000",
+            "13875309039130379123931424254934593471702463886131200000000000000",
+            "37712121952130322350424411490310586953108092354560000000000",
+            "65853429066427465015436329665082314084247928832000000",
+            "4110870386791186329312795254569168896000",
+        ],
+    ),
+    (
+        -852,
+        &[
+            "1612367267971475199440710611641385268057869742190282738951898520765857792000000000000000000000000",
+            "-2661304063206281188420347972457047853310490375560359790756330900853620736000000000000000000000",
+            "10658653499206182817142966888146260430750861112801078521762505443500883968000000000000000000",
+            "12089889771973566318301723197286312377799073330749870993054354496028672000000000000000",
+            "46846835599092601355288226682492045721779606244807135121664326524928000000000000",
+            "10284821622033602451670634606132066226181623275279140805494861824000000000",
+            "-546074483267397728325634259644287686071603927317457152000000",
+            "-6680895145643489619062121564880755648000",
+        ],
+    ),
+    (
+        -856,
+        &[
+            "595320092985311485518113602681984286078598796937520420636189368159335021853433397248",
+            "100840602675506952168901909673258837400582800529922501097017442693461370191478784",
+            "6991369178933310977728137772066916192262112745060166206505651706920934834176",
+            "-44861747133100666670326391171999140977507020813422564697216507707392",
+            "753904043512209705196167696045982334339284730437509446135808",
+            "-8283439699523057056710299565708340695936",
+        ],
+    ),
+    (
+        -859,
+        &[
+            "60840761437613566888711032177150718357895558969645698306118384015873409024",
+            "-62251432058869446795243738839465754101255963512935282057369368685182976",
+            "25687949852570186267052644386825393638247990061761236292055605444608",
+            "-22891549188863688130830736915023220430586081481798242357215232",
+            "96605827634631091326644502274552505349352028214078210048",
+            "-1567981867819951857438274995475995306493967597568",
+            "9729704930387459982187979811126816866304",
+        ],
+    ),
+    (
+        -867,
+        &[
+            "54198575106762219309306750175869557905848927491457024000000000000000000",
+            "106110003377820534797452421571371781575278631527120896000000000000000",
+            "110435906540395590439728400091584740929517223963262976000000000000",
+            "-363299799803406415238234259440206899137613209272320000000000",
+            "367424180219313125835753436424699754428334342144000000",
+            "14923676060461817600380995991658397696000",
+        ],
+    ),
+    (
+        -868,
+        &[
+            "11371446703972153557005184311153593718600725700309629796352000000000000000000000000",
+            "44378942591429771358105182513581760001477784533918208229376000000000000000000000",
+            "-25842148695545547358731852874265215585849882892400677879808000000000000000000",
+            "-3688512135428749819755387763199836244226650046656053248000000000000000000",
+            "4832336678320186564947698246363596254480056565762808111104000000000000",
+            "1084165259024824617523114514914286804516387861794134945792000000000",
+            "-1974950399340297216385036265608215318709613636666722560000000",
+            "-15741186408247165829355299967450436032000",
+        ],
+    ),
+    (
+        -880,
+        &[
+            "437933653351422497825712813509539954823526996235369230198777456706540918212890625",
+            "-482160540467556939032577498022028447151670172574242129886009889282324218750000",
+            "171401658686352720418782023552351340224675268711254376519116362515099609375",
+            "-17699499386266304885284098943933707102943005712120323244803654562500000",
+            "-12425315525808682626492416080553384697818961835242196615181859375",
+            "-48563440109049085735322381227458895847987356104982748250000",
+            "394924267109031637155481141464524116039967284491375",
+            "-29781283083164337628532883589758763272000",
+        ],
+    ),
+    (
+        -883,
+        &[
+            "167990285381627318187575520800123387904000000000",
+            "-151960111125245282033875619529124478976000000",
+            "34903934341011819039224295011933392896000",
+        ],
+    ),
+    (
+        -891,
+        &[
+            "5168163648975512659218280004228058594242246960004693595942643222708224",
+            "1035035326134443716536282940826886761586345876366755233455717482496",
+            "-31854233116932749008032176737600378973050147231593732960681984",
+            "1038741776815098026658188487974579443177380368468924170240",
+            "4594037505619224384492487458826312531064723079168",
+            "53225524125202190209686089298526984077312",
+        ],
+    ),
+    (
+        -892,
+        &[
+            "-372149812047162494048395602957711481733426254780107021331787109375",
+            "12888074513058337599431425295320655246615604080287582397460937500",
+            "-376317958803610515808933126877165900572458146702090515136718750",
+            "-3581942103445146895987714777999691423326643300898193359375",
+            "-17447062783361851082567188998609741584508958751953125",
+            "36682546052170023559690090806474794430137859375",
+            "-56100625263034189270874516313520288130250",
+        ],
+    ),
+    (
+        -900,
+        &[
+            "-4505595488855055680380278948251098181568447060309250050058263945455435012452122624",
+            "2666636459316679858538034264275823471283788269019071039602253291794099879255474176",
+            "-2022524573647342918280124468576887398696291585495295912730988135111220747304960",
+            "1098430914413638930820009903023957287595511010439478427107839237793947582464",
+            "-189769443940144827839527371611346257082331678097547366839587235168256",
+            "101603203192205824246255328904577634479259834604958774981992382464",
+            "-24942266940674267225801669590950077121570482271389359110635520",
+            "-85367117095565698138593773880519557543424",
+        ],
+    ),
+    (
+        -904,
+        &[
+            "3488838936048965271195721811656374469089896850076042183740021422914461046377929895233514897408",
+            "616215650990935344228967444647916612741560908947286959624098645643452461938810109552492544",
+            "120904883437149580765327208646326712645659926129632069283432742507181066145158590889984",
+            "-3267779893984300414470855349561423767762687447234272942677311007916870284603293696",
+            "875108533816120485059399024511676251536750002061840070754648001857591468621824",
+            "8843635213700260530933073494189639108219892487853462140789325306003456",
+            "34136759844202695849676952671984446452103045659278902245179392",
+            "-105232090472405843529324874950793760389632",
+        ],
+    ),
+    (
+        -907,
+        &[
+            "149161274746524841328545894969274007552000000000",
+            "39181594208014819617565811575376314368000000",
+            "123072080721198402394477590506838687744000",
+        ],
+    ),
+    (
+        -912,
+        &[
+            "292248170254121235619993701489303707142172322588775105563785569000000000000000000000000",
+            "30603730518456999196763742094362383163862117974240753958520472000000000000000000000",
+            "-19829344530444693227387820514981953905649304538413135966877892000000000000000000",
+            "-3458915670414347785525465745579235341107088024272994657303064000000000000000",
+            "-734701011310861097918254876906183503984119102166504300570000000000000",
+            "-173180340954468826898242297684133257472551306129611058008000000000",
+            "8666486798098119106174563714674233229118522982908000000",
+            "-159684335583614994121690745429033730984000",
+        ],
+    ),
+    (
+        -915,
+        &[
+            "4778598459304636281822769179464711574701772719392590046424730998308601856000000000000",
+            "43855439221605005920636851848560021956384388384056044083990431408114892800000000000",
+            "-10489960107953362378189920112570620718805966517738855799965556081164288000000000",
+            "1191860687224407349325828405512653573945197811392414212685022049075200000000",
+            "-683634932911280143877531576013158673185381886359897993983819776000000",
+            "1911117653858213529055899613140385436093019832644473035161600000",
+            "10665182589849263321659594224288888316698204850618368000",
+            "186627311568162254652457351930438621593600",
+        ],
+    ),
+    (
+        -928,
+        &[
+            "221147287295840631009155378215919896241234993293617000000000000",
+            "13390564998216290984073146179239751905642859284000000000",
+            "-8992980876865725569995845414882451608653638542000000",
+            "-365698321891389219219142531076614125108000",
+        ],
+    ),
+    (
+        -931,
+        &[
+            "-7386309756969448257470450133409239259554086107480959871912419262464",
+            "575189149356541771195221007548176708986040419379171425940605501440",
+            "168561941548969525986478743144511786925541326306064233857548288",
+            "19158475518778580965654795745833295381755715032783930785792",
+            "55890403399933273258794756214733278270255068610560",
+            "426825670734143049390226201308485409177600",
+        ],
+    ),
+    (
+        -939,
+        &[
+            "2024902434669436324739145341733692121629358550134383994031382133501370645111659036672",
+            "-7059079382504173607597607915672743790646423785743729469904815061401427183669346304",
+            "10889963956570922788708680325979283701383508794622225517705492599842926451752960",
+            "-5504223925677243705210791927479997260528390484447679461056971906317021085696",
+            "2940858355414790924106273619734960462655934355629313592494752959089868800",
+            "-20687636271171058530758216980402785183556485984763720433299095552",
+            "55586849969378700008713392929540253523509353365748318208",
+            "643769580989338183909418822153022152835072",
+        ],
+    ),
+    (
+        -940,
+        &[
+            "179388984738118075927335478384580041754700365040094034407582402510848000000000",
+            "150374151707312696807411533594380715749234259093054453174234214400000000000",
+            "129621471765539841075613092102924094889300530308678275627965504512000000",
+            "-557802358790342917462106846351452762488715684290139386624000000",
+            "157465813216968792011512406135114199587296070496000",
+            "-677621063891416076248230276783145353540000",
+        ],
+    ),
+    (
+        -947,
+        &[
+            "20199776314224058950365890537565315358777099022469188831674368000000000000000000",
+            "138392022610291656780042207674445576504764510661377409324941312000000000000",
+            "9493349427387940653432106335736508248255562379877296382148608000000000",
+            "-95925831671373391613819628116008945992178543362048000000",
+            "969285419584981893143448179709100896256000",
+        ],
+    ),
+    (
+        -952,
+        &[
+            "493920695006430907709173729054732122181918904423699735755358208000000000000000000000000",
+            "-592557041714469241897016906704735355784894422824869937715085312000000000000000000000",
+            "363969630606174800770050152072850567154113014133031225808388096000000000000000000",
+            "-122373980350411313421741467080588001304951213611614615927259136000000000000000",
+            "15992247010538127637727164332469124388512242356012692730568704000000000000",
+            "-1460506257540279616113180180469936930740753040759099691200512000000000",
+            "1398684197243031487731716900176949010950344385170323392256000000",
+            "-1250680692430675587428926155257574505536000",
+        ],
+    ),
+    (
+        -955,
+        &[
+            "438953058221654415262613188100773336407392447044238966784000000",
+            "520207875218635547684744626511303352924946915393536000000",
+            "396469707692607651662987973604670339150203846656000",
+            "1456880094856940116294718071366713311232000",
+        ],
+    ),
+    (
+        -960,
+        &[
+            "-19192288008617553802976506336613107097580959050398227942173633645508625484081787109375",
+            "11671486871422839327369948216751300053154599586498205189044712936237217037500000000",
+            "11343056878593318585549788502195152637913990578504007725465584553919672357781250000",
+            "-2181186855129642826113909435666872312018703669072139897795214050066107037500000",
+            "-2443532747525396974127268466209799333721900436992701209087069802416150140625",
+            "8510061434050416980144286390949909981067452922415342940850251200000",
+            "231603028815859449990230191757377253206753790524668348000",
+            "-1877825235500241645559415778799662274516800",
+        ],
+    ),
+    (
+        -963,
+        &[
+            "22576353114860596323808371902774966361260032000000000000000000",
+            "-11551747659112351822438130857235662422147072000000000000000",
+            "3886621812484169095239511756859674325942272000000000000",
+            "253765758001451434795075083364515023159296000000000",
+            "112488709831669838242898071905169624268800000000",
+            "2186030586436385809493739168780136288256000",
+        ],
+    ),
+    (
+        -979,
+        &[
+            "41456720643855288369027026098241251192498301669071928226258093264056046894119387136",
+            "-22643568178464477657797831635107226279672744582352200786450999877362565662113792",
+            "2204787059539590582951297575074592196712742441023010756587849923537773002752",
+            "916257787625809004918836254250770296891108887112981767512846720779157504",
+            "-1322703982629372572876164117246022193808478202872056584884346421248",
+            "589520179717321952159610085436888408453713173860944032301056",
+            "-2745802068956130180697143221825446875143596438192128",
+            "4897095498150258061393918811814252543639552",
+        ],
+    ),
+    (
+        -987,
+        &[
+            "-86561080972804089195032732237332898908212644882153472000000000000000000000000000",
+            "1015734896368101959906550405520147975434348570978929344512000000000000000000000",
+            "-738865832866383397613300288928804236455666502102906044416000000000000000000",
+            "175519677296813899294782422675994144944294962020854267904000000000000000",
+            "-17525372243225465818923603787827275077385860486489178112000000000000",
+            "1871196748851681685262168201856508499939941431304519680000000000",
+            "1419085079338686192332016369584506969346142319411200000000",
+            "7311497286020753098637376411748003504128000",
+        ],
+    ),
+    (
+        -988,
+        &[
+            "156923629064905439774176378010411051410367908309970855712890625",
+            "-851246405305114045923979169049333147116118615064727783203125",
+            "1439425711515104898407394446456770226677541331765380859375",
+            "-415425015530688765021462979459998135459997197656250000",
+            "2532835862794270727054106141876129096796815609375",
+            "-7686260772919956192092959105066444924388250",
+        ],
+    ),
+    (
+        -995,
+        &[
+            "1281350115758093409346818447390495398325805514140454923517632350610260980827376582656000000000000",
+            "-1878797468359099326102323843656108409930138188000048292024138306492722136519540736000000000000",
+            "731848779330132181036381207237516688608618691617197820085107648110602079717818368000000000",
+            "19268761151540238893754846215526934598980038158578487990690668574208551813120000000000",
+            "217029363832203538036010478387856843711248927324436786385626509019021574144000000",
+            "535734097384699985051067999540981151603192088077511541759653445632000000",
+            "-2416346836659256769074846469235340589704096371911426048000",
+            "10898583330497937885196343531654435700736000",
+        ],
+    ),
+    (
+        -1003,
+        &[
+            "15040125689821293744115482557611348328448000000000000",
+            "1305202673705533598197444367081354312024064000000000",
+            "-204493994631228266186213761658603748458496000000",
+            "16219528503217062422459730048347378577408000",
+        ],
+    ),
+    (
+        -1008,
+        &[
+            "51630181018035922586446060435475064667857336405278384353561452978193759918212890625",
+            "-9623177844578931672393199214869395564387947556418406942362028594970703125000000",
+            "601851510726627093460306887771501768947575370678259345891178848266601562500",
+            "-653943958761605787507115874354135153334917391090577609294921875000000",
+            "39887275511342017666301457578678712498411763179335590495605468750",
+            "-2190679066767645331428619900812737918677822647457978968750000",
+            "1402871699551435545392128688275394509553443591278328125",
+            "-20778123513000618808244103112222211363592000",
+        ],
+    ),
+    (
+        -1012,
+        &[
+            "-204344290478354698106731378125784194576718103432833630334976000000000000",
+            "1243508019466325039942928040075544459231301705571821435488256000000000",
+            "-127409933077387882483393397275452601210672521773684542789248000000",
+            "-25320300665394312513202440887044222231008000",
+        ],
+    ),
+    (
+        -1024,
+        &[
+            "-3982640049544389891044927668019982104052246204823059097675306545730321061519624765448",
+            "13336558465281706877370085366534556613833315264811736765458900322363447675074490368",
+            "-14971105952122567548813563373837605419708405020121806479102310312904401074439936",
+            "6034146668472921778821203413589218802242908892098322095763305771622015824384",
+            "-1094115820016426093428804464603523547840415706026200263551945939284710608",
+            "-3294613950440631544133269929608432497220718621183275684372777472",
+            "-3798748493913392784680009316499227133024190865135315360",
+            "-45713378860880921566301948813006298369890112",
+        ],
+    ),
+    (
+        -1027,
+        &[
+            "271046093357449955035386983426329999080765259776000000000000",
+            "164592522336657395778809121398601659935044403200000000000",
+            "-41547404176734721779832688271619104304005120000000",
+            "52960452958968182770743647384658280611840000",
+        ],
+    ),
+    (
+        -1032,
+        &[
+            "9767598321928425111492583219612146446391539403275402158023865702012095558221561856000000000000000000000000",
+            "18232249716341006692552607226253688943255289070298480516647575393886733357060980736000000000000000000000",
+            "15251220060253909185348291123129400341101410322625537860158732516914014007409508352000000000000000000",
+            "-11176672597745582211963490041124223121837129395979888490423024920014195575750656000000000000000",
+            "5092740266769801837496770094778606683677580952600811711848099081110874775552000000000000",
+            "-226711763353757855159978657258440677997891524237747159643319442395197440000000000",
+            "556400952776314676006618975700322655569830054920023307634432000000",
+            "-67648560918371492736413791355417111850048000",
+        ],
+    ),
+    (
+        -1035,
+        &[
+            "-18925434826456875294094809743729821384454769051855388573756307112198144000000000000",
+            "70073462042410725976011942631003122155333653661365629583764428213452800000000000",
+            "-67301150162849409932971676949508642559256451855720028558157938688000000000",
+            "-389922034701525156463196038501959758869329447979309243092172800000000",
+            "164127569761225315760429279072618119126047317908990118068224000000",
+            "-158040203547836122267813410467631846478930737933739622400000",
+            "46801996484839555255728125568965255870146588704768000",
+            "78328372361885062472335721723782252181913600",
+        ],
+    ),
+    (
+        -1043,
+        &[
+            "1956339308201356286000390942634922931524967822148973090751149331087097856000000000000000000000000",
+            "-342334436764110490696099633505540022668190509324059731011614391146643456000000000000000000000",
+            "348240844151391894509460622135318068013627093363433794947792822675177472000000000000000000",
+            "-7953691320302114896369464108931325271027984915341853835762173323771904000000000000000",
+            "49986940404417396022440635431899967679776934384808700765813197504512000000000000",
+            "27461114260670202910168605900900118416337762630699877212922839040000000000",
+            "-56360459319374201851863914055780751336731756439011328000000",
+            "115672705644459069177770774199204617330688000",
+        ],
+    ),
+    (
+        -1048,
+        &[
+            "29454477481561065337739645086875288893634262511106481929070989017088000000000000000000",
+            "-25621049914343420689392039025367673119179757513095203019197049405440000000000000000",
+            "7452831769125999597702118084128588919955059910743673793703133638656000000000000",
+            "1617853253606236970710953212092360268707670074178388784079001600000000000",
+            "1790943047893740981132390014576016307007148883438139426931648000000",
+            "-147475943613655721880897041029867660392240000",
+        ],
+    ),
+    (
+        -1051,
+        &[
+            "8743043565409016736756337526364572620943665551429688944132020955512832",
+            "609478936308852703138855519171409018549012233989963733448483930112",
+            "84072685629795186930092590274005854385288302697479331815358464",
+            "74010210743744060468842095314484372058081554805555200",
+            "170566836806239391545422096319774885221433344",
+        ],
+    ),
+    (
+        -1059,
+        &[
+            "54749382100303945965938377687131815951613999630363307989951555859309282920971108352",
+            "-68467787846615964253889968420566642459854805801826964850746468270218385188454400",
+            "91171383367443129855808350957355408805007188493743297041835744129102880178176",
+            "-194474091343506783682373629396296341554933178146702896739203931963392",
+            "158448918014406746129523347274938013298923809932524908445696",
+            "251140984312286759034108048655108324537663488",
+        ],
+    ),
+    (
+        -1060,
+        &[
+            "2268948670246012491581730803314685275266118438160652960497740363456259526434970094534656000000000000",
+            "-1837066464070996120469895861690624093336322482955377737284336128664641040933608593817600000000000",
+            "363986842303494580753265392002190637000016179641766125133824119048907965365665923072000000000",
+            "15914447848098297080317415374388284095627013115523094658599294529049637930244505600000000",
+            "-9013115019628792018231351089598845936684783581209361402393906675416488738816000000",
+            "3288837407053237198260555329634177441823555835772563872061612890148044800000",
+            "-4278718050564023344365201219666287230777920229470585743855265792000",
+            "-263557742837171682900764257711891814100211200",
+        ],
+    ),
+    (
+        -1068,
+        &[
+            "150987365419070887024382860867468133647475428945079644684937925201384000000000000000000000",
+            "-8263173087284923280313878351624537272370635985579915045534610518958144000000000000000",
+            "5559155688949726256614920324024047749692139559027298305540259943676400000000000000",
+            "-7626544315048813292199899291792967998530908307889776589280000000000",
+            "282433051177825517590691236172309271882488091122327772000000",
+            "-387424104936129233696550917778260484096324000",
+        ],
+    ),
+    (
+        -1072,
+        &[
+            "10771403633173887558715389348756131034781957045837640625000000000000",
+            "-38237960194013118522667341406580126256375888958452375000000000000",
+            "175758801049062616453336377002027949185418548361108187500000000",
+            "-205618168320113722528866065219311275381480814571715000000000",
+            "69104902721692050993732757792664181052336943660214750000",
+            "-469469171868912646848380230233174981468198000",
+        ],
+    ),
+    (
+        -1075,
+        &[
+            "-193287692463614614883338608255047893277791478477760472547328000",
+            "212204814360368170818793477323114082369152890857448275968000",
+            "214995298235242029368331836577539579500355536528028467200",
+            "68871046376981529174352392764446823706264504945868800",
+            "-995861976212308963874669710696037059914167746560",
+            "542088659144358007339967407586625308670689280",
+        ],
+    ),
+    (
+        -1083,
+        &[
+            "59183470775890623102362273565816658249475908317552333929054208000000000000000000",
+            "-29032393492809658592660308950281662196513701107834190854881280000000000000000",
+            "4966800676267052203103265355580743304785480055171066621853696000000000000",
+            "-855891373824131277999924218491939447670368650852072161280000000000",
+            "736126059004298167617725833874006982356590699109941248000000",
+            "794720003370771809323025500213738023874560000",
+        ],
+    ),
+    (
+        -1092,
+        &[
+            "-11399327725494218798737808488898891371555857163161203882074016958512319065701220352000000000000000000000000",
+            "7812160693352835962330966127506219038319484257784013790202419396792420884688142336000000000000000000000",
+            "14147331105803618441787960284346172301266050546136888382416860722817545313985757184000000000000000000",
+            "-4114380912031888144184139782379675690399375140970078847729371868187444847401238528000000000000000",
+            "1362719999058592388335992752851672703626579848779924470931866989317688863514624000000000000",
+            "45539601715292538862264980052027187457221547030758023509730728512783118336000000000",
+            "-42617815331984530664240019051172769446368789029954440306166016000000",
+            "-1220096358687753260204078826101522741473728000",
+        ],
+    ),
+    (
+        -1099,
+        &[
+            "1136813185931196805090835221189220662326985354242554724384883589416133767200768",
+            "-159957807627155985973845772410270888386713456569958064091773706333780443136",
+            "6031103667311076246161128355928344422120000526076426899664680163213312",
+            "2094712066990559001059860582304843256137699004807572833425162240",
+            "-3055628884137026639358575311444074263110618514158780416",
+            "1700869372837689862623335628779192341802188800",
+        ],
+    ),
+    (
+        -1107,
+        &[
+            "-4484734717995054917518881620708787284436704034816000000000000000000",
+            "4457558696468932915807721087710514950028390301696000000000000000",
+            "576603519172298968612826050475615700161195409408000000000000",
+            "25341222610540232773574921596117973778756009984000000000",
+            "-228333054151667629685434175906212150310338560000000",
+            "2483117566520491605893690385973749426167808000",
+        ],
+    ),
+    (
+        -1108,
+        &[
+            "-247139378620682941452553875146675251081843248745748494049974419456000000000000000000",
+            "49997233345143174809548676197872104879737690613211460200689762304000000000000000",
+            "-24353136016851142417213264085768694191955374076583496802241941504000000000000",
+            "-12534133693582485709775846498820786514500479100988745457653760000000000",
+            "-132814165888453325873288657098806887185672975644361253299515456000000",
+            "-2603132450413884702446245024069350283540176000",
+        ],
+    ),
+    (
+        -1120,
+        &[
+            "1841811080695794448208890540085619814110855601385055183808474748461766086656000000000000",
+            "-1512572379433641351361193790261973614134139017384624373607320653398678597632000000000000",
+            "1401213751884409486021113387186716711187916394026191348088730647281756899328000000000",
+            "-407249830775402984223103491263253211364855684735501807196688176133632000000000",
+            "-5388713465854884610162832294562653895856692351318247161602999423616000000",
+            "1618726820370580980549423882831792477177113144109089604290496000000",
+            "-1184903489696338774370805660650272047993708667959568608000",
+            "-4578953743453653436402475577268852635346536000",
+        ],
+    ),
+    (
+        -1123,
+        &[
+            "1809906887755628098044099196443063823751970816000000000000000000",
+            "-11882827525751670729580856303717922468701235314688000000000000",
+            "60970880543121897498770914917110161705048360353792000000000",
+            "22349204940161721553488598505833419202533261312000000",
+            "5270819845653305357116482008859297909325824000",
+        ],
+    ),
+    (
+        -1128,
+        &[
+            "144054188197061114647959975577806552486751522951898092607441434623008249072169517056000000000000000000000000",
+            "-62033029791626533178335359418286117742539498742181672586245188539457830384289447936000000000000000000000",
+            "13830220238125982572766496986631239487524316130123904844173241359632236251647574016000000000000000000",
+            "-844585485799675867123692988812078131758361010761863389272329693759855439738830848000000000000000",
+            "44391355980699835426894567988595876035361867479015727519849895145154143068643328000000000000",
+            "-1022921108500635244812826227071035133165543028174118908244961829374723518464000000000",
+            "543657507354199525771540120622677433276646245037370994327848704000000",
+            "-6661165944562850357791223335310365561899072000",
+        ],
+    ),
+    (
+        -1131,
+        &[
+            "-415214533284961581532329138210174343300914019928949495967504903585987272066858890314137993216",
+            "595954486881379931582049395595621614220000272430547549206779865785358170889413484915720192",
+            "-171310804730507442642317276987078559993324481782628245187237734298768732422145358954496",
+            "-74529144850112877516801574743066293838966268038672608570530648925366734908379103232",
+            "34122285055044393017709955545218612952137199292010774758639192875464268435161088",
+            "14033329913206901439195429957687196729229107479433961679975445382037504",
+            "15109868648124674790339937063036605896254712347999635083624448",
+            "7663821188957387585165436096721585940894711808",
+        ],
+    ),
+    (
+        -1147,
+        &[
+            "-4910240074893223291872087070464491467225807856861184000000000000000000",
+            "3425558159620577161079728245075966370657307832877056000000000000000",
+            "-554591662444162124799867520121911920095650803351552000000000000",
+            "257441396385104084140166784710633455033579349213184000000000",
+            "-116130636998144683505156435708506246624239943680000000",
+            "16138529448137876452423420675052001395564544000",
+        ],
+    ),
+    (
+        -1152,
+        &[
+            "-363293859229412182995580710491260400070842035882250149861101318359375000000000000",
+            "872568631773575990495664560189243442791843184519335631859484375000000000000000",
+            "-855224804304963833050087576098891566334021493163477984752117187500000000000",
+            "279338388087794540164137476894239704925126397537218846665875000000000000",
+            "-1871247892605040272958762728364555842849201645776371395468750000000",
+            "1079121836133399240495909216243647212571580648376855923000000000",
+            "-7683978679732727859499001523415153460592523491898144500000",
+            "-20345532020874860576659347429399276398778376000",
+        ],
+    ),
+    (
+        -1155,
+        &[
+            "83347851037168500148480644982979700827573455605987270779845921763214445510656000000000000",
+            "-8900615215725314134559469276744109057080931256308383138559489050825505898496000000000000",
+            "-9518746549833191407809353203709672284413276783319410082239757655160643387392000000000",
+            "7711851888998550611415837895667386487366740340143356218759041649486069760000000000",
+            "529969159795786169479551056606836273591438303269788897135324287419285504000000",
+            "125802782591288988289501749810981813757267278571203492224824246272000000",
+            "66829784571649628051957299392021041534581719969792169869312000",
+            "23373692778731029019776599932268751032999936000",
+        ],
+    ),
+    (
+        -1156,
+        &[
+            "-246060606554871520158322961324411023361190977146221402565846805912598088829599642897659802111759261630464",
+            "-146482726662380306063824562626851315228095519791673366807973277741677422211121973045385149511263846400",
+            "-21203653652429208872992335600541688882737717866161678774321441564826357142317226780035296759119872",
+            "910329369185002925537183681400451163577921075432930279439146114466784372979432853012400308224",
+            "-13763279481574678526222714145494399440867380776482463203327872965691901901025092164386816",
+            "4487357612215464781163853624027323374788163589708318444503809151933482635624448",
+            "-3829958304157312994360458317224367411680053825974921314672884981186560",
+            "-24479132925843908660058308463908687996314105344",
+        ],
+    ),
+    (
+        -1163,
+        &[
+            "2253676441560869887019558922093618471222049601321413632211588120495586803712000000000000000000000",
+            "-43649674649570359595907588018012248122762614401644386915867907324995698688000000000000000000",
+            "7708264450336815403003578588144131383308534443084832229577828269614432256000000000000000",
+            "15393135843276004846584804672322854689862352887630404309934068377583616000000000000",
+            "353501232287940202644216099359748490254664707257983585827727502999552000000000",
+            "-109323303260030130590860679220598062851653756095580602368000000",
+            "33809173898726869642841622966118744612888576000",
+        ],
+    ),
+    (
+        -1168,
+        &[
+            "52416234573413341591686328237623823218428791223557365207909662390625000000000000000000000000",
+            "3936899440860120338910762919178716919494705473511915543950330255000000000000000000000000",
+            "186082161955271232176407972808077405078532938477964391525121023804000000000000000000",
+            "80748697554246667501185385083956525458348778473338855917372904000000000000000",
+            "418016339656850609443522848464747115926521731130157742190566000000000000",
+            "160013872267779435429692923388371528748326439731874399400000000000",
+            "19327490591781814143724346590177342618316176001325564000000",
+            "-42554619239647636417730489192134050420327336000",
+        ],
+    ),
+    (
+        -1171,
+        &[
+            "601370120287051308534473506959775149743922980556851291866882922737568330022912",
+            "-2596454204866777440509932055058092764332749820065126897669189204439176577024",
+            "4698114377323981482920205972316070880254267680044693057537398949902548992",
+            "-1131970981574191479074818713186782208548591187139442356404219864416256",
+            "231370094760139736464478222351255733154795266053352013042374672384",
+            "65699179390043860649118809142526564774812370271471665152",
+            "48841767407029519692010033379934438734249951232",
+        ],
+    ),
+    (
+        -1180,
+        &[
+            "2910486363862897873650504532245405674913318175178942955838953257084173730712890625",
+            "-5043557028037761839005950859376219328777071167036271146384262155313616943359375",
+            "3247457685120716311051253334801527177789545226262448057295741205295500000000",
+            "-769950585903133278608382841345357652244604563373006767080063716974609375",
+            "92502130415591702326049784581916154843351816320047256919808045015625",
+            "-179769395873189693121857707732108019706120698978561328077390625",
+            "174433440919903386966832842712285802275008329631102171375",
+            "-73767807010445070282369645126757123446577868250",
+        ],
+    ),
+    (
+        -1192,
+        &[
+            "300736284634655215079871589457449013099582150098887089147485618176000000000000000000",
+            "-1080326219153552745696726677082503956413664431573168030185297215488000000000000000",
+            "1470074081213259807708205673552638474266559428801240677240653541376000000000000",
+            "-450741055908020775777391148239027791615275928530649110728394483712000000000",
+            "45536837777977555898687922539665926283646055703793757394000675776000000",
+            "-127519101991376657036540211752734421820581424000",
+        ],
+    ),
+    (
+        -1195,
+        &[
+            "105038454094592276486064109104104502132412363045485369548963913051622342656000000000000",
+            "-31765316871382021817225343522178858887213448859273156834962967315349504000000000000",
+            "849870594419732537859817743615962295376282415002685748742378003365888000000000",
+            "252613078475837371445944700597944116868061159721148343537425711104000000000",
+            "11822656134735808985029491078729358731362770157672876149963751424000000",
+            "-951626588814501908233831529991745549009183111518917492736000000",
+            "395722801069695660929359296678732437001084110458847232000",
+            "146155494193453197473043349525918973370900480000",
+        ],
+    ),
+    (
+        -1203,
+        &[
+            "1082015346905596190388342899749769814037859897015446430092886016000000000000000000",
+            "6914629728705193455053225872669379597837205042406801899446599680000000000000000",
+            "41432297436623760923570057622637550238668775532993425711720038400000000000000",
+            "-12963107975090134462022812660336927551359334411701214605475840000000000",
+            "1249014691659202243911946754435591308010786179695192834048000000",
+            "210099810501223851312682035089317344313896960000",
+        ],
+    ),
+    (
+        -1204,
+        &[
+            "26236684573345821045481208535113157120024262973142691090411429037014188511150629860367870883268288819899662336",
+            "15347564800403555635653732680202308424880666748809486966892709044831455317159846293585364240691155047546880",
+            "171825091982316566505113639629304861072028027393599726861648358193954430294014651125888418715636072448",
+            "5136332989458424177697219385463626151155495706713724615842869793200957290832600490322290448269312",
+            "-888365080573973341129181143524174490938301785748178536518629966534296150991642674109349888",
+            "-489794881096237554992254768194398019944518404207392597455445123962946218365026304",
+            "-103070675342189898833514071329744658150575745283928094825868657728176128",
+            "-219831579883958463189398373780658485003226375680",
+        ],
+    ),
+    (
+        -1219,
+        &[
+            "-5842630610265546171446025137462133609903618721117876319915867425085998497792",
+            "86025026984825992238928438037907605220882432118830009032833545345553137664",
+            "18335328381057829127653144709396490133306754981018761537416243921813504",
+            "4903594828850368686510646252265602134391201975822526498112333152256",
+            "-2356601808633740746787681334664247878133470139102798020608",
+            "432595789637492816207343163042339197660135358464",
+        ],
+    ),
+    (
+        -1227,
+        &[
+            "2513550635275580846572126510888944466103176257943640211456000000000000000",
+            "89252949391959745426288430543461455160950172376987709997056000000000",
+            "5282646588767618158994396140387285593346806931114470408192000000",
+            "619638890847298092963653036606353098743021568000",
+        ],
+    ),
+    (
+        -1240,
+        &[
+            "5476188451854495958632605474669826435742271136496375291011556214973391720117242068729856000000000000",
+            "53121242106192681103926681861126043350157259675817864391618498173117338239656119815372800000000000",
+            "164508797470128586227491577980044025935000647406245088950623243558850258210278816612352000000000",
+            "-889631393652005295418813742845687562587213744314398942581037469971078089259404492800000000",
+            "279839623777872303202164277375058342637611021164432456666205161220908361713188864000000",
+            "-4741437185455099303266383931213196531577753607267932896670680862557656573542400000",
+            "1166739167531951917994308221820716481035064273909202745789667077347328000",
+            "-1108279206119763859472986634297184549094197926400",
+        ],
+    ),
+    (
+        -1243,
+        &[
+            "30540293156908205255120060127032312602199851008000000000000",
+            "-54665859623503521460552388837431864605259333632000000000",
+            "26521598516319200744664388741019144869638045696000000",
+            "1266871605300222128375795939246750405038301184000",
+        ],
+    ),
+    (
+        -1248,
+        &[
+            "-163398783062336317386494838938375946485788083432799465133691337136581997727000000000000000000000000",
+            "1330395929455902225128309464764780425724346564934530655819105434026952887336000000000000000000000",
+            "-84840515187167303431782292109488077515113137301784290386684154540189974540000000000000000000",
+            "-23203809868612886381547108782441294639915059116494539526905566286507164696000000000000000",
+            "44840410918622754109139586145273244368940839451907024971177037165702000000000000",
+            "20686611719669435084324655715954513720256763286307081269891681655640000000000",
+            "18441739042462292723741445647507844804841705171738372158516000000",
+            "-1582642249277538051715079550052388517066586728000",
+        ],
+    ),
+    (
+        -1252,
+        &[
+            "-31399011381667455964134210833800202428486317927487509408582552562434048000000000000000000000000000000",
+            "411460951447546830555737562448624644472901672839808345597591568242442240000000000000000000000000",
+            "-2120265191620962638289499664916021343027383311613851873378531571264314671104000000000000000000",
+            "-573084747548303421026138430185214022906808574996871791021599667325321347072000000000000000",
+            "-161594809919006961106948722803180714034212994034338739633571745057359241216000000000000",
+            "-36923256171633071212393612186609263875904512294802193823596449091424256000000000",
+            "-2599233779821048617865353547403668245460580968118754203774692198656000000",
+            "-1890442888861114555169420342668193351308827072000",
+        ],
+    ),
+    (
+        -1267,
+        &[
+            "41641164811798159687111097207495461601511274727866368000000000000000000",
+            "-61457144594568911784638644004143969298883726431748096000000000000000",
+            "25393048043513374925275960224049028869761536870055936000000000000",
+            "-1212907169067261713451014962669956980586524568453120000000000",
+            "31680022302665703779281561303635807117209940000768000000",
+            "3671970425506731602488773303126170976367632384000",
+        ],
+    ),
+    (
+        -1275,
+        &[
+            "-105839089040414298665232270331148095347926526154015182687245910968236778431717388058624",
+            "68233100232681116280999392561446983596982760274916660686260407399708174578639437824",
+            "2361294613197182524772582634118604917806314584256008925752317636251127056689004544",
+            "-541013944105467566726085429347358460745472841003327239245008690333625270403072",
+            "98234560857445428061995555365799214690425246943842202930151218542588985344",
+            "-5300610984299747433248965507536027344501491707130376276831753012772864",
+            "90637350900600585874530888740880586275372745641082210644714848256",
+            "5223726167966529740249746240357995313027168468992",
+        ],
+    ),
+    (
+        -1288,
+        &[
+            "102754479510979755667992775269213271419122203036304057774524162828664832000000000000000000000000000",
+            "-135592047329244181563859854029469104322336037648777718984560963161862176768000000000000000000000",
+            "55251219298960274790206059994259244751582364127637923564529862516843479040000000000000000000",
+            "-7804977107640328871382321911850312257914473353998094123905830083305930752000000000000000",
+            "1311193655366629122758166415025110581022336555429567526397469192148508672000000000000",
+            "-277918356548071200578865270134459984153730463113514232105998649504329728000000000",
+            "28091507215014468589117919400997097994512713378240764256154628916992000000",
+            "-9240951570865760045680997346137839650862727232000",
+        ],
+    ),
+    (
+        -1299,
+        &[
+            "2348929649130841968898344047976806095930370899632014110641077062769615525451027801841270768866951168",
+            "-866529817220891913271235180615993038396282685119617042763192295821169461726309757710417765859328",
+            "905195743060192681447637697984709327358200563330246100164363600882035360517935365698179039232",
+            "-233355141236045644875187749134096951524591007453609166725450795134527974986249451492868096",
+            "17201538196064710316480462594106609217615884456684129449592016657542245147647011717120",
+            "-4070811489819626917518187041897937109649243608165798387889410378544961290240",
+            "367976141652620610614090478683784827267978580695275869519917088768",
+            "14940491760061197080547019716241030734498519220224",
+        ],
+    ),
+    (
+        -1312,
+        &[
+            "565053359647663441767709985344678730932492856613391432090212615658724193000000000000000000000000",
+            "193051483119797214592017402974874187583972789378994726978711523145639592000000000000000000000",
+            "17749498760918200354744768353660161967277248079516810216714388638540980000000000000000000",
+            "-2002298865065570385910221918804944108457013791421363850699608886808000000000000000",
+            "-7705009513945835836476301706511898991623827040770669172015110138000000000000",
+            "851840544507229979694904457201323611564081319515019982775000000000000",
+            "-59533860107494341335386814562951673882800442294214202636000000",
+            "-26291382867728340915753513032585095730904954984000",
+        ],
+    ),
+    (
+        -1315,
+        &[
+            "31251571605786539236115581037331124154751650014234887030525092761643778048000000000",
+            "788909147853118458299330252176783793816920523835835908921279818131046400000000",
+            "32064230027367107335157347451358070703360770776900834007428662034432000000",
+            "-4948610872256928975405274872974679679022101245074504200853913600000",
+            "234624683948394811777250424962648197903308562001408557056000",
+            "29942115328974511819983335920363596143051602329600",
+        ],
+    ),
+    (
+        -1320,
+        &[
+            "97393688378575070484102609772364748569437702720422138019614918281329114615938477076822153891382147228847046656000000000000",
+            "-38039887135901201776740554544943720383598953324261921806673528172358159163524934578063481550048308706148352000000000000",
+            "1440564325039592709845067874877463044088141586574151125929003856830996185412289341320527503606330769276928000000000",
+            "-11346725453301019241313454803369930713744430991711423923070736653179704868794839532252190334016028672000000000",
+            "63448588042551212367314599642690745398746089936036428003699736173360732007940703292533161590784000000",
+            "-7564848369936329757748832158280770921137664340570303183861640564358996428548474241024000000",
+            "226663670534475149680772726035193159902547895822786263994560472042657792000",
+            "-37175310962524209402711325090684825397858189376000",
+        ],
+    ),
+    (
+        -1323,
+        &[
+            "-72439556854674001987775810026867243692368764040380416000000000000000000",
+            "160646066542491131624777841902946733373266611306233856000000000000000",
+            "24790720034570729021150166040824934325726349537312768000000000000",
+            "4449399299679990355983977223021057142086975948324864000000000",
+            "-13649922805621928254271947049916308437038520074240000000",
+            "42320664238412524773562978234934635103614353408000",
+        ],
+    ),
+    (
+        -1332,
+        &[
+            "3214718899478036626215638237473026480732892244235396950574625818446760247296000000000000000000000000",
+            "21781673570574698765969951443623464757684499312364680184628163570069571371008000000000000000000000",
+            "-43361515910489383908632185542484474438932547967221586082580522583646595448832000000000000000000",
+            "19541191373996956982289865994255845092942220728068874909173295444455814070272000000000000000",
+            "-3516695449799718921066396891461510337563391069500663351288834939658240000000000000",
+            "2193067218355090749936961461286699812488971985906938248215656917528576000000000",
+            "-492715541640917593622070600758269111688087091521845965512334545365248000000",
+            "-62382700950473563258623202773871826575469575104000",
+        ],
+    ),
+    (
+        -1339,
+        &[
+            "7411492215583335406680386069134177507659532824196141067868149520003916182006602391355392",
+            "30968649615572598010925895093718487729759169307879587390159292214365614142488972361728",
+            "63295766811549416137966612025648637420755933874154121676075308926549426826385555456",
+            "8892504506105006144683448068330523213124695791708010852438086533025296977756160",
+            "-36304267265821874004680153060868693016741916286217400353769301909153251328",
+            "7871005940017915035836790215106102653780420644393131990026640483680256",
+            "-1317863830876503015866836087126626066961888763091916475596800",
+            "84282150364912367553633118527949722501471285018624",
+        ],
+    ),
+    (
+        -1347,
+        &[
+            "967532855310961934347875936063699390475423017669252414977217524662272000000000000000000",
+            "-1456374759760488414800362195901333580223450443497197790164374100377600000000000000000",
+            "1183528878500783495853618069837929217014996033765883119985965119045632000000000000",
+            "103639283096178207036911521742511340431747893774436991103674613760000000000",
+            "5837261284776421148760695036466761809536118049097250473246720000000",
+            "118756352448741769642769440612484278574983434240000",
+        ],
+    ),
+    (
+        -1348,
+        &[
+            "-205233637351402671829166709514995268961396870255929205484761826182823936000000000000000000000000",
+            "-7005331189623551300018194757420977733082442861061117431244227484357492736000000000000000000000",
+            "-108187583835179393700112402594423046456149469597911874410489089289148694528000000000000000000",
+            "27980828322472250690823326318083787083822983317437137789778209393608491008000000000000000",
+            "-2491127180202617183517663397050355909303021112011092960318072397429186560000000000000",
+            "62575221355828397175179551683922687994271877639779115313739348036808704000000000",
+            "-1379943528861977205449890617002860891430178388941937988560603446905088000000",
+            "-123948386055710779819634738004266793471976178112000",
+        ],
+    ),
+    (
+        -1360,
+        &[
+            "95041806551127658301053302654778861597397623111548137208777543182461973352986170822656000000000000",
+            "-54281901366624631839068799311114259890885091868822624470887413427308577821313018265600000000000",
+            "2288725478827252785263150006201073511821678313577380275083849183301789818823186432000000000",
+            "-34427694337961988250966082300493614285557666537434740992832461318903637138534400000000",
+            "619957457479433960031585063386266356755875469115123462013996045355996544000000",
+            "-9073766590058893274599148814291655921373741428400338194682264272115200000",
+            "786981609592529453478188188809860030224958400577297642897248000",
+            "-206877758915355499793443963527592275402511967707200",
+        ],
+    ),
+    (
+        -1363,
+        &[
+            "-61478122816559617874801094004621892133458751449853660758016000000000000000000",
+            "89683555152843744472071241545062362107730983259913431547904000000000000000",
+            "-4349577227353230343210559398325533683875804151269484920832000000000000",
+            "58060208975832929889852651167990014185834763388951986176000000000",
+            "-1660322544422636423379846230243523338529861638553600000000",
+            "235059877328812462787270454241538268504455626752000",
+        ],
+    ),
+    (
+        -1372,
+        &[
+            "-3391510319482277987936779592926232767104722544547650249536846147060394287109375",
+            "588916767416349295339021257311331110162682037474794781064302608489990234375",
+            "-2279190620276635272948951754950313195939280410542983095577172088623046875",
+            "-12451131845109000582154117512151540946532385966107313225858154296875",
+            "-1475997229879142686367766889427921688550943665768226412240234375",
+            "1008548229421937887496687237832842018127642943972469859375",
+            "-344514398594758897949974406005050159563786434370250",
+        ],
+    ),
+    (
+        -1380,
+        &[
+            "-1043717434674580402717419972320183214179349425352220925666143898343059593202965992203361417535941416422257721344000000000000",
+            "1515672488750192406755322903951442203568732081884436967876801416160928484351453665061090181618261938610896896000000000000",
+            "-359450962870172070183849496024185547533350025952232695319442086650832640411793554090144649654068466818220032000000000",
+            "-3204053117604554451212581049183434081694096098503753773217096642404791036731056991709579306948808933376000000000",
+            "-11198719491880226077458851730219954432641563194866892969956474394228580008323634692847376865427456000000",
+            "834210097027380610396492239537077265364446379760873874291185809535255620499245325451264000000",
+            "-10629137536424828578817878065952562448875253668798815309065879486385563648000",
+            "-483428242454398634463543323663456033879150969280000",
+        ],
+    ),
+    (
+        -1387,
+        &[
+            "2052723014407052457647477199445183281503328534528000000000000",
+            "36686449491372953371348633076156538920834099576832000000000",
+            "8421392423043512311845823062070841518683467022336000000",
+            "649705640341533249055461232040056199884943609856000",
+        ],
+    ),
+    (
+        -1395,
+        &[
+            "-174073500897032906358070982421124537448148226111746502675021869659193344000000000000",
+            "56621391379830657444241634428023431240530191953112340380794954448896000000000000",
+            "-397595846892428703300073383351732625952511447296145364687311907848192000000000",
+            "581603780409003444896762647875482537048374964245785860532133167104000000000",
+            "-94364854279922841540135401879771952205802694602660134938734493696000000",
+            "6281788721152662945100176418070332096572563302092257229799424000000",
+            "14153615782132185541444359799928496646663977282911822938112000",
+            "910009356386615602117442517710776114094108835840000",
+        ],
+    ),
+    (
+        -1408,
+        &[
+            "-95277345059857675529765850608414256161965322307869420125414253101318359375000000000000",
+            "117994487746126269903893060101430924961443826657369751862318547234375000000000000000",
+            "-60602041802305925031810953629825950824854159804892447167153993992187500000000000",
+            "19339065432190645500663695528623621460594702802152579815035331875000000000000",
+            "3624557779133564584118558736842453020293231527488160900463406031250000000",
+            "365502643998063218369715821534127671067125026481595866256819000000000",
+            "-9883790270532072107662137303668911532573931568868278744192500000",
+            "-1570141596345051056353618284818554882637958473736000",
+        ],
+    ),
+    (
+        -1411,
+        &[
+            "259898672030231371072634921991495299708204070671565541211890306346215538688",
+            "563159340355333157360236566159729674583227014555157757651685811744145408",
+            "19568314960219288785284224576189610670941760495017044362657792",
+            "1780126746705689756102562231651060896708610079948800",
+        ],
+    ),
+    (
+        -1428,
+        &[
+            "-3711489744391505840909265987948679379016518570689838859089537543927618911465409927842627584000000000000000000000000000000",
+            "3456839501734300594235950152209592698750414128946811077602647076913638860503714431187067863040000000000000000000000000",
+            "-187852796407105234735480351477265459845155497818612689401274425061640747631666840894423310532608000000000000000000",
+            "-303386981784522159659729731008790456216922186724680934591752863711575979381177651000980311113728000000000000000",
+            "12303208205702120460701668812255683079036859290282157017127878896492104050452329313939251200000000000000",
+            "33379850139585632155557058810145064423499658538072264572138227867514574381239272501248000000000",
+            "-217468476883849476852145600742611039750726891957499097582953392796739840000000",
+            "-3616297983752590489170592931739244329043525804992000",
+        ],
+    ),
+    (
+        -1432,
+        &[
+            "1017363994814577240421026817418452807179749062333264460033172733502588977152000000000000000000",
+            "-1098704264123732131212193591122149705387769398148232104895611176277060550656000000000000000",
+            "638138760721657920366751035779504520066933841824249493685737521692929486848000000000000",
+            "17166275542357183852220728407748455306244780065346610129475027465524828160000000000",
+            "279020588065753321124597808265400844031464533956585302760260064754467776000000",
+            "-4269963537479460404026062619741702395527185137456000",
+        ],
+    ),
+    (
+        -1435,
+        &[
+            "-4009811510734177961140258455491639109235283276327883374802990268416000000",
+            "2539970793779946148723473087883821948343643667859030533390886502400000",
+            "105161502065491843193116493512870644772977750388041962225664000",
+            "4835907878329132222450395857259654466718969836339200",
+        ],
+    ),
+    (
+        -1443,
+        &[
+            "-40121431686111497107438694301588095660424226808243356275191121444864000000000000000000000000",
+            "36588900024092959214287670379032341515077411319217339485496610390016000000000000000000000",
+            "-58921214019502688220627382578989797334628684856811176849124609753088000000000000000000",
+            "25376376872103630476913309563818126873011493179044338750905202507776000000000000000",
+            "2804882657599803905961113861619489484496874584629733544395223859200000000000000",
+            "-78821894638824193833493666440221145954189657256628410269762060288000000000",
+            "1271924565380917081210267685888550317016839228476875701829500928000000",
+            "6735130520807277676895557698068701680318487707648000",
+        ],
+    ),
+    (
+        -1467,
+        &[
+            "4750778730158046729996627074508314393022035482908819456000000000000000",
+            "19361523208256264971288945998620952474692182081536000000000",
+            "-40389436387354150167355479516145857227679829327872000000",
+            "18095625621654510801615355531263454706630064771072000",
+        ],
+    ),
+    (
+        -1483,
+        &[
+            "6870938060226957278431611659432080124005133614045290427318272000000000000000000000",
+            "-5983127083630888407007205561356930289691293391265374293458944000000000000000000",
+            "5008469925229175131759057638332360377210649970749868324421632000000000000000",
+            "-376421614944123477105798679337666203760274925144628992671744000000000000",
+            "35808686496201036344310676698591209807816679863512090017792000000000",
+            "-2011756811987005488751821886072011140151603715305897984000000",
+            "34815875634155562113301067877087243139098717233152000",
+        ],
+    ),
+    (
+        -1488,
+        &[
+            "61014148794188080077220618844735677678784263994952926681577145038837703711883714913000000000000000000000000",
+            "13952488021727091955296382484660398606216283906337645101670797349042711153063471576000000000000000000000",
+            "76601356821762328470018760314511043516689139768482635460827644088932345359833990300000000000000000000",
+            "-3082057817987225783940018823823275626896555685431339407982802274473499583111837976000000000000000",
+            "5648541152822227192914361314913340954845584907772959762826339567230485074982000000000000",
+            "-214422686607711518235564775293210089167639136234495027887060259225055133080000000000",
+            "14918306683190340821199819882268782824041976148175650592108117596000000",
+            "-42685094971061121600487495197367621526273874332328000",
+        ],
+    ),
+    (
+        -1507,
+        &[
+            "946755971011460406830147750660957594274801022094278656000000000000",
+            "-131730022847167071512725748945146741381253488296591360000000000",
+            "3693591679022156272138192761442250249057420055674880000000",
+            "92304656744815388412175046838197961483773831208960000",
+        ],
+    ),
+    (
+        -1523,
+        &[
+            "2314123724434909685785654086800322061690046225438002028660927632777371172208522559488000000000000000000000",
+            "-961085139072891786755934408585494655262395547218838265894064215450937234238914691072000000000000000000",
+            "37581718392000292350872411069636893113927876115921108386082469022500051483279759507456000000000000000",
+            "15751883021683135238392657004483180133954400951494482627373610379681259991896948736000000000000",
+            "55303233788138929604623500985560011289728848253544057985769545161183030629367808000000000",
+            "-98673474639121782081117549681135815498329331906463660201740337152000000",
+            "176055791505098728943225496211146954292845720272896000",
+        ],
+    ),
+    (
+        -1528,
+        &[
+            "354575195374461844246948169302330676593475440368426716439789752420588738052096000000000000000000000000",
+            "-1330434855055626709489147347810897660163606036675159746155342268003326531469312000000000000000000000",
+            "2534914232367418849248517663550828594607243865920257083888881891887274349559808000000000000000000",
+            "-1627456670209388101210115630889329822101953361875062144548551446440017449975808000000000000000",
+            "440965705781341613340051370543735443449794646350475924862081008378403105300480000000000000",
+            "13024372828329947544607341382751063933651799451598218667912113136795507920896000000000",
+            "99878477524954402165551224499432669308893034482769366038671830373023991552000000",
+            "-215268892142320585480835263642311079363564257459264000",
+        ],
+    ),
+    (
+        -1540,
+        &[
+            "35393772430126097929846875535887051112083338397643764824010316117729858709536249792898603024023289856000000000000",
+            "46787441786732458820488281906991541116619282261319097960218235215598040810357936414042690370937867468800000000000",
+            "7427862043816793225887839351921172697648972542206102972589825511255238923000003268762243855770714112000000000",
+            "-73560169330053609039053599079825580643757504375974892764340262860363008316091765380340603735244800000000",
+            "-466360079075241326995156571883638104758296725599974536329327058285603153961495639931946008576000000",
+            "10513992728337323857474607080214044088440240805433393995398011461565738649957200239001600000",
+            "-205585092816139405052854352895728829746651659435621725196337548174692722092032000",
+            "-348332834308833141926080575603441539337630931347494400",
+        ],
+    ),
+    (
+        -1552,
+        &[
+            "109964426923282546974659040541734596978737196511881924277668743675778078390625000000000000000000000000",
+            "-168501849245451656752562954988722497763345739413055619141297631930191127985000000000000000000000000",
+            "960477017611229799814540391661767410462946491995305567283527773267134882115644000000000000000000",
+            "8900602084859519542159521126012997003311482526430884819486504858908179944000000000000000",
+            "35491695589295328080698277740737444558083031482710342387034582925450726000000000000",
+            "327097650860419697623451356533269288102063806154364190468091653800000000000",
+            "15407889279707488698918058932649230485443796860453245816156924000000",
+            "-562593601440627491802215287725892135356831604255656000",
+        ],
+    ),
+    (
+        -1555,
+        &[
+            "179277385817055839939036171839607344168985308854293076933328502784000000",
+            "-153802169705179237851782806689208034512876098346328765182771200000000",
+            "36521008026523717023567141651588968008073653196177682701746176000",
+            "634043412248649501919536531936002831564519413161984000",
+        ],
+    ),
+    (
+        -1563,
+        &[
+            "911117845086375875023097329777601952853998940113375012207584134299648000000000000000000",
+            "-759085032445061540449808744300679851794433087689797198590650890584064000000000000000",
+            "160751816249029592886668561036804121827461172730387368506958851080192000000000000",
+            "-2314021489973021006909674633799769456496976796530529153284887805952000000000",
+            "832630358471054404769157558327998916860657859855158261373751787520000000",
+            "871644072641038723271254278753152228012083268861952000",
+        ],
+    ),
+    (
+        -1587,
+        &[
+            "6513324489337367793276083747037291772905558077285250942538049188404318991024128000000000000000000000000",
+            "-8168588266655858483200292225893943479720117796169933324940609821757723952283648000000000000000000000",
+            "2813697895290923521611737117584905290860102279060096521488196645102862945222656000000000000000000",
+            "-155417521276512947769892943086747417220989216139937481392841648573290960125952000000000000000",
+            "9999369081241627108636223435431829155706715490472599837617872902095397453824000000000000",
+            "-76543133597007222828549052962241728864813279252220384307817905293099008000000000",
+            "2954752272341849851501952547794655253913818288486582959962132054016000000",
+            "2253672506019520101130998199580644883220587571191808000",
+        ],
+    ),
+    (
+        -1588,
+        &[
+            "-752137845086560071769242284465766606476342485056636077644022839057445394584272893640704000000000000000000",
+            "60195369717995194299765194704687291551287615796192537870780377606698912826370781544448000000000000000",
+            "-12280966359713199719188969559666103539924037476829303011261871571442431686156252155904000000000000",
+            "-378335315410755116980846930665131165808873272109042275761349284670644093689845760000000000",
+            "-3589374606091040963085179205989698322613665725980213008521504909876182609984000000",
+            "-2344296418042625602896648055533516832503437774872272000",
+        ],
+    ),
+    (
+        -1600,
+        &[
+            "559462351133957566925020139551079066447337240072684796539358548067475972474514643909349376",
+            "17407829897767957362204795138913291266988080722489123580329651450610753365671132692480",
+            "143024871202414505618313750409257950266606895712449240285630068762060469842770229248",
+            "85970845657246222416250936234685713661470515363126674330639601706235460767354880",
+            "-36049504014248319927878816597477661489453862649360326174124381651265944493696",
+            "-1273977760787543327105884931567815586794688668314175862042152098485752320",
+            "-165507505135647884219104010125184277664686549608037137951196998027808",
+            "-3758842716698661712241372734394341106254342926371131200",
+        ],
+    ),
+    (
+        -1603,
+        &[
+            "627109133600030028142177582970827323412605557499695827255296000000000000000000000",
+            "-60480669410529354812812065502423560797385816674962705427202048000000000000000",
+            "2568459135239962459516949268334734895309288870551797782020096000000000000",
+            "-14263511902167067342306592426922483809118337482052432035840000000000",
+            "268886131223053886910893524650123111359741795969122959360000000",
+            "4228577613077642052745258882473091413893367342170112000",
+        ],
+    ),
+    (
+        -1612,
+        &[
+            "2317942391860066954754945406098987831127459481358788701076652023012793640309312000000000000000000",
+            "-155835745506112148554903463491994428014358566869455027451728851192204026163264000000000000000",
+            "1445263336142341892076638685381948956079310803099408183172268860585529680368000000000000",
+            "-14756809207502232042983737077742071377819813230486549694568337137668306400000000000",
+            "598179490965305476614628134816774447312739865219595996000000",
+            "-6016283711133122621257776576325578228411322362102084000",
+        ],
+    ),
+    (
+        -1627,
+        &[
+            "295733725023439585719866308804178367489089418921586342730749968384000000000000000000000",
+            "-547023624034990816249416316919376969544953055437388783990849142784000000000000000000",
+            "598771417557219170413097345877883075205528802350385266869093269504000000000000000",
+            "-11184103908396275712991620071369234967801980963700026254842396672000000000000",
+            "57020173939152264591401728828543469521559778443285209823576064000000000",
+            "978279656855049666545615412384782135249382850167035658240000000",
+            "10804502039601285960801782808177853141315827252215808000",
+        ],
+    ),
+    (
+        -1632,
+        &[
+            "-7806523004830340653252108305025093259092423651280605743116929219902308161033474839711000000000000000000000000",
+            "11262365816480350196041241114413079614175355964104221705572138350958641614029879500591912000000000000000000000",
+            "-4019929602760308477016874802289548190742377840361963800054266292252230960989281706620812000000000000000000",
+            "-112164755343813860671579988334089777134112608720507805810992524094248996549870799749144000000000000000",
+            "64012373426854833986538712522941142957515693559217178513081731630593953282324870000000000000",
+            "1863500792738204286134888525495499870475409308576099589360570317193944171987032000000000",
+            "30959417173525385763787068378742745109960186037760043922005604313268000000",
+            "-13125108240384232407998547914164132381785384481492584000",
+        ],
+    ),
+    (
+        -1635,
+        &[
+            "49276199578905457845102573562747225935362693654898618135337655669177929822508545164691799801856000000000000",
+            "16043575465481243110101477319672824496307598176085040524226450503095803336920572865041714380800000000000",
+            "1185588917565204896903467156749052980062785088793963546202418814735307992599908533342830592000000000",
+            "58083943160701156490657320186359495682972997660477330280450645897910745265012978430771200000000",
+            "763487798228608245665681588689408967028390633457036644160039940866436715782659375104000000",
+            "3909023240926865657331063331027713854833000410357627622106797397664367472384409600000",
+            "36167589002951478828770452769752290877803027612573264454125976905842688000",
+            "14748221610324576161365059254275292279323081493780889600",
+        ],
+    ),
+    (
+        -1651,
+        &[
+            "216220344049743586117132124524430080596519876722987925617581330275633769111282557592997239136046632927232",
+            "-114611534166920842587960894629119122029833245000886555708869559472552401058428998644032285067446321152",
+            "42871825134148753313679327641923674906784437280526767670553741874480765592431242145904472996446208",
+            "2834843470900478592559231789682794766373920093108816181241013651032618892555652710827038867456",
+            "-61453432759599254472258307451257744729140306642012680190624380972303723778506854760448",
+            "410110862453001242693445224785991388012252106680219869479795820053153814937600",
+            "2069077842669089250944860377500325918322079610874033363077455413248",
+            "27417083397248154476202712265428875552070001373905092608",
+        ],
+    ),
+    (
+        -1659,
+        &[
+            "187459606686726562914275562207638348322531465366390866446463579473250894737277794805235272023450107995790765981696",
+            "4342859304564535095042702292602775558581959331335294564433926193299584409072266800867072926888092810399646220288",
+            "314571548882093771261282046120453990924273718104695891208642727461843353698311279081359366436321067354030080",
+            "183647945801252310584296428931380015830637803812233477092735985592566096720599486953887258209683337379840",
+            "2111689753800117550462681010811129703668984276691189746364574778117395533942197640879599136014336",
+            "-26270800232649708051749867466345962801065777767454913161312807307262209032189315645440",
+            "124804514092043993920588314502834201574437325706873969007128707772029337600",
+            "37339864774832663986948622408100112420432744772428824576",
+        ],
+    ),
+    (
+        -1672,
+        &[
+            "84167329691923140076193507169334139598140227194773815009110465270031301158938285601380630528000000000000000000000000",
+            "-43190494397179273049688575501882805164460160313753285064008131911720769240692356550267764736000000000000000000000",
+            "-119893361548661338130215673616512245939410748219524093019630785480172185426740305367924736000000000000000000",
+            "-495529611552460246729081107629228214824438456692432376850245676095088945815585838434353152000000000000000",
+            "4198344167877930464612816644953997068009100594886625432557406188264179713288439064715264000000000000",
+            "81204269056320560967578569311530944131259719490504958732080350520763364611189673984000000000",
+            "483305082310639983155419912275235577939644104117915287583215431001599349769984000000",
+            "-61585845488050596381864858204056796734820954451125312000",
+        ],
+    ),
+    (
+        -1675,
+        &[
+            "-416157805849154826005910122101916634240040968455930675065497059328000",
+            "78007263835917224223637743991660347853338389729938751279736029184000",
+            "39577325945461007595991543188572217587325253585876709253788467200",
+            "365139487325146129271611658291681014072699664148923845233868800",
+            "-256288090150097137368400692657425009522588656589597111746560",
+            "69104900975525017325515326105265286438319262062385889280",
+        ],
+    ),
+    (
+        -1683,
+        &[
+            "670201758382433785609092696254464472528997169910625843348699770944216891392000000000000000000000000",
+            "516306171036518131737088895522829154600790590899248538399546205310703108096000000000000000000000",
+            "4198865083385774678238988714037690450776962004324357855041138934476177408000000000000000000",
+            "52696922467584336864782517340218623104664583740821352042308344741888000000000000000",
+            "111955297527664352081606374241899594694972785351340784674446245888000000000000",
+            "923748449984940172272502000003323968040764605821662350169604096000000000",
+            "11616536661628607968551912915613868843327106404400148512768000000",
+            "93907026108898024012187950088014490666792047895666688000",
+        ],
+    ),
+    (
+        -1708,
+        &[
+            "994192341080313317000893402756351762952621111234628991660238911716455990939112000000000000000000000",
+            "948996843580871981636264900800576860525196901375826716015636822099469486638559168000000000000000",
+            "432109859683880457245237336392864135003226663676624706691014812352286270776660464000000000000",
+            "-3804785583592961074802231907033749234792264713656916310537561366291594837472000000000",
+            "27679387156321086127604614619760385615628427815972422705884000000",
+            "-243717543219509240868385073078378209964919214569161028000",
+        ],
+    ),
+    (
+        -1723,
+        &[
+            "22117636276671147862840290439621705704748794677800451702784000000000000000",
+            "-25585666741501267588751437947480472614496645764581393694720000000000000",
+            "8147352112441079339114932116215629522948839835223967399936000000000",
+            "100007681683305113853711203615180993147700655452479029248000000",
+            "430473126428685627917807961121230497816202334067122176000",
+        ],
+    ),
+    (
+        -1731,
+        &[
+            "5023154833403628731590712240742726436850496418832518391720058914852335112337908602980801264802154767328175194112",
+            "-3350507801591761313761014069179418367015961504458915097741685795668262992120837154989939427980032451203301376",
+            "11783987048066530155248483932336319378594515045089107015958756265978762640870431107918255007247901439033344",
+            "-2861982024021086595914418940528632264778880620779428599388756688019247064016119050353085695894877110272",
+            "247250357411666586786620624423715520998979417863847733455713632653679383501230581869787923888472064",
+            "677789783175040280464772599790660356013727504349199309095239259070912019720473621299200",
+            "4864390422592523528949214363415261246344830264681514614178422512837877301248",
+            "582466983797392283978013940777319014543526676620651593728",
+        ],
+    ),
+    (
+        -1747,
+        &[
+            "10972276008883064285080011870718249401446538792690253824000000000000000",
+            "490532026749899358428380180583698110640057609931980800000000000000",
+            "-5035877279568158128379680839999923826224948571172503552000000000",
+            "-1073980648086639795645379984231813344252858982220169216000000",
+            "1064178758997417055856124713452790702387631331694727168000",
+        ],
+    ),
+    (
+        -1752,
+        &[
+            "8784104114151950543870965017364390186153896739161199386801574467379103878664015995729741508511668883161088000000000000000000000000",
+            "-5618451238484624829288312721125305115323085123317611460582254550454556172796580574820005601411993119490048000000000000000000000",
+            "1299742473031598843617950277127833138760319151348746149681947050548889500392557542029139546011789075415040000000000000000000",
+            "-56999665746957089228559212347786087948427803429102941315879309278172511068691675530438260771715486056448000000000000000",
+            "1648660879565696324395789554116594739406087568620902721408254798972245411301438545746728330696304386048000000000000",
+            "-500072290522287298493437051524780341981635149883364054616310019345014143732986037961394220527616000000000",
+            "46009234809638558926795075126335929285203951897146281453699757122222467643459328000000",
+            "-1283995022690977380746041844501471412370679672985205824000",
+        ],
+    ),
+    (
+        -1768,
+        &[
+            "6972506166996326005668602109398642804238680403398752117366309964308538393538123005952000000000000000000000000",
+            "-81288959010056057481593493665488831374698391287179614983994580979194302783517246881792000000000000000000000",
+            "286872452486958155746462042320177542329601464669616047696468743478392567854302648860672000000000000000000",
+            "-91213107282611886328242129572761452365192020295527418234678497178769222083301382815744000000000000000",
+            "2899084560391260080285208961045589884517868114041548821693965402743033447667191799808000000000000",
+            "1707323624444471636384624452363577927660563983908973767756318716331101311890853888000000000",
+            "113008357183335295512468973203170025881565986073053233234313232827242628475455232000000",
+            "-2337439476036560922839276285432493182071557115439083584000",
+        ],
+    ),
+    (
+        -1771,
+        &[
+            "21172633006876103241693523195029149321305822158883025087050318468530057009207904187986751112184137252864",
+            "4317513117338886371066489129580028033688132899396745335498131555436812520580140862168907189017489965056",
+            "56861295866549883841686576483309011910783273685748314744289057129031106313179618204114135661150208",
+            "6563630811082422991462283459369585305023550992794734851421175582685002111590555808767772983296",
+            "38662928657565906333516325398928284525676337108866928394685515957369747125705540194271232",
+            "242376166691000759506893832445286954466738743746706882585691805884283901670588416",
+            "492324347300657649362903200694486764244934893397527306084246467117056",
+            "2614522107508201896224113844957293524905956330315793301504",
+        ],
+    ),
+    (
+        -1780,
+        &[
+            "12858703576150165442872138454389112052505221669376607813578883133089254703852777017049785394148758997958656000000000000",
+            "6927725192413068189831290646693890707967815306093486307216981210414688532594925298122399721237579300864000000000000",
+            "28358246739184972369507420785666250906823746531142012928715184561908908409625698386032634275221783707648000000000",
+            "2131920630965960046829466142138117883532150493912576475645481827762028949965789784058778553145098240000000000",
+            "44675973532546488256683786051390141790335907483670753953480198060766634755143978950386063299313664000000",
+            "71989905415596407272360588321224179570900100727998432650598130457439373081877117896422162432000000",
+            "-221132280110904282349580852715014209778181695565277045568074014366450991480249264128000",
+            "-3656801861190984837107498501181529008270633865017945024000",
+        ],
+    ),
+    (
+        -1787,
+        &[
+            "64543164987089262213697750136635232209963099023049721095782858989420319721370537551417655164928000000000000000000000",
+            "-25237571320078485794201715802461746323364504422441091431091004924907421127646903650207366381568000000000000000000",
+            "8768947288403174551948630149189980838267090254569383966225258733648144946782067547753576136704000000000000000",
+            "5250794244278974834648940452736724249089423584779718825091795022717751035275017121479786496000000000000",
+            "1339554553226394174553611437572006718020120134382658117570059999593515316428727614701568000000000",
+            "-79720189784112180746539843280355241727002604470459947136655480909201408000000",
+            "4744344785293901943219880280306328338444647230832717824000",
+        ],
+    ),
+    (
+        -1792,
+        &[
+            "-10790264911093774228507253453960202624644801383991684644772717310824829123192085107743740081787109375",
+            "148247843401881742605396903182471548343349369613319531143280196206354083545641841796875000000000000",
+            "-12120401731706150173661651612806098875044602701115298162814675767782911460726999023437500000000",
+            "8661553824940084837409199422392736790906108166842733168121566353007877490716953125000000000",
+            "94747248748080400467292379040579506567102599939162869249249764848280772416636718750000",
+            "279356574156923690553999094564902235127779224262846599374382745932146463000000000",
+            "-1570399897460402626538003609623688758839384044669928772812983175442500000",
+            "-5712266113195708816033763055477595826028733634126621448000",
+        ],
+    ),
+    (
+        -1795,
+        &[
+            "1975363495575590489082132982742621821282593929112144493817174221518244248071717044501302214656000000000000",
+            "-67241999569014329652787591169360247491362161583445656361212215008513002466973491939770368000000000000",
+            "2924513295836839579134542930733031072273376059963840856531811474546345345568381870276608000000000",
+            "-19669528887826644065767740183064652514793035744327319144306223631279459403472830464000000000",
+            "76056694764463627435025086849395044366064639879216867180868932934720968934293504000000",
+            "524286991295186900868889708592874276048060201124123703041715068395847680000000",
+            "2325035420593243193336642737998263727919003643069993711246940045312000",
+            "6384600935170665514043470986931256443946434696356175872000",
+        ],
+    ),
+    (
+        -1803,
+        &[
+            "20098755686034016693939765554551228674948613342025236024467466047050348654231552000000000000000000000000",
+            "12431099082752332287240163903349018450148337456691773084431706791603697675665408000000000000000000000",
+            "5339552182571690503750705300170745370291548852529531519274266557636421407997952000000000000000000",
+            "255936761911580214009989960075992762190960288476376884794019770776490463985664000000000000000",
+            "2980065220478929740671471824848481298954158468434623735968090680021428994048000000000000",
+            "-53544285852786010205176722393916933944859431776616967065134864224349061120000000000",
+            "175821626335149220389208523228761676152767188142677624297656952020795392000000",
+            "8586263647806690299995234077948434023520359932608237568000",
+        ],
+    ),
+    (
+        -1827,
+        &[
+            "-9276974008862071066455066490893685731695082583592031085395968000000000000000000000000000",
+            "70109141427325651837753633313401062860404435212036835757541818368000000000000000000000",
+            "41402447714669775215080245713613393576525746057951239639614357504000000000000000000",
+            "118736239351042014743730972628384057706237442863811773137999101952000000000000000",
+            "-76627836694117562723703203512999710300096137449954657926616449024000000000000",
+            "13431379984158639535398401008501830443568425875315548859597324288000000000",
+            "4521988292403823221781805773030781975727216769625557758377984000000",
+            "20802344089604987273508875732329627937241071419660910592000",
+        ],
+    ),
+    (
+        -1828,
+        &[
+            "-71959859564387520925812967140138623597874691536758471718786118985298519625305270779904000000000000000000000000",
+            "396331802328486833321754042710906110096129036102681228323991807981051159335073461829632000000000000000000000",
+            "-533723035561374273182322280309516831529565748280646156467960963817196890346687885737984000000000000000000",
+            "-159839184329762409323332563126720149460333613853625816734802063453803824041752350949376000000000000000",
+            "-128438825936674412329972144970735472512613104814304844506107200167121099341122219515904000000000000",
+            "-833591115789130037848257656111132636521499493723149089748788518231788877523672240128000000000",
+            "-3170335694293098002240168280982690550402293505418616712937123088143962764144118016000000",
+            "-21580930539330992514717511322557295141033968574732392896000",
+        ],
+    ),
+    (
+        -1843,
+        &[
+            "1022305665261405789362573887281623554836961835601464130879356928000000000000000000",
+            "1703315687836999454462902705544451532759249442114228547496181760000000000000000",
+            "65803232285668613475104046006381373299270829927678317718142976000000000000",
+            "1933896614861732326681521305766136783111159908701140030586880000000000",
+            "7838335171913679781647841420995122015074838433940560150528000000",
+            "37403894180202678155356755976563481526943814605112504320000",
+        ],
+    ),
+    (
+        -1848,
+        &[
+            "2938880477054936063934166814222358958437520288529000102386327055109822715435266509343580669929125733754288484646912000000000000000000000000",
+            "-1882356204774046171576660675348691009084885293720571366092494536234534269652885480282987062900161050358966317481984000000000000000000000",
+            "112686396603605581488195360789983050759006793584078593601068787305714468706147259532864454886327005631932498182144000000000000000000",
+            "-483032986373707186467046509820584981104360833138097316324470196311679727060369680591237093328362643773359652864000000000000000",
+            "2097675736199370987230892333044091148920381864118519451847121706571707876016631500981130944564050109161472000000000000",
+            "-338256708485605522726290891966565571718828403690835075348938558322631209551561508276725929313136640000000000",
+            "9516436245479571768057813268908710281867928899384121705783749545414246835457738496000000",
+            "-44907225947994667664211013610763873589182456351030606912000",
+        ],
+    ),
+    (
+        -1852,
+        &[
+            "-3432366463633000984122516967992172603410772959589466086717172107028801835536956787109375",
+            "-1864202964955940882925421755698625635946118843243450767871656775077275596618652343750",
+            "230312731759414372117136144355370831363255645370702387878157540695712677001953125",
+            "148172205686307595979085071200401430887222096138830264461868050839903808593750",
+            "-24803289148863558030510653805302816391959034827087822744603688968402343750",
+            "-1605603892581506840006218437487307406796086358371358468578514359375",
+            "-51970685983805268521226623066259626848036613895311201054625",
+        ],
+    ),
+    (
+        -1864,
+        &[
+            "15708825836075130048472208775281230276406749524010796985690492864579539455976596279153971776804818741159173588756825976573230317568",
+            "-15251032535754076477509401246713013140677891916060861388972283651849656960024834969796742494390023298852498338680541589327052800",
+            "5049206342605041078137005265806574527172857455410100131852980177137743489647210683935725351726350503499075723486798576353280",
+            "-4040384007042304719843254404007471014289056879447437070162627679812491472419070637960028605283832041541184761683247104",
+            "8332632164436790450386480019440057949599754645420635562511403541156956514778492697424752495228150300053348548608",
+            "22316966976004787927475637445451565457454493945976489607409263421639138769760843766352478072182145024",
+            "22830357786431699026866819703206831574879797519703685909299973936433509723002363898216448",
+            "-80477623844269624322446500505858925376767863475438052874752",
+        ],
+    ),
+    (
+        -1867,
+        &[
+            "328043617100733300406098627198236644161849525109185520718517210187825152000000000000000",
+            "-417666456716082105125094342082637063107409343852345940493085161029632000000000000",
+            "6263821649035332499821670679192496191611885050408415035156766654464000000000",
+            "-10557876447102477661186151238644596127207141266739286333456384000000",
+            "89755014833202823144487504645057894143187931042124775424000",
+        ],
+    ),
+    (
+        -1912,
+        &[
+            "8533744906522299379568964249672297401480188986151807569450858264130141094296237463568384000000000000000000000000",
+            "-1973035119672326875552599794849030041452003331555546458715084703322270288886806626697216000000000000000000000",
+            "227014265214024216163677814903627688424814463291186979032377907166440845948316273344512000000000000000000",
+            "871167039998947214039871615717955531152005242340488945501146558376921470255422719131648000000000000000",
+            "473974708772805989855697078559423157995965724958714892213655712224087381793634171248640000000000000",
+            "8615704067438965486190359131256771540938138566548669238345848831658801146147337048064000000000",
+            "308279587235171748518552469818141365437891350083538025136448461778355250285182895872000000",
+            "-456348379360215549897508684309886836152114522668603214912000",
+        ],
+    ),
+    (
+        -1915,
+        &[
+            "456054159537444194212412319784978746670466288075756158380806269073727997083648000000000",
+            "955039752175865038518068452582314127774856229032417567234553777376498129305600000000",
+            "663390741827053666634329253765141561972318116625293027983875501308124332032000000",
+            "-26067037587498062947818338641678342777412546888862528797003763522365030400000",
+            "443913529284034310464735952592501145675272880020032207103765780627456000",
+            "508255408855221387252483812965750096617531678810798573158400",
+        ],
+    ),
+    (
+        -1939,
+        &[
+            "25168994119206510380956088584331547798512188240852103437146012650389796583243491737767259070931803308032",
+            "-347015126715812627188811406779371212703576706539508605220149278867816671029506968839381382514566234112",
+            "4032067249840249953876706860726529827445338139377438942536347489040990320447611843541278114623520768",
+            "1760480250252994720272497369132313449911676198266619367286870088609818540080660020668687026487296",
+            "493629846123531339870634086533823263449976203516464885973806360118360339624455065455193751552",
+            "1289486632909008153868952630798853712361986453109781387700691633550877365053807394816",
+            "-2012583395225375388164793228433404906628752633485834271033913601238761472",
+            "1199649546897419949721286727141564553761375754459369401090048",
+        ],
+    ),
+    (
+        -1947,
+        &[
+            "-708754387150477906258898481288241167074056377419203417482180134060613361874239488000000000000000000000000",
+            "1793612415581137291777152291808015847540569073698047312801604537949255905038041088000000000000000000000",
+            "-560978584019371606449697977681910033269557798330925826069066218960215332249141248000000000000000000",
+            "81994076822059328052805945290639146483694047057376289254590868083628947659554816000000000000000",
+            "2542514753896367146063368303814220376128471886516922974219317357940071158775808000000000000",
+            "62879490394833310365216362802035708378238956132005566324877016974098608160768000000000",
+            "186417520111832869856276238369237172768580252979149213900510785182464212992000000",
+            "1595383311249443628495540825415110864762065678771340005376000",
+        ],
+    ),
+    (
+        -1948,
+        &[
+            "-60359231756114228046312760517995598636014418611165202108540472727680394731044769287109375",
+            "-83528308786029877065829754240857464041613879051775005896994344692041579769134521484375",
+            "-56589112415861039423835828090216147304982517225639838106022379838921412963867187500",
+            "8664480542771479995124593803995558323584232529893072617642359530708632812500000",
+            "-1875003998570024043265547160717814432553678968089712618964279633525355468750",
+            "78738042837388380931310004898524767489632229776164052758764296500000",
+            "-1653192694311606159539246652235098886474782176330892781014000",
+        ],
+    ),
+    (
+        -1963,
+        &[
+            "422574183089897680993341868384579111278985397481264634217306904003084288000000000000000000",
+            "-168280159147819662631541114368157506731851183877818745600964405588131840000000000000000",
+            "19059668030708404214628089603489170588607044373819525852869528551161856000000000000",
+            "526162909745086246983617441331479464568236789741451254071906942320640000000000",
+            "1518056825015932290654595442833580616566509313889830333734453248000000",
+            "2816602747966722384292456109932330141692178657723659018240000",
+        ],
+    ),
+    (
+        -1987,
+        &[
+            "2152711710460113053454361551481127980899754626177153469301210633086911381504000000000000000000000",
+            "354767736420196788341083494859294966727170608589666215527197435153235312640000000000000000000",
+            "179963463985257891728398235767398012843929190313858757148260720926626152448000000000000000",
+            "890517685903235689953875667141889963831705427594807695660313138203459584000000000000",
+            "1562557420774935520840278664946610330799534895639013318206251056758784000000000",
+            "-5779660732904696211335224337898125440233946750210367147426709504000000",
+            "6578663875408632130391644198378445881290562481107692638208000",
+        ],
+    ),
+    (
+        -1992,
+        &[
+            "731953138148612363200006773690618236635791777404045594505226326541058476059650041402601665221795426481471488000000000000000000000000000",
+            "-308540563325234150530294445899496416062338252313902356423304625989479333175413835518385628809127226449567154176000000000000000000000",
+            "144300395576021007554802289763516095072866714885905301963374807594833752520753254277490289850334459477191264567296000000000000000000",
+            "-808866852840923608996786103351311825128901733002741646984660426791252242238385279258056544064657248894788042752000000000000000",
+            "61548825903829969549115412252532661196051058398845236147394689389091797730156217217353404626936327722731069440000000000000",
+            "-4366341834150210896212499994237814155784873551664836144411269196821900431454410940399808960472136773632000000000",
+            "21974272648765844716043407694203780803861138081980150177219170488878166433047776610048000000",
+            "-7845302114898729400939192362548254523399142470783095817280000",
+        ],
+    ),
+    (
+        -1995,
+        &[
+            "-2793280578623934055462524935401836387726975747753731724304200142758161004380391213668435519754253369344000000000000",
+            "-9492390995020665462583343409318513515487837080038989095059610855656603716668716150284165565548331008000000000000",
+            "7583787138256896036340914197731526406279614642135346044512609265816214875013392826297432572782706688000000000",
+            "15993943008244876723277715962890260649301013109812073265696006930313435935545142727945523178766336000000000",
+            "1405418389316369750731155601214022158479432472665399394391931350212884992870888047689009540890624000000",
+            "2767970419159266385208798347997551824595952578888109911158722719932769753517105526865920000000",
+            "1794440507135061234628935488054861046711402251472375612353624431382279258898432000",
+            "8718600855947368607644625805980861416251107932699191877632000",
+        ],
+    ),
+    (
+        -2011,
+        &[
+            "3724345697998400664331198731496978290531557897528995154619748120946305571479729398064716390416252928",
+            "-31439269340303078148228220328042261494256082283896860166973369297530113746794449868138882772500480",
+            "113648106726091213901222788439200303371402104192999309947244480427700911848751831420058812284928",
+            "4019743135782898154025673059934798448591221861229553928796974604824506077288962047564840960",
+            "45505523280167527497259908995178896249258874658053628457109501134300067995887255683072",
+            "16300847873399966463232250676579570378903529987299352299708939470043611136",
+            "15287325030838530726060718956632626460288135250496778653237248",
+        ],
+    ),
+    (
+        -2020,
+        &[
+            "96618510476101494262326230905015345942137622244445490714230161823031395192395752798660747432604314429635859794886656000000000000",
+            "-114547487123454043834286732897330618088919845732945793440217852778093992004535827518587441747221316624920623448064000000000000",
+            "34191722589574393966831482026109621471487590645340391309906435399776676476885292566669299458458444794208198852608000000000",
+            "-126635906833390756259248223848378826853267744250592618362284655893908450431114261029737774114402645946925056000000000",
+            "126482005618461671700071041598721624155300799114571228919217796579304386701681471499911206569964094357504000000",
+            "176138175078398975668857889775192190573785077943887276596638345525815510274543362151221330827116544000000",
+            "-95859593740017356476608336735898254701107375715173120312705957482694415125000852664690688000",
+            "-20945483472992398576760567464640319554324020854303057017280000",
+        ],
+    ),
+    (
+        -2035,
+        &[
+            "-20272526749412809823083654113701249456142212389727866328943941716005371104626563987756594233344000000000000",
+            "15388294599000857744143318757566229576494184459768130035160444575649085997438966923407025766400000000000",
+            "-4553791528273365823340100486576933335331088745499980126285253838433450610448348173438025728000000000",
+            "10896229812971971671983008109757287513711503514668192133658351218979725801169002306312601600000000",
+            "27741871614211157379858579625651948685563265832063918538688386026812633205437978640384000000",
+            "-19866656381335114376540075886706223313405319956556931579063269810193875284787200000",
+            "72103646767357843076571388909583447510575669556261115230586047727403008000",
+            "35346514847400399846346453580055626844947813034275743009996800",
+        ],
+    ),
+    (
+        -2059,
+        &[
+            "-56034856684589927078414591358686024427303592021191697501854403158323415669675351642993565296157109470101504",
+            "1824291952089881900027334206261814738869090207478311873577753102655469161007885650926863964874474159865856",
+            "-342342670048086209880261896954335258835452878801433679150675349095070316347093698798115959196050522112",
+            "86498199092241089536767712301980297570828593262728170897256449244680855228593363778346517782331392",
+            "-265404438026451640991322555394438190750016288796322384211040885419900295058559623516500525056",
+            "472400296402031515614318725961432499823980974605990415354208529387103805312618953965568",
+            "-317141747616659344947748020214200609962251521536086891769742607211342856192",
+            "81324504661699411696370065463759236328368250641149867361173504",
+        ],
+    ),
+    (
+        -2067,
+        &[
+            "-1048381404250859237960345683954077798415126341231866892467394263961044473599989121024000000000000000000000000",
+            "-668970055238116784833531805443697664386487391562054634198653530750179119133232201728000000000000000000000",
+            "536136574847970169413414271304893338857911718699521129945713552651830489441573535744000000000000000000",
+            "573132446960627712075904799997673891353427403590157439665916955283589661007762423808000000000000000",
+            "7173187924990578735062288895592562644568526591237592374659705653729515234259894272000000000000",
+            "-39649209022469693446259132924803691874935601942314972471658561483222120627437568000000000",
+            "50952986609128295585968919064626912847948399068930884453924509174603662229504000000",
+            "107245044545088283784195030883785582562392604305814542819328000",
+        ],
+    ),
+    (
+        -2080,
+        &[
+            "-347127751280529546373794280439177372175446883588945874007113331668224542343854191569843747712216083001344000000000000",
+            "-240753367017134731311878092269387607837909272311526693061291544991717222941723665219340524322953850880000000000000",
+            "379428865063798067505114124625206579686407240860236771172756342047667505265467346904294443814630852608000000000",
+            "-6063598850065852012823483216690399070555797270909125921267921492705305568991107438558207255623168000000000",
+            "-105733279672248811399833429397962592522528764925435139420243621078815060992360770225402496000000",
+            "1684405247901071153593663644432536416039251449279374007548871514684165451929961741248000000",
+            "-604064322492058926197334066658597434652036522463710938994618666986992510688000",
+            "-167932815580503107204846664172651140184308864293747590432872000",
+        ],
+    ),
+    (
+        -2083,
+        &[
+            "56106216560188103589087337422639293318133708539713749574270058496000000000000000000000",
+            "-24261822031805250493501739349427861853890830942453644737640398848000000000000000000",
+            "22788373070840541263845558779868546271601148638885163292191358976000000000000000",
+            "-5278168437052680004607896557147810716175840424729992887405641728000000000000",
+            "693048905406378756456177931319819735393680958729481508859412480000000000",
+            "1079050983332697700114819217544921691043181788643123108249600000000",
+            "186205839479306999525966201308917660820963218042478314958848000",
+        ],
+    ),
+    (
+        -2088,
+        &[
+            "48980118314049943567497426977871530083646422227390983489043389554051529561583367224594285987889152000000000000000000000000000",
+            "-2127194436389128200753235632897521525793212182671005617518168403151698400049950540401541641955966976000000000000000000000",
+            "4439181137353160316195727652257529576970638746138470303989463167346863515881343640701698674166595584000000000000000000",
+            "-1988786487402278007774316686918033638725087580438728019372517187084769740349404857558572941056933888000000000000000",
+            "23420967633564982538105714054283360377710970956513286255293734383792032302959407300632576000000000000",
+            "-14681185315775167281996714763789190709254547292066383856800681266217807445368786652262400000000000",
+            "3288718313968488694575150867889540132781047366554012509299314115316901666700393990850304000000",
+            "-221148730774162817262208919667710826057675203054381512835136000",
+        ],
+    ),
+    (
+        -2107,
+        &[
+            "-22916111954511175098995062190363776864630107877823373004777787687436288000000000000000000000000",
+            "22397003691902224736298620177555812699134098891477510798147435102208000000000000000000000000",
+            "-5914977952131763934653101648243511487445517113797335957821232257171456000000000000000000",
+            "507519055560569456010725658072909625638957287992016903705007583395840000000000000000",
+            "-5432018765512403868742684758756472994439959748091070453546376757248000000000000",
+            "94866233775410589240316359765864806739257117253046210064138895360000000000",
+            "147617624403864673046490719774140458699029856138278185274966016000000",
+            "424324834303956302734761202812083495311080389375237821440000000",
+        ],
+    ),
+    (
+        -2115,
+        &[
+            "-12320529112816485029490071275147401254623044766065702899789922616015972916919899695954964244332544000000000000",
+            "6851014630470788951474000220378708978602690108698789741239657161256381344647209534016507084800000000000000",
+            "188046328224204977070556063022838471502834529684592151709117292658953821567776668392066383872000000000",
+            "1626559182233388632896336380121222528500462338262089585196492549826467838877250518777856000000000",
+            "8274053733279331185231476171025098956871194878269431225454669482053541753257984000000",
+            "224029284156834848313469715162172279223090702619043704100091947965038460928000000",
+            "1975952177594069191509332910528238914996999050720625864243495490402910208000",
+            "557802358738710442621865138024484879192652104239727305072640000",
+        ],
+    ),
+    (
+        -2128,
+        &[
+            "357459654940888416704443701791671810884637955578278296910034364210456019437521627271287251297000000000000000000000000",
+            "-132363273227266187625531866220596456831394921070306441938407342166354050912270218119317491752000000000000000000000",
+            "3197036611676967344729944360449019653811177576856707147717409177907523869607400565772028316000000000000000000",
+            "-25111946194304598144600141912479915342907708566536227349772599118964921045237685219893528000000000000000",
+            "26213070566304753575236237044283105212855971593891292188536874273867352179191228966000000000000",
+            "-4625140091596834170686754429725173494579201967401235709985734484959258057427864000000000",
+            "4718224433225211297795655500989363615505581679300784678995152826424699740000000",
+            "-869006076956265928147209107432503320058611656677646610879144000",
+        ],
+    ),
+    (
+        -2139,
+        &[
+            "-57771122326546737064483150345267326483952386954553744379912486357170043101324249815958900390706948533880537120374725279744",
+            "22089730582886730633299320461534039278428333084249184089158685090706540236576443012701405516263417143165381085495296000",
+            "5988156326278329439835261699042349401793974642032568505429964895394774239767349866442252718354566727418846237660545024",
+            "201338530057281532233366023595687450846199404057413971346259247962330590690914515774449577896697808205281002258432",
+            "23763282725606453391484505513988030448134545515494461252776909718807596233434469041928462651053913700250419200",
+            "-9217161108125662254175853475538687031412893753776117291730406543916162896270825261047525752700928",
+            "1365565828749185026713140023904918527341891846190393698826366499277654729373457055744",
+            "1263235967225622798274995475287782721050617768569108440152473600",
+        ],
+    ),
+    (
+        -2163,
+        &[
+            "21930996060512693669509222865448586670385717000082799967810659351386787113514530768945152000000000000000000000000000",
+            "45797036945102309772224764907295871238530066024682327207378092955990293523370571651501522944000000000000000000000",
+            "119789605101657535895986455209554301915012365752512903364318458396145461912322216384528384000000000000000000",
+            "1611443147523634084402145043638881489590036013317277636323101941514714454728328867188047872000000000000000",
+            "4623306242820838325644402602447512556123271728765793422411608185331204726789274857373696000000000000",
+            "4691380305439125737919865408088523817408753718317461685980267910709453124365828751360000000000",
+            "4036485341885803653376998461079161888638902204154371225491809758052315576664064000000",
+            "2847754421255434406831084382854294692017686343514257980964864000",
+        ],
+    ),
+    (
+        -2179,
+        &[
+            "712527597959059293070805699693521600394216540598041061347929087201923098381806834125889389523932020736",
+            "-2141397850576918291751666984549101827680047251446601092669060366120869432472534134557982997285437440",
+            "2258389034399881078068141441393463592947960121440609696654361125351833860687657613511921005232128",
+            "-896506111596760103554121634111061765403826433758516443534674821293805613463403580012709806080",
+            "145970527168209719679893830700260646547963981332090546601960027262268491955192616217214976",
+            "-43201681248411133404924644637854182450983260008399844068658369300948230078464",
+            "4883833389509877903588796107700053363172382390445121693564600320",
+        ],
+    ),
+    (
+        -2203,
+        &[
+            "217049315120963111446606786614831619271467826257075291824999096037895307264000000000000000",
+            "-136125812590732504314763782112143869965497676192764476144106058214793019392000000000000",
+            "21640569628318338155418587236281230588430084772239571168625744182002057216000000000",
+            "-6844014255307220707700878302398568223491614384979193414938903183360000000",
+            "10928022023566789669783068114819419427253014142627272930054144000",
+        ],
+    ),
+    (
+        -2212,
+        &[
+            "14859840370878018942192069785534736609041618993116811454870480459571604797635893173299653640192000000000000000000000000000000",
+            "2836558871281444105023829139714192684715227904751331708206723179225566182622515778993036627804160000000000000000000000000",
+            "3890977187601683986025999074783014862874029146761395187144961451446495921472907167223695808482246656000000000000000000",
+            "328395500347434528480260927061346860624403260645429385348188089057588048606016337122946001032445952000000000000000",
+            "105093300942325379186152642727472890130098478811470395614227057010673223584776083307003660090761216000000000000",
+            "2635398029579912909726297634825126731998624721745568762355601723548084534226951586697900867973120000000000",
+            "-1794017495863558121539770794991417630974554033875313142885940850131161183007225432535206144000000",
+            "-14764467315169255060799247175327322183255822742333059328696768000",
+        ],
+    ),
+    (
+        -2227,
+        &[
+            "28720631316224774123307666252767008222477866535756939624181661696000000000000000000",
+            "261956092796420413099089855619865674232266250871471722047878463488000000000000000",
+            "1117562314448896028873037128320051953679946267733531638662510411776000000000000",
+            "179677092901233078376451780209000783317926213276608629696154828800000000000",
+            "-3167872386034517628603508442801962844741316143930251578791952384000000",
+            "24345691790686041024307812988315229670534281342927768410779648000",
+        ],
+    ),
+    (
+        -2248,
+        &[
+            "2084393770538271174632110448442782099523036581268606990260702099518651039420326349339448303419392000000000000000000000000",
+            "-5111700864575655671821311476793257287436705751661083914552926831329948562556710374566056861630464000000000000000000000",
+            "4254323691588764748812287060309974082965854648421920952814038514593015998995458627389616540352512000000000000000000",
+            "-285309659850927630147138833398370620839153631753330889789417698213635383991903932609155657629696000000000000000",
+            "88134361481013862037623721282321456763410990739898215257501213783922976829712180008709697855488000000000000",
+            "1928686970271650129221439194571253201811246109358954538964427552222315038308340385311697309696000000000",
+            "10812459035707397233476655208432997948451828225740320517014125818201161322450738421035747072000000",
+            "-48897086573140438367811382998684541126261747074957640329055296000",
+        ],
+    ),
+    (
+        -2251,
+        &[
+            "4165008547003759628112118234054515048886256385208424911443416900360120729658811518010622935507689814326509568",
+            "-6307827962692401019514460275645177699576708122376245842900388173676879901328864294777028934510181636636672",
+            "2464881623384552032373486896264007208141017518750714231238086180941101646142017809371392435388107718656",
+            "5429620551652337296284510315545277007161774639927390689562927006778011611377558599768391173537792",
+            "4220913458196253389938728795352049508682058289950623471169656768921425837629393101339492352",
+            "295074881153276414431511820211653523303202730826459891885794383918751968919552",
+            "54004897876955992640352042858358069070508875345188259096734531584",
+        ],
+    ),
+    (
+        -2272,
+        &[
+            "242098545888828375171338921908254951284419427727589143764576369986234915718207249394533449730913000000000000000000000000",
+            "-2993239997072728904758400266510189156879172786963694948203232514129095569896178024742894770136000000000000000000000",
+            "26882526212821149984482104726246076164009266027684820020492290178864836605028100352437305844000000000000000000",
+            "-28275348028023034016505973113737965257692697553138676631578369303505955121036911368680472000000000000000",
+            "35522689692136547175596291952821294044268349658272095885086148758991926614098518804614000000000000",
+            "1562433513379899260452285482389494242725630461945233934124618925116768204255064000000000",
+            "-1959310793627003060971299543464559587661491575238203463269889829752520759756000000",
+            "-108064404551405195098949633286004881296329339101774903010298472000",
+        ],
+    ),
+    (
+        -2275,
+        &[
+            "11134488179854392251805065935747215052193757272978590452216743862944751679376078169041981669376",
+            "395965144893590806374236202941742101172842621747742430901293146337696901992795377748798341120",
+            "-632721776794265757856409712037572299719835055374260577388650404394722471289383294586585088",
+            "363266688980031245804974067994881411479261223387002973640610843035122149529667848110080",
+            "-113046035354516680451948181972932291185070039769155065858138978117005033175101997056",
+            "22606408080380265016905369003661854625239269690520558198227611647084724349829120",
+            "236201734664611527591729737308250047320627107032388840429405573623188553728",
+            "119290118719947598596515903711265977008111086017291056585329868800",
+        ],
+    ),
+    (
+        -2283,
+        &[
+            "2785697544136965162387394072420723630688138362481979594882244140111963949043943078887424000000000000000000",
+            "1716198832407149390142230898029767593989849732884797744439502301315597060703112342274048000000000000000",
+            "595720153990123108249414034049306230614826738750085234614994400505924870613277555556352000000000000",
+            "586214303394503164685048660501020600138153720498510345640905849705825978399057772544000000000",
+            "834127837386049541614414002772005990776064591087700696685710203969678685910335488000000",
+            "155211702038184330803145503259654912289657012240057832249131008000",
+        ],
+    ),
+    (
+        -2307,
+        &[
+            "1412749382157200587651173463430627593022095337512260704544407030480861162358898692194304000000000000000000000000",
+            "2483523182724276462840362617159334093406959945577855737118632263615153258463394420228096000000000000000000000",
+            "18391044050775961391361238931792273382705484863620339131559223526406061994386510997094400000000000000000000",
+            "-7144919420125250617948915451698770424668832111229415217593095047333306025612145950982144000000000000000",
+            "1961358533602121404447657447226129662822667640353509536238051840165359956904582127288320000000000000",
+            "-626996636568052494642098765460721199143690474545853784048875792892969080502756573184000000000",
+            "2381865133483501161160556136812180820910829345877586866301686608817038178857779200000000",
+            "340947786559500160124183578739055204802510496739566359983968256000",
+        ],
+    ),
+    (
+        -2308,
+        &[
+            "-157727454369160325096917269451231698910192320402807198030882406442048696175437472235895361597037637501114646528000000000000000000000000",
+            "33703942214830888332224446855859562826929810670351680358142233572550592683754012504926919783161719063406706688000000000000000000000",
+            "-1627589682059047757488859482396970402222350620520036142916712605753947203683501726408661011385242983473348608000000000000000000",
+            "-48640268796821492944205184979956189851804596976508785080863551601865876722648188234499062856477413589909504000000000000000",
+            "-1116915376511119833346360365484262725901723504762607767808121100535269203642463333264940109750244273528832000000000000",
+            "-215077006587347609435326071805340693578369507481999185679593440353684230656268736385364941459689472000000000",
+            "-209090364516198298340935512226113016534090923176795795480454098341062670041807922364951693568000000",
+            "-352281102218420735745148169757008158883391113410924856568037824000",
+        ],
+    ),
+    (
+        -2323,
+        &[
+            "8783498249734496043068607515313266334294762947629986455609459464079551536251831058432000000000000000000000000",
+            "41482307300058062651249451797562836301715123037057176625260996939891789931727493791744000000000000000000000",
+            "586390130695674037751911352593424003002758410625516360752453971246354013952160038912000000000000000000",
+            "2355614900277067146686975407330771003809876145256382453743263919431045641148563456000000000000000",
+            "-3694458185662565363864707856121826719438556347549597400509321809651026448351232000000000000",
+            "3534391218226896568360877967587911402663044321192348194795973348085972598784000000000",
+            "-2568061646447128677022846949995897484277899373000227979269714957303808000000",
+            "574835233844737556839255055364769382984597625813154466546343936000",
+        ],
+    ),
+    (
+        -2332,
+        &[
+            "33793111622345261684533983655372826571464243779728150921154776425899335336051395587270796298980712890625",
+            "-6164961275953333515869841779882536995826864956194218477839832614520976686630351710738658905029296875",
+            "4461965262146690834178146167327540286906186533181858743604587665704731715931580894020080566406250",
+            "-284125711347723478630660908977123771521814241540979340524815256987537585601571641326904296875",
+            "22600995320329442897804448537196177520061708433698886365042291350071222936808833007812500",
+            "-23015927878688172888324427546880042659891828464866491561772121145125864206589843750",
+            "188337674961756999216084394117236673316770254049382978816388756685700812500",
+            "-770559803726795562481167325160193202418219716790521741236985110375",
+        ],
+    ),
+    (
+        -2347,
+        &[
+            "901644569922562159908075052673194391591813593885976890680803328000000000000000000",
+            "-275005605893225872283528922063608430694556156276885172134243991552000000000000",
+            "-3974980164212098708353473429081552320904452727132783329325088768000000000",
+            "3850392729356723089776031377897241173629209744573099844793532416000000",
+            "1254200008892825161202591449810348856556695501877957727675109376000",
+        ],
+    ),
+    (
+        -2368,
+        &[
+            "13763010562010447736335522042503118314088071947404391874290390652556395856755395898681640625000000000000",
+            "100063299100062484741764359144138587201172398931943523325676311235352126427837989265625000000000000000",
+            "190332485121054080731722106324844931398398039096766656328623050243331233387876634242187500000000000",
+            "-49252652190441354278643720772033204087329719605902503851579338085360804341232695375000000000000",
+            "18197221520849310447588942925277896315190875292038821651919667999819970201492870843750000000",
+            "398147263907108330337871794606964821428334362411832262933068117568928561817387000000000",
+            "-98123633966353743740193577075364216197810192969706641547460333165755216626708500000",
+            "-2474109398126428198773245665479239061728276248067157774227557896000",
+        ],
+    ),
+    (
+        -2392,
+        &[
+            "124410439821086008358607603757564827085385830015369381672797870110123542431419771350322006103622491243282432000000000000000000000000000000",
+            "-71808862906473472814394102146121380821290881158866990026668566029452210291527171744718668760844487666790563840000000000000000000000000",
+            "2018457765501263336124690942980023568284191954128375844311938307195596105572590413919483528446301945838802305024000000000000000000",
+            "-30672574295057310479427470609811347426677433474082768470160190422041956357979018915233511417585646633012166656000000000000000",
+            "144197929203921474272195483414870313808743773282134515459101721548181010409900511434648512065471783222140928000000000000",
+            "18818846337859987063125250589794267670112845200158627410217366715345437821662151515443054759656067072000000000",
+            "12403257016066523884985705407282205692587531571021429715163535373661739954712323938156718409472000000",
+            "-5358260562387730184396375024847338881045229800751153102619210816000",
+        ],
+    ),
+    (
+        -2395,
+        &[
+            "228616418180049074709350282557829527885362837375879477212616034738822310171430724225083180010438656000000000000",
+            "21274735684631007862638382597263174117333575432683625805805781354781238286904462329865767485440000000000000",
+            "3487049671727184329540591965156127615783919350615727001357138505200483179043721297494705963008000000000",
+            "6088145313009796962427744822933219947827777458744347698214244962467053425656202465902592000000000",
+            "185032440987219672604651625573413611382963075139010145974439130990248550962647325999104000000",
+            "-39791635998335145997527055893989556255304536770761603345857366763042514216681472000000",
+            "133360434735208138089733757820276320569763794415147284017898042088860692774912000",
+            "5900052012888604049004973273330975332187234459947124292673159168000",
+        ],
+    ),
+    (
+        -2403,
+        &[
+            "24279119730911572401603463947085851076637243613288623331619120173023232000000000000000000",
+            "7786057051615269232815758297734073317959461324692935887614063838817681408000000000000000",
+            "-11874884954050127619173400514896650801018238433895901838685061902682292224000000000000",
+            "5559748699025181480926702161852171591222647661089846990718237507678371840000000000",
+            "-205921970353992165278585586660540390520273422573683799918287706390528000000",
+            "7625704243942351617426385884534079265889040465842639422267977728000",
+        ],
+    ),
+    (
+        -2419,
+        &[
+            "-314194214386379898806580397540675612605513580292483042393954362095827278800515491814903686257542094321972913266631901184",
+            "299256045780870182245708482435056848123358443573608156863607397619039854145509819221058448877633374779882751158910976",
+            "-1937472185132603372385350601086937234232301749730905636322087766142074289054570948988503046018579772758405677056",
+            "16496024369010396397534135205240293336694461582204651425355202956238838370828496634989625840136809193930752",
+            "-22319918758694661128993153155111449465069490473275421887408975683999183311765759093339554837774204928",
+            "8838986644680457974994367912407673123394933696256640998832779166759159683311364572046952497152",
+            "-542594573785434538267097853079861041822869416584462036669647426916796479572017152",
+            "12722520234155695340444885429373169449342627695533484393997242400768",
+        ],
+    ),
+    (
+        -2443,
+        &[
+            "1091141467717850568672979128010507742016030650424992197403128062629904384000000000000000000000",
+            "-1303075637569920638823636626357675640545850735224562636245543475075002400768000000000000000",
+            "2830628238191940143635407775581195163394092966209234143724725659872427769856000000000000",
+            "-35392782910988048306790135198049515274150075469203806953714502351930261504000000000",
+            "117601856069354919757831592900055106746498198166118083406444614561824768000000",
+            "27329963093974614764335693952290587215038951361905284668516892672000",
+        ],
+    ),
+    (
+        -2451,
+        &[
+            "-4760211488944401636955878152547067630114193329987735449856366133978610812433496605042859940246610959401821823629721564212264462974976",
+            "2282004664186276055103399964008606471999515064221059430191605588118151299479064045444584023756254938867792690696688322679675027456",
+            "1163421081166468839179064417372054479588360940565514021470041714921998542593382577875462473413969885898221173360571969118928896",
+            "-62030206832686440126220402493657052744272687330865361455783015755541161411380248016779917120894821008757649881171866157056",
+            "1206162554347534712055603320654516266095273207127827878453452784757316915302438597135936996829020481422476846536065024",
+            "23068811455694279738226442460363360701355835452121728081388741451813107277676715651220633707875225567232",
+            "1155100784243526637003872250125796587728178505619250793032728669787782175601366729890988032",
+            "35234240048945856199743020456691589345410815114528684509614735065088",
+        ],
+    ),
+    (
+        -2467,
+        &[
+            "3499654580083513480780442535270906288244245817187967851041301566205480840454601703424000000000000000000000",
+            "-218482602063875680231687751041101729826219255439957614269374584996832163538912935936000000000000000000",
+            "34138055642822050993969699125166307824813691951911035039100037773951293368957927424000000000000000",
+            "238528745089758540048406771469424906444240253141860505676549741440515769959972864000000000000",
+            "1346232118527750102324672552680470759598861276724468920118554211262755835478016000000000",
+            "349913759717781640009553198134863073363570193781559525466801009407819776000000",
+            "58489485549827269251653457931226945926008898906244602774295126016000",
+        ],
+    ),
+    (
+        -2475,
+        &[
+            "201684182286372179393062451263664548060625469466775936614618690237593893155058830747061375205376",
+            "1461935163386926165093218929459220054819780120807007414661052239525077010895427288653126172672",
+            "-11030509847433905709754559162349920700839909342437632654918062458938754401421865769385328640",
+            "23276237671091755724585580198839323482321612322032799082106723423862180101165652285325312",
+            "-16688768132204280248123479672087623697467741156248627613900288114046029303345458446336",
+            "3870969463782175497146162095973181905954010908290907099807769415723908254765416448",
+            "2733771219332172892697509321059616009224971408258071111694652494838465822720",
+            "75312415659287710391802089346350507527807726607227485542746741669888",
+        ],
+    ),
+    (
+        -2515,
+        &[
+            "4542025682001712314257239669798903836427850081974322640019291191483010335933953132317007413248000000000",
+            "-784644786861482921129467981036881479240730298368490326394432485672646287230039000718979891200000000",
+            "34524264893265272380279321615950864937352546831170702124352655476891149091055251354550272000000",
+            "35508022560279525502093915946881957463667854787274639048203126213271422670327303372800000",
+            "12817452553049467040241755058889739973324654809101647659200465239853072411262976000",
+            "264952534336082861130132854408232794590765653659767680784931099443200",
+        ],
+    ),
+    (
+        -2563,
+        &[
+            "102129587663985724287806992489561567577846558863500706855006254743420928000000000000000000",
+            "58864774134339111721427554723408681524548474869863918839124022121201664000000000000000",
+            "274597348065990355539342208212141816548455550122244719825808530813747200000000000000",
+            "45746741444785974725001917837507456131544792405121928160749594381123584000000000",
+            "2274186539352606939662866985000267388597468055648246852970041520947200000000",
+            "1183114456005657497289765152791754822505422898489194494502849581056000",
+        ],
+    ),
+    (
+        -2587,
+        &[
+            "66736639466811464486316409211308571080194967611443915406908782127986639896576000000000000000000000000",
+            "-54442265883475106765390048942726869236814789244408193629587557789619162447872000000000000000000000",
+            "9865114381921760244056372681154858507519972229288562374312003274710940909568000000000000000000",
+            "7628559835192390873908577700659214627987654432446757572146396454875480195072000000000000000",
+            "2268363442853398159616332893340107720195299265667683407887256419214756413440000000000000",
+            "8849540488014262193082570337941167593099661991915229358330188298113253376000000000",
+            "-6085928857069049365337750400177161843048680118970540092114938047234048000000",
+            "2486989942934826375315932161474036024382329866851220006583098433536000",
+        ],
+    ),
+    (
+        -2608,
+        &[
+            "327451677250026694198133278336402500637269636726169887842595798606445909367058576435277640625000000000000",
+            "-700265800610377949731030701279743787907896705372015247301698864378963568331565476375000000000000",
+            "3160150517834696901784153329054103916683908447320628422609243420587305378116705388187500000000",
+            "-3711837295929728841959711983585876317003061224025827146858434575359795370054804675000000000",
+            "1247257156019654977752984724237035223986874495851369721538788760906203681342078134750000",
+            "-4750778730825177725463920948909726618214491718039471628856160047142000",
+        ],
+    ),
+    (
+        -2611,
+        &[
+            "1267190080011558249306954319775563369076412717376567619970524325704810905397382904600998793392127573141591069856511295488",
+            "-4318659117989107458781452977153748766802574545307888190668851551904116166611533823438546101561997522118909583282929664",
+            "10015138824440559773461469863520185108615885298718619382178631368933079327251317932410772292017579963465681921376256",
+            "-2090628219425683945718389585035343275042269941492169572855949745750126667299999164390204819969457408413980950528",
+            "365683968820256812607652768500018049338770188755076883113164008359539651217449388381557971287322807561617408",
+            "40140910161893968804559952319021529042417580813518843970866600150516263160697693651722687301550080",
+            "282669548830199342100998738488921841069290120815275026807551839938311136982631186432",
+            "5209884698349228558037974557708340212707727669564789775363960620974080",
+        ],
+    ),
+    (
+        -2632,
+        &[
+            "53820732377877338148316943692651569819153474902347740128879965869894547499800384306371138820186231913054208000000000000000000000000000",
+            "-36098023394114637436303306545736328277088938130810913604684366758762456072470482871859544638778985138989563904000000000000000000000",
+            "-1760492392388778065071233205185563223990357154860891352332466793585091656922573887309695343383101884580495360000000000000000000",
+            "3842417232610001534693566987841681575858761059551486031920045659123627407918831739293754701283732406711615488000000000000000",
+            "956195387965773050147636135153278804904017397786080921386196041102905188781864882898815422816708595089170432000000000000",
+            "-9873395305753088718613001054008056995708079465869897421124339080536957127460539622532758566670869303029760000000000",
+            "988425231479083864224965006969741386094856862472165666799770884364687956750804523585983866768099072000000",
+            "-9922685244115451547818924178705378299025706797775657545773063137344000",
+        ],
+    ),
+    (
+        -2667,
+        &[
+            "132853645189106925590651674244422952619136322653264600262900146813791586870089913950433053769728000000000000000000000000000",
+            "-36915998305913348080516190524465710759024533172587953334344019233154504159946981161531085636501504000000000000000000000",
+            "-50672055143637586604261550419961185826970305499365656255306387862085743383958351676925646250967040000000000000000000",
+            "16031882193096882659048592543592625723375959971750567032165592717889757577561592855392850192367616000000000000000",
+            "867799740256509731744043409909017837755668812216542626087679411895750921380022161819032514199552000000000000",
+            "103065289038289033950058830825973135115666391151873677337373599890516350914213195925080680431616000000000",
+            "8857824947997827921656062347224625691626615963181851796308491888581505195222682837188608000000",
+            "28873226649132904235936949268937601124588504994427408999245168050176000",
+        ],
+    ),
+    (
+        -2683,
+        &[
+            "401582160082250340418163965666567927009157113311106944233010240700809216000000000000000000",
+            "-302940399280383744660448145173115256445404252345773292018673267122565545984000000000000",
+            "332217934831843316166822577187571931306604468831007814954178159155441827840000000000",
+            "-239604677226145548257568705238807644964803277307165530139680701834854400000000",
+            "46939054890138039541090570578284916766453800683280728875816043274240000",
+        ],
+    ),
+    (
+        -2707,
+        &[
+            "344679577759157623410052688683047786263673857136776402640790931244718124187157794914304000000000000000000000000",
+            "2263108422446549614904503158803251283557837382503587318851149509467007723366390989586432000000000000000000",
+            "1553459658945527108673745399258547941749413502031092951444662400699623057250523639971840000000000000000",
+            "1289019821999213611240033363624395444533643590543012552285552427832398827374069678080000000000000",
+            "18573411615864569013390282240349149185865143360604525964754566944255160714820321280000000000",
+            "-597448741247585908022756193255787911076007742625524167613264269596296216576000000",
+            "97032338859143352337572722352757096906871457834580061186819637411840000",
+        ],
+    ),
+    (
+        -2715,
+        &[
+            "457992466514370401773976555377631547994834840944162175059940599417674247438957041907116509940121109138552475756716202504598061056000000000000",
+            "193973150930763702952768368946962948577132075091072905398381715517973919685906339224171360643550515665944279719545222957577011200000000000",
+            "116353283630664257956336007259255383486748720052527892186732828891887869347253771018202333571003595866197131584506456233213952000000000",
+            "2085665162499582476060144383984191086495142125310601233830441218919727661146139260970314640105482393583369990058820816076800000000",
+            "-262424108946631502975221961073434112998793530315896982425496258918808673102614441920124898130853333218445162971136000000",
+            "10168481381418671848398686488583460535598204088650951988764368156572948870870788530984240850111880914534400000",
+            "61514471165383002759676115958129819734650496205915473722605622949029982739250346370350972928000",
+            "123518773600274895445895334798358510556324806539319013676721095214694400",
+        ],
+    ),
+    (
+        -2755,
+        &[
+            "29337028460725238204645944620552999306908548616246029279937886373377190000212972315108374432710656000000000000",
+            "11611058265581699601005176930523733131745596277763670932694382544142282885473137041478657144520704000000000000",
+            "3233088730923734679340576917470263237807045359375755009403099064984281604641604137287865606340608000000000",
+            "231364823053982306837362960050053192282121293513521056984287826162974913102373222137357926400000000000",
+            "7552252618133178954499034484805048203090503181406609174588372192928106112834753239139221504000000",
+            "-51824271900003957877105405891933146097485719054095285233737613748126556684714778820608000000",
+            "86340983162829461578532005070353653044059291445937458331686057563344398577473421312000",
+            "410690029169042864116576927553320643969807364252042619871510569230336000",
+        ],
+    ),
+    (
+        -2787,
+        &[
+            "15884985100445268357953590178542974707070315641086015871581915377130411370815316912360128512000000000000000000000",
+            "-5235644766151628118258097105317029735516395462631368018318018234267002950529128519059000786944000000000000000",
+            "784947499401654518044866033516003257154294406573276984526494194216920292229127924374648651776000000000000",
+            "-49440523129472948316992499986015196039816359049791059405468009768469693387316640185053085696000000000",
+            "1090494707028478138412734767068970391852526831578865754532271533947768540600626516003389440000000",
+            "1067130840769367449760459178094245062365277165359712626201706039537664000",
+        ],
+    ),
+    (
+        -2788,
+        &[
+            "-95033528128325823466692300216342476916164510511021961424473553690443577307008152935948043561083806416896000000000000000000000000",
+            "2580313639244323216567765502410660048357072940711904615726812223970348255506498659617279951444806324977664000000000000000000000",
+            "-2445586198163749201664648734137280358048815940800896869381776663766427547940189563573618049071594186211328000000000000000000",
+            "1007779386783615980887092344562982854987566845018023123785745887911944061683281038999225316789335707353088000000000000000",
+            "-118506965758290048488998038336744984084645683656606142588761581564193214241454163816244295525711044648960000000000000",
+            "24661416777126829042727344814927026742011754909126771846307003311736018275555136823715896158780435984384000000000",
+            "-1152678081838915209636370046929334032897793935169661561827818139280312347114772111363565804392830339328000000",
+            "-1099356858301586342655228334060278223640823240379764725424646968295872000",
+        ],
+    ),
+    (
+        -2827,
+        &[
+            "1142902694166254015132760281525989350142483372752252752880122673516373189995510983810875392000000000000000000000000",
+            "-637671705266425882857672503375223170342401707332890412250696989269482434527854094605877248000000000000000000000",
+            "-28221784736158451293466692467367265058005113476965532758757541491228530357662679373447168000000000000000000",
+            "51925139710443212698210788080112446470350724697557916980818897977712965349938962989318144000000000000000",
+            "7335878227865280731656653701204067962954398472260666730708611419741488908286224559505408000000000000",
+            "1861128749133601316010340871533914531412548367669650901971345775971974168564666990592000000000",
+            "-145306591298512610681325452946855343576145910082131589019316355759966821285888000000",
+            "3493520759918244098016953009273004520746211344353624559576644034330624000",
+        ],
+    ),
+    (
+        -2832,
+        &[
+            "18853686997523252855577280860018429822810498523439520745567995742641480508374673891877850670344993426267465892098769746273000000000000000000000000",
+            "-21188015967268105465037535925215240968522924917071210749682938260360544296879616860330282555155389588975995482523090090344000000000000000000000",
+            "14621969471947567631102010863752370024338760494126016652966249698898782578130206663420040269828148928296683977696541198140000000000000000000",
+            "-12988024709888849471673626338613212426095852570797553604750450591120668682103832892370613233565431574786527291835873816000000000000000",
+            "10321269274768386743721564019370625681764905029054026149413770676484639146166832187515140572228251598021337062000000000000",
+            "-9155800438033314285181123284632923017065986326992111050470671396818109846337007141262733879034953568447320000000000",
+            "6454311439611798882740756822642937697847331864033419828163749509705718576685303085717619196000000",
+            "-4049367083884525204793930303407474429670455227162141572380663292609448000",
+        ],
+    ),
+    (
+        -2907,
+        &[
+            "7904590755258866831216050261975484958041200734856541862991343081089556021248000000000000000000000000",
+            "-57062528187698528717826729046058714643411327064175231905443395863804712583168000000000000000000000",
+            "351002424801906576951441270724560103230958904973076758844459327419530671554560000000000000000000",
+            "-812193537216648271731837031306317880019670450139952477061366811453428909211648000000000000000",
+            "579335696795610966036372945555437461936614927648303289406392287150893099384832000000000000",
+            "141930200719247305975933870324006377578763260768823666224790239265944879759360000000000",
+            "5447862748032282438093508948468713523855969423164050730365634000177630019584000000",
+            "36520541840717218739713922029878153622635975856250791719838370217107456000",
+        ],
+    ),
+    (
+        -2923,
+        &[
+            "-107647076004054618899149885041577023508926704841725337605266619477543174918897664000000000000000000",
+            "774363313119818364121689883356346187869427427850739895784313136065600552976777216000000000000000",
+            "-66955496044443649365534208670381393264833976438822919881785746085233630904320000000000000000",
+            "1505139645470342989281445816047836400962568733755304379307218827715771646345216000000000",
+            "-86792263080787259059639314792679999399343245671650982657822521515082514432000000",
+            "58170379961665933240578203738471180381102882882017892163204624801890304000",
+        ],
+    ),
+    (
+        -2947,
+        &[
+            "1823046542509512347074622140405229478402786701625600080374731837413972894037076992986185728000000000000000000000000000",
+            "237819702408901774823392025680542403746122528219782093160770409141734975865362123780370989056000000000000000000000",
+            "32995203827203443524392217671459913138035066690472042803655165815638861820489578644810760192000000000000000000",
+            "103940107979046137960481865323712897085595046003779827265033143129250956085774411852939264000000000000000",
+            "90361381469771317689891291235179048727871953390250128329094022754544088865816786763776000000000000",
+            "-42223248731920646665358126020220243779462983332871470345180994525218068503145218048000000000",
+            "4444201883771603308885564596191548426812105572664273460429198981352318715297792000000",
+            "116657940220351621885787823318695896864056416228423819999236238980718592000",
+        ],
+    ),
+    (
+        -2968,
+        &[
+            "2409826590352270725428367041942118560420119833085177642681185523736862334970542288754879152549120657243966947087523774464000000000000000000000000",
+            "-5249686142219647641666395344633757746600995350999824913913889754157605363068399553413219697971920705706630690765094780928000000000000000000000",
+            "7247236508030807855232821812136162668735241970251165993781216253090370930658565214152392824993065936154084242334388584448000000000000000000",
+            "-14560555093340727980451956414353157069386700999155940253792384164982636909102821878157400131489742146447920088605261824000000000000000",
+            "-74746635693113342445529283149756496736168556502003182613946513311433805294328194313315363125887330807133633364336640000000000000",
+            "-130055176529374289825498482339674839020267113508102921106028422873808306400744780534617702839257655450588236443648000000000",
+            "3129776498013494163163435976303188377202118345986464310758665706092081193792081956748971967843452920612608000000",
+            "-213964743132773346609134375855339942007069520484176454243133315522606144000",
+        ],
+    ),
+    (
+        -2995,
+        &[
+            "130586054255350151678923728510835519043092543367050494770499219207725717783205622438150620861455636268259429318656000000000000",
+            "19741714183768626420408508463185580917291330366960270184671818109013398612604530682220079383229541606322339840000000000000",
+            "62261160619733084653510543652069947321464025901570867907390134159681572651693808383569651618614934566137233408000000000",
+            "2536085998246704017092327420180722480102040828976549141405492478675250876265297659983506591063273637937152000000000",
+            "860643789562852392049733722498668232423613162100836239809079916628868666914669702265015434417316722376704000000",
+            "-33399522499983363473971763499921939456212993541268985162786320072223592895161971327444075937792000000",
+            "399173066342897596847949966472129734002535372580643163421705385082112044454557895360512000",
+            "465232073298926046304225798218910717565066583254291706189245421239943168000",
+        ],
+    ),
+];
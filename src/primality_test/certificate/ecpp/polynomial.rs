@@ -0,0 +1,137 @@
+use crate::modular;
+use bnum::types::U512;
+use rand::Rng;
+
+/// Each attempt splits off a factor with probability at least one half.
+const SPLIT_ATTEMPTS: usize = 100;
+
+/// Returns a root of the monic polynomial with the given coefficients of `x^0, x^1, ...`
+/// modulo the prime `p`, or `None` if there is none.
+///
+/// The Cantor–Zassenhaus approach first keeps the product of the linear factors,
+/// `gcd(x^p - x, f)`, and then splits it with `gcd((x + δ)^((p-1)/2) - 1, g)` for random `δ`
+/// until a linear factor is left. If `p` is composite, this may fail with `None`.
+pub fn find_root(f: &[U512], p: &U512) -> Option<U512> {
+    let x = vec![U512::ZERO, U512::ONE];
+    let x_to_p = pow_mod(&x, p, f, p)?;
+    let mut g = gcd(f.to_vec(), sub(&x_to_p, &x, p), p)?;
+    if g.len() < 2 {
+        return None;
+    }
+    let exponent = (p - U512::ONE) >> 1;
+    let mut rng = rand::thread_rng();
+    for _ in 0..SPLIT_ATTEMPTS {
+        if g.len() == 2 {
+            return Some(modular::sub_mod(&U512::ZERO, &g[0], p));
+        }
+        let shifted = vec![rng.gen_range(U512::ZERO..*p), U512::ONE];
+        let power = pow_mod(&shifted, &exponent, &g, p)?;
+        let h = gcd(g.clone(), sub(&power, &[U512::ONE], p), p)?;
+        if h.len() >= 2 && h.len() < g.len() {
+            g = h;
+        }
+    }
+    None
+}
+
+fn trim(mut a: Vec<U512>) -> Vec<U512> {
+    while a.last() == Some(&U512::ZERO) {
+        a.pop();
+    }
+    a
+}
+
+fn sub(a: &[U512], b: &[U512], p: &U512) -> Vec<U512> {
+    let len = a.len().max(b.len());
+    let coefficient = |c: &[U512], i: usize| c.get(i).copied().unwrap_or(U512::ZERO);
+    trim(
+        (0..len)
+            .map(|i| modular::sub_mod(&coefficient(a, i), &coefficient(b, i), p))
+            .collect(),
+    )
+}
+
+fn mul(a: &[U512], b: &[U512], p: &U512) -> Vec<U512> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut product = vec![U512::ZERO; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] = modular::add_mod(&product[i + j], &modular::mul_mod(x, y, p), p);
+        }
+    }
+    trim(product)
+}
+
+/// Returns `a mod b`, or `None` if the leading coefficient of `b` is not invertible.
+fn rem(a: Vec<U512>, b: &[U512], p: &U512) -> Option<Vec<U512>> {
+    let mut a = trim(a);
+    let lead_inverse = modular::inverse(b.last()?, p)?;
+    while a.len() >= b.len() {
+        let shift = a.len() - b.len();
+        let factor = modular::mul_mod(a.last().unwrap(), &lead_inverse, p);
+        for (i, c) in b.iter().enumerate() {
+            let term = modular::mul_mod(&factor, c, p);
+            a[shift + i] = modular::sub_mod(&a[shift + i], &term, p);
+        }
+        a = trim(a);
+    }
+    Some(a)
+}
+
+/// Returns the monic greatest common divisor of `a` and `b`.
+fn gcd(mut a: Vec<U512>, mut b: Vec<U512>, p: &U512) -> Option<Vec<U512>> {
+    a = trim(a);
+    b = trim(b);
+    while !b.is_empty() {
+        let r = rem(a, &b, p)?;
+        (a, b) = (b, r);
+    }
+    let lead_inverse = modular::inverse(a.last()?, p)?;
+    Some(
+        a.iter()
+            .map(|c| modular::mul_mod(c, &lead_inverse, p))
+            .collect(),
+    )
+}
+
+fn pow_mod(base: &[U512], exp: &U512, f: &[U512], p: &U512) -> Option<Vec<U512>> {
+    let base = rem(base.to_vec(), f, p)?;
+    let mut result = vec![U512::ONE];
+    for bit in (0..exp.bits()).rev() {
+        result = rem(mul(&result, &result, p), f, p)?;
+        if exp.bit(bit) {
+            result = rem(mul(&result, &base, p), f, p)?;
+        }
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roots_of_products_of_linear_factors() {
+        let p = U512::from(1000000007u64);
+        // (x - 2)(x - 3)(x - 5) and (x² + 1)(x - 7), where x² + 1 has no roots since
+        // p = 3 mod 4.
+        let f = [-30i64, 31, -10, 1].map(|c| residue(c, &p));
+        let root = find_root(&f, &p).unwrap();
+        assert!([2u8, 3, 5].map(U512::from).contains(&root));
+        let g = [-7i64, 1, -7, 1].map(|c| residue(c, &p));
+        assert_eq!(find_root(&g, &p), Some(U512::from(7u8)));
+        let h = [1i64, 0, 1].map(|c| residue(c, &p));
+        assert_eq!(find_root(&h, &p), None);
+    }
+
+    fn residue(c: i64, p: &U512) -> U512 {
+        let magnitude = U512::from(c.unsigned_abs());
+        if c < 0 {
+            p - magnitude
+        } else {
+            magnitude
+        }
+    }
+}
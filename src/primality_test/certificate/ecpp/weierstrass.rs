@@ -0,0 +1,139 @@
+use crate::modular;
+use bnum::types::U512;
+use num_integer::Integer;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Point {
+    Infinity,
+    Affine(U512, U512),
+}
+
+/// The curve `y² = x³ + ax + b` over `Z/nZ` in affine coordinates.
+///
+/// Every operation either gives the correct result modulo each prime factor of `n` or fails
+/// with `None`, which is what makes the arithmetic usable for proving that `n` is prime.
+/// In particular, an addition fails when the points agree modulo some prime factors of `n`
+/// but not others, and when a denominator is not invertible modulo `n`.
+pub struct WeierstrassCurve {
+    n: U512,
+    a: U512,
+    b: U512,
+}
+
+impl WeierstrassCurve {
+    pub fn new(n: &U512, a: &U512, b: &U512) -> Self {
+        Self {
+            n: *n,
+            a: a % n,
+            b: b % n,
+        }
+    }
+
+    /// Returns whether `4a³ + 27b²` is invertible modulo `n`, so that the curve is
+    /// non-singular modulo every prime factor of `n`.
+    pub fn is_nonsingular(&self) -> bool {
+        let a_cubed = self.mul(&self.mul(&self.a, &self.a), &self.a);
+        let b_squared = self.mul(&self.b, &self.b);
+        let discriminant = self.add_mod(
+            &self.mul(&U512::FOUR, &a_cubed),
+            &self.mul(&U512::from(27u8), &b_squared),
+        );
+        discriminant.gcd(&self.n) == U512::ONE
+    }
+
+    /// Returns `x³ + ax + b`.
+    pub fn rhs(&self, x: &U512) -> U512 {
+        let x_cubed = self.mul(&self.mul(x, x), x);
+        self.add_mod(&self.add_mod(&x_cubed, &self.mul(&self.a, x)), &self.b)
+    }
+
+    pub fn contains(&self, x: &U512, y: &U512) -> bool {
+        x < &self.n && y < &self.n && self.mul(y, y) == self.rhs(x)
+    }
+
+    pub fn add(&self, p: &Point, q: &Point) -> Option<Point> {
+        let (Point::Affine(x1, y1), Point::Affine(x2, y2)) = (p, q) else {
+            return Some(if *p == Point::Infinity { *q } else { *p });
+        };
+        let slope = if x1 == x2 {
+            if self.add_mod(y1, y2).is_zero() {
+                return Some(Point::Infinity);
+            }
+            if y1 != y2 {
+                return None;
+            }
+            let numerator = self.add_mod(&self.mul(&U512::THREE, &self.mul(x1, x1)), &self.a);
+            self.mul(
+                &numerator,
+                &modular::inverse(&self.add_mod(y1, y1), &self.n)?,
+            )
+        } else {
+            let denominator = modular::sub_mod(x2, x1, &self.n);
+            self.mul(
+                &modular::sub_mod(y2, y1, &self.n),
+                &modular::inverse(&denominator, &self.n)?,
+            )
+        };
+        let x3 = modular::sub_mod(&self.mul(&slope, &slope), &self.add_mod(x1, x2), &self.n);
+        let y3 = modular::sub_mod(
+            &self.mul(&slope, &modular::sub_mod(x1, &x3, &self.n)),
+            y1,
+            &self.n,
+        );
+        Some(Point::Affine(x3, y3))
+    }
+
+    /// Computes `[k]p` by doubling and adding.
+    pub fn multiply(&self, p: &Point, k: &U512) -> Option<Point> {
+        let mut result = Point::Infinity;
+        for bit in (0..k.bits()).rev() {
+            result = self.add(&result, &result)?;
+            if k.bit(bit) {
+                result = self.add(&result, p)?;
+            }
+        }
+        Some(result)
+    }
+
+    fn mul(&self, a: &U512, b: &U512) -> U512 {
+        modular::mul_mod(a, b, &self.n)
+    }
+
+    fn add_mod(&self, a: &U512, b: &U512) -> U512 {
+        modular::add_mod(a, b, &self.n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_law() {
+        // y² = x³ + 2x + 3 over F_97 has 100 points, and (3, 6) is one of them.
+        let n = U512::from(97u8);
+        let curve = WeierstrassCurve::new(&n, &U512::TWO, &U512::THREE);
+        assert!(curve.is_nonsingular());
+        let p = Point::Affine(U512::THREE, U512::SIX);
+        let sum = curve.add(&p, &p).unwrap();
+        assert_eq!(curve.multiply(&p, &U512::TWO), Some(sum));
+        let Point::Affine(x, y) = curve.multiply(&p, &U512::from(37u8)).unwrap() else {
+            panic!("(3, 6) should not have order dividing 37");
+        };
+        assert!(curve.contains(&x, &y));
+        assert_eq!(
+            curve.multiply(&p, &U512::from(100u8)),
+            Some(Point::Infinity)
+        );
+    }
+
+    #[test]
+    fn fails_on_points_that_only_agree_modulo_a_factor() {
+        // Modulo 7 × 11, (1, 3) and (1, 3 + 11 × 2 × 2) agree modulo 11 but not modulo 7.
+        let n = U512::from(77u8);
+        let curve = WeierstrassCurve::new(&n, &U512::ZERO, &U512::from(8u8));
+        let p = Point::Affine(U512::ONE, U512::THREE);
+        let q = Point::Affine(U512::ONE, U512::from(47u8));
+        assert_eq!(curve.add(&p, &q), None);
+    }
+}
@@ -0,0 +1,226 @@
+use super::{PrimalityCertificate, ProvenFactor, WitnessedFactor};
+use crate::algorithms::{EllipticCurveMethod, PollardsRhoBrent, TrialDivision};
+use crate::modular;
+use crate::primality_test::BailliePsw;
+use crate::traits::{Factorize, PrimalityTest};
+use crate::Factorization;
+use bnum::types::U512;
+use num_integer::{Integer, Roots};
+
+/// Pratt certificates need the complete factorization of `n - 1`, which is only attempted
+/// up to this size.
+const PRATT_MAX_BITS: u32 = 64;
+const TRIAL_DIVISION_BOUND: u64 = 1 << 16;
+const SPLIT_ATTEMPTS: usize = 4;
+const WITNESS_SEARCH_LIMIT: u64 = 10_000;
+
+/// The limited effort spent on cofactors of `n - 1` that are too large for rho.
+type CofactorEcm = EllipticCurveMethod<2_000, 100_000, 20>;
+
+/// Proves a probable prime `n > 2` with a Pratt certificate if `n - 1` can be factored
+/// completely and `n` is small, and with a Pocklington certificate otherwise.
+pub(super) fn prove(n: &U512) -> Option<PrimalityCertificate> {
+    let n_minus_1 = n - U512::ONE;
+    let (prime_powers, rest) = factor_partially(&n_minus_1);
+    if rest == U512::ONE && n.bits() <= PRATT_MAX_BITS {
+        let factors = prime_powers
+            .iter()
+            .map(|&(q, exponent)| {
+                let certificate = PrimalityCertificate::prove(&q)?;
+                Some(ProvenFactor {
+                    exponent,
+                    certificate,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let witness = find_pratt_witness(n, &factors)?;
+        return Some(PrimalityCertificate::Pratt {
+            n: *n,
+            witness,
+            factors,
+        });
+    }
+    // Small primes are the cheapest to prove, so they are used first and larger ones only
+    // when needed. A prime that cannot be proven or has no witness is left out of the
+    // factored part, which only has to reach the cube root of `n`.
+    let mut factored_part = U512::ONE;
+    let mut factors = vec![];
+    for (q, exponent) in prime_powers {
+        if is_at_least_cube_root(&factored_part, n) {
+            break;
+        }
+        let Some(certificate) = PrimalityCertificate::prove(&q) else {
+            continue;
+        };
+        let Some(witness) = find_pocklington_witness(n, &q) else {
+            continue;
+        };
+        factored_part *= q.pow(exponent);
+        factors.push(WitnessedFactor {
+            factor: ProvenFactor {
+                exponent,
+                certificate,
+            },
+            witness,
+        });
+    }
+    is_prime_given_factored_part(n, &factored_part)
+        .then_some(PrimalityCertificate::Pocklington { n: *n, factors })
+}
+
+/// Checks a Pratt certificate apart from the certificates of the factors.
+pub(super) fn verify_pratt(n: &U512, witness: &U512, factors: &[ProvenFactor]) -> bool {
+    if *n < U512::THREE || n.is_even() || product(factors.iter()) != Some(n - U512::ONE) {
+        return false;
+    }
+    let n_minus_1 = n - U512::ONE;
+    modular::pow_mod(witness, &n_minus_1, n) == U512::ONE
+        && factors
+            .iter()
+            .all(|f| modular::pow_mod(witness, &(n_minus_1 / f.prime()), n) != U512::ONE)
+}
+
+/// Checks a Pocklington certificate apart from the certificates of the factors.
+pub(super) fn verify_pocklington(n: &U512, factors: &[WitnessedFactor]) -> bool {
+    if *n < U512::THREE || n.is_even() {
+        return false;
+    }
+    let n_minus_1 = n - U512::ONE;
+    let Some(factored_part) = product(factors.iter().map(|f| &f.factor)) else {
+        return false;
+    };
+    n_minus_1.is_multiple_of(&factored_part)
+        && factors
+            .iter()
+            .all(|f| satisfies_pocklington(n, f.factor.prime(), &f.witness))
+        && is_prime_given_factored_part(n, &factored_part)
+}
+
+/// Returns the product of the prime powers, or `None` if it overflows.
+pub(super) fn product<'a>(mut factors: impl Iterator<Item = &'a ProvenFactor>) -> Option<U512> {
+    factors.try_fold(U512::ONE, |acc, f| {
+        acc.checked_mul(f.prime().checked_pow(f.exponent)?)
+    })
+}
+
+pub(super) fn format_product<'a>(
+    number: &U512,
+    factors: impl Iterator<Item = &'a ProvenFactor>,
+) -> String {
+    let primes = factors
+        .flat_map(|f| std::iter::repeat_n(*f.prime(), f.exponent as usize))
+        .collect();
+    Factorization::new(number, primes).to_string()
+}
+
+/// Returns whether `a^(n-1) = 1` and `gcd(a^((n-1)/q) - 1, n) = 1`.
+fn satisfies_pocklington(n: &U512, q: &U512, a: &U512) -> bool {
+    let n_minus_1 = n - U512::ONE;
+    let partial_power = modular::pow_mod(a, &(n_minus_1 / q), n);
+    modular::pow_mod(&partial_power, q, n) == U512::ONE
+        && modular::sub_mod(&partial_power, &U512::ONE, n).gcd(n) == U512::ONE
+}
+
+/// Decides whether `n` is prime, given that every prime factor of `n` is `1 mod F` for the
+/// factored part `F`. If `F >= sqrt(n)`, `n` has no prime factor up to its square root.
+/// Otherwise, if `F³ >= n`, Brillhart, Lehmer and Selfridge showed that with the base `F`
+/// digits `n = c₂F² + c₁F + 1`, `n` is prime if and only if `c₁² - 4c₂` is not a square.
+fn is_prime_given_factored_part(n: &U512, factored_part: &U512) -> bool {
+    if *factored_part >= n.sqrt() {
+        return true;
+    }
+    if !is_at_least_cube_root(factored_part, n) {
+        return false;
+    }
+    let (c2, c1) = ((n - U512::ONE) / factored_part).div_rem(factored_part);
+    let (c1_squared, four_c2) = (c1 * c1, c2 * U512::FOUR);
+    if c1_squared < four_c2 {
+        return true;
+    }
+    let discriminant = c1_squared - four_c2;
+    let root = discriminant.sqrt();
+    root * root != discriminant
+}
+
+fn is_at_least_cube_root(f: &U512, n: &U512) -> bool {
+    let root = n.cbrt();
+    *f > root || (*f == root && root * root * root == *n)
+}
+
+/// Looks for a witness whose order modulo `n` is `n - 1`. Returns `None` if one shows that
+/// `n` is composite, or if none is found.
+fn find_pratt_witness(n: &U512, factors: &[ProvenFactor]) -> Option<U512> {
+    let n_minus_1 = n - U512::ONE;
+    for a in (2..WITNESS_SEARCH_LIMIT).map(U512::from) {
+        if modular::pow_mod(&a, &n_minus_1, n) != U512::ONE {
+            return None;
+        }
+        if factors
+            .iter()
+            .all(|f| modular::pow_mod(&a, &(n_minus_1 / f.prime()), n) != U512::ONE)
+        {
+            return Some(a);
+        }
+    }
+    None
+}
+
+/// Looks for a Pocklington witness for the prime `q | n - 1`. Returns `None` if one shows
+/// that `n` is composite, or if none is found.
+fn find_pocklington_witness(n: &U512, q: &U512) -> Option<U512> {
+    let n_minus_1 = n - U512::ONE;
+    for a in (2..WITNESS_SEARCH_LIMIT).map(U512::from) {
+        let partial_power = modular::pow_mod(&a, &(n_minus_1 / q), n);
+        if modular::pow_mod(&partial_power, q, n) != U512::ONE {
+            return None;
+        }
+        match modular::sub_mod(&partial_power, &U512::ONE, n).gcd(n) {
+            d if d == U512::ONE => return Some(a),
+            d if d == *n => continue,
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// Factors `m` as far as a limited effort allows, returning the probable prime factors with
+/// their exponents in increasing order, and the unfactored rest.
+fn factor_partially(m: &U512) -> (Vec<(U512, u32)>, U512) {
+    let (mut primes, cofactor) = TrialDivision::strip_small_factors(m, TRIAL_DIVISION_BOUND);
+    let mut rest = U512::ONE;
+    let mut pending = vec![cofactor];
+    while let Some(part) = pending.pop() {
+        if part == U512::ONE {
+            continue;
+        }
+        if BailliePsw::is_prime(&part) {
+            primes.push(part);
+            continue;
+        }
+        match split(&part) {
+            Some(d) => pending.extend([d, part / d]),
+            None => rest *= part,
+        }
+    }
+    primes.sort();
+    let mut prime_powers: Vec<(U512, u32)> = vec![];
+    for p in primes {
+        match prime_powers.last_mut() {
+            Some((q, exponent)) if *q == p => *exponent += 1,
+            _ => prime_powers.push((p, 1)),
+        }
+    }
+    (prime_powers, rest)
+}
+
+fn split(n: &U512) -> Option<U512> {
+    (0..SPLIT_ATTEMPTS)
+        .map(|_| {
+            if n.bits() <= 64 {
+                PollardsRhoBrent::factorize(n)
+            } else {
+                CofactorEcm::factorize(n)
+            }
+        })
+        .find(|d| *d != U512::ONE && d != n)
+}
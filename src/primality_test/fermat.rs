@@ -0,0 +1,94 @@
+use super::miller_rabin::utils;
+use crate::modular::MontgomeryContext;
+use crate::traits::{CompositeWitness, PrimalityTest};
+use crate::word::{self, Word};
+use bnum::types::U512;
+use num_integer::Integer;
+use rustic_factors_derive::PrimalityCommand;
+
+/// The Fermat test, which runs `ROUNDS` rounds with random bases `a` and checks Fermat's
+/// little theorem `a^(n-1) = 1 mod n`.
+///
+/// Carmichael numbers satisfy the condition for every base coprime to them and are only
+/// caught by a base that shares a factor with them, which is why
+/// [`MillerRabin`](super::MillerRabin) strengthens the condition.
+#[derive(Default, PrimalityCommand)]
+#[primality_command(witness)]
+pub struct FermatTest<const ROUNDS: usize = 50>;
+
+impl<const ROUNDS: usize> PrimalityTest for FermatTest<ROUNDS> {
+    fn is_prime(p: &U512) -> bool {
+        word::is_prime_narrowest::<Self>(p)
+    }
+
+    fn is_prime_word<W: Word>(p: W) -> bool {
+        if p == W::two() || p == W::from(3u8) {
+            return true;
+        }
+        if p < W::two() || p.is_even() {
+            return false;
+        }
+        Self::find_witness(p).is_none()
+    }
+}
+
+impl<const ROUNDS: usize> CompositeWitness for FermatTest<ROUNDS> {
+    fn composite_witness(n: &U512) -> Option<U512> {
+        if *n <= U512::THREE || n.is_even() {
+            return None;
+        }
+        Self::find_witness(*n)
+    }
+}
+
+impl<const ROUNDS: usize> FermatTest<ROUNDS> {
+    /// Expects an odd `p > 3`.
+    fn find_witness<W: Word>(p: W) -> Option<W> {
+        let context = MontgomeryContext::new(p);
+        utils::RandomIntegers::new(W::two()..p - W::one())
+            .take(ROUNDS)
+            .find(|&base| !is_fermat_probable_prime(&context, base))
+    }
+}
+
+fn is_fermat_probable_prime<W: Word>(context: &MontgomeryContext<W>, base: W) -> bool {
+    let n_minus_1 = context.modulus() - W::one();
+    context.pow(context.to_montgomery(base), n_minus_1) == context.one()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_framework::pseudoprimes;
+
+    #[test]
+    fn agrees_with_sieve() {
+        let primes = crate::primes::sieve_of_eratosthenes(5_000);
+        for n in 0..5_000u64 {
+            let expected = primes.binary_search(&n).is_ok();
+            assert_eq!(<FermatTest>::is_prime(&U512::from(n)), expected, "{n}");
+        }
+    }
+
+    #[test]
+    fn carmichael_numbers_pass_every_coprime_base() {
+        for carmichael in pseudoprimes::CARMICHAEL_NUMBERS {
+            let context = MontgomeryContext::new(carmichael);
+            let bases = [2u64, 4, 13, 23, 101].into_iter();
+            for base in bases.filter(|base| base.gcd(&carmichael) == 1) {
+                assert!(is_fermat_probable_prime(&context, base), "{carmichael}");
+            }
+        }
+    }
+
+    #[test]
+    fn random_bases_catch_pseudoprimes_to_base_2() {
+        for composite in [341u64, 645, 1387, 2701] {
+            assert!(is_fermat_probable_prime(
+                &MontgomeryContext::new(composite),
+                2
+            ));
+            assert!(!<FermatTest>::is_prime_word(composite), "{composite}");
+        }
+    }
+}
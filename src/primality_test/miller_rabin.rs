@@ -1,50 +1,97 @@
-mod composite_evidence;
-mod utils;
+pub(super) mod composite_evidence;
+pub(super) mod utils;
 
 use self::composite_evidence::CompositeEvidence;
-use crate::traits::{Command, PrimalityTest};
+use crate::traits::{CompositeWitness, PrimalityTest};
+use crate::word::{self, Word};
 use bnum::types::U512;
 use num_integer::Integer;
+use rustic_factors_derive::PrimalityCommand;
 
-pub struct MillerRabin;
+/// The Miller–Rabin test, which is deterministic below `3.3·10^24` and runs `ROUNDS` rounds
+/// with random witnesses above that.
+///
+/// A composite passes a round with a random witness with probability at most `1/4`.
+#[derive(Default, PrimalityCommand)]
+#[primality_command(witness)]
+pub struct MillerRabin<const ROUNDS: usize = 50>;
 
-impl Command for MillerRabin {
-    fn run(&self, n: &U512) -> String {
-        if MillerRabin::is_prime(n) {
-            format!("{} is prime", n)
-        } else {
-            format!("{} is composite", n)
+impl<const ROUNDS: usize> MillerRabin<ROUNDS> {
+    const WITNESSES: [u8; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+    /// Pairs of a bound and the number of leading [`Self::WITNESSES`] that suffice to prove
+    /// every prime below it, from Jaeschke and from Sorenson and Webster.
+    const DETERMINISTIC_BOUNDS: [(u128, usize); 10] = [
+        (2_047, 1),
+        (1_373_653, 2),
+        (25_326_001, 3),
+        (3_215_031_751, 4),
+        (2_152_302_898_747, 5),
+        (3_474_749_660_383, 6),
+        (341_550_071_728_321, 7),
+        (3_825_123_056_546_413_051, 9),
+        (318_665_857_834_031_151_167_461, 12),
+        (3_317_044_064_679_887_385_961_981, 13),
+    ];
+
+    /// Expects an odd `p > 3`.
+    fn find_witness<W: Word>(p: W) -> Option<W> {
+        let evidence = CompositeEvidence::new(p);
+        match Self::deterministic_witnesses(p) {
+            Some(witnesses) => witnesses
+                .iter()
+                .map(|&w| W::from(w))
+                .find(|w| evidence.witnessed_by(w)),
+            None => utils::RandomIntegers::new(W::two()..p - W::one())
+                .take(ROUNDS)
+                .find(|w| evidence.witnessed_by(w)),
         }
     }
+
+    fn deterministic_witnesses<W: Word>(p: W) -> Option<&'static [u8]> {
+        let p = u128::from_u512(&p.to_u512())?;
+        Self::DETERMINISTIC_BOUNDS
+            .iter()
+            .find(|&&(bound, _)| p < bound)
+            .map(|&(_, count)| &Self::WITNESSES[..count])
+    }
 }
 
-impl PrimalityTest for MillerRabin {
+impl<const ROUNDS: usize> PrimalityTest for MillerRabin<ROUNDS> {
     fn is_prime(p: &U512) -> bool {
-        if p == &U512::TWO || p == &U512::THREE {
+        word::is_prime_narrowest::<Self>(p)
+    }
+
+    fn is_prime_word<W: Word>(p: W) -> bool {
+        let three = W::from(3u8);
+        if p == W::two() || p == three {
             return true;
         }
-        if p < &U512::TWO || p.is_multiple_of(&U512::TWO) {
+        if p < W::two() || p.is_multiple_of(&W::two()) {
             return false;
         }
-        miller_rabin(p, 50)
+        Self::find_witness(p).is_none()
     }
 }
 
-fn miller_rabin(p: &U512, trials: usize) -> bool {
-    let evidence = CompositeEvidence::new(p);
-    let likely_prime = |witness| !evidence.witnessed_by(&witness);
-    utils::RandomIntegers::new(U512::TWO..p - U512::ONE)
-        .take(trials)
-        .all(likely_prime)
+impl<const ROUNDS: usize> CompositeWitness for MillerRabin<ROUNDS> {
+    fn composite_witness(n: &U512) -> Option<U512> {
+        if *n <= U512::THREE || n.is_even() {
+            return None;
+        }
+        Self::find_witness(*n)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_framework::pseudoprimes;
+    use crate::traits::Command;
 
     fn check(p: u32, expected: bool) {
         assert_eq!(
-            MillerRabin::is_prime(&U512::from(p)),
+            <MillerRabin>::is_prime(&U512::from(p)),
             expected,
             "Test failed for {}",
             p
@@ -66,6 +113,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn native_and_wide_words_agree() {
+        for n in (1u64..2000).chain(u64::MAX - 2000..u64::MAX) {
+            let expected = <MillerRabin>::is_prime_word(U512::from(n));
+            assert_eq!(<MillerRabin>::is_prime_word(n), expected, "{n}");
+            assert_eq!(<MillerRabin>::is_prime_word(n as u128), expected, "{n}");
+        }
+    }
+
+    #[test]
+    fn primes_near_512_bits() {
+        let primes = [
+            U512::MAX - U512::from(568u16),
+            U512::power_of_two(511) - U512::from(187u8),
+            U512::power_of_two(500) - U512::from(863u16),
+        ];
+        for prime in primes {
+            assert!(<MillerRabin>::is_prime(&prime), "Test failed for {}", prime);
+        }
+    }
+
+    #[test]
+    fn semiprime_near_512_bits() {
+        let p = U512::power_of_two(256) - U512::from(189u8);
+        let q = U512::power_of_two(255) + U512::from(12413u16);
+        assert!(!<MillerRabin>::is_prime(&(p * q)));
+    }
+
+    #[test]
+    fn strong_pseudoprimes_at_the_deterministic_bounds() {
+        // The smallest strong pseudoprimes to all of the first 1, 2, 3, 4, 5, 7 and 9 primes.
+        let composites: [u128; 7] = [
+            2047,
+            1373653,
+            25326001,
+            3215031751,
+            2152302898747,
+            341550071728321,
+            3825123056546413051,
+        ];
+        for composite in composites {
+            assert!(!<MillerRabin>::is_prime_word(composite), "{composite}");
+            assert!(
+                !<MillerRabin>::is_prime_word(U512::from(composite)),
+                "{composite}"
+            );
+        }
+    }
+
+    #[test]
+    fn deterministic_below_bounds() {
+        let p = 3317044064679887385961813u128;
+        assert_eq!(
+            <MillerRabin>::deterministic_witnesses(p).map(<[u8]>::len),
+            Some(13)
+        );
+        assert_eq!(
+            MillerRabin::<0>::deterministic_witnesses(p).map(<[u8]>::len),
+            Some(13)
+        );
+        assert!(MillerRabin::<0>::is_prime_word(p));
+        assert_eq!(<MillerRabin>::deterministic_witnesses(U512::MAX), None);
+    }
+
+    #[test]
+    fn configurable_rounds_above_bounds() {
+        let p = U512::power_of_two(127) - U512::ONE;
+        assert!(MillerRabin::<1>::is_prime(&p));
+        assert!(MillerRabin::<100>::is_prime(&p));
+        assert!(!MillerRabin::<1>::is_prime(&(p * p)));
+    }
+
     #[test]
     fn test_carmichael_numbers() {
         let carmichaels = [
@@ -77,4 +196,27 @@ mod tests {
             check(carmichael, false);
         }
     }
+
+    #[test]
+    fn shared_carmichael_numbers() {
+        for carmichael in pseudoprimes::CARMICHAEL_NUMBERS {
+            check(carmichael as u32, false);
+        }
+    }
+
+    #[test]
+    fn command_names_a_witness() {
+        let command = MillerRabin::<50>;
+        assert_eq!(
+            command.run(&U512::from(561u16)),
+            "561 is composite, witnessed by 2"
+        );
+        assert_eq!(command.run(&U512::from(1000u16)), "1000 is composite");
+        assert_eq!(command.run(&U512::from(1021u16)), "1021 is prime");
+        // 2047 is a strong pseudoprime to base 2 but not to base 3.
+        assert_eq!(
+            <MillerRabin>::composite_witness(&U512::from(2047u16)),
+            Some(U512::THREE)
+        );
+    }
 }
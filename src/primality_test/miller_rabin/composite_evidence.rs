@@ -1,81 +1,76 @@
 use super::utils;
-use bnum::types::U512;
-use num_integer::Integer;
-use num_traits::One;
+use crate::modular::MontgomeryContext;
+use crate::word::Word;
 
-pub struct CompositeEvidence<'a> {
-    n: &'a U512,
-    n_minus_1: Decomposed,
+/// Works on residues in Montgomery form throughout, so that the exponentiation needs no
+/// divisions.
+pub struct CompositeEvidence<W: Word> {
+    context: MontgomeryContext<W>,
+    minus_one: W,
+    n_minus_1: Decomposed<W>,
 }
 
-impl<'a> CompositeEvidence<'a> {
-    pub fn new(n: &'a U512) -> Self {
-        let n_minus_1 = Decomposed::new(n - U512::ONE);
-        Self { n, n_minus_1 }
+impl<W: Word> CompositeEvidence<W> {
+    pub fn new(n: W) -> Self {
+        let context = MontgomeryContext::new(n);
+        let minus_one = context.sub(W::zero(), context.one());
+        let n_minus_1 = Decomposed::new(n - W::one());
+        Self {
+            context,
+            minus_one,
+            n_minus_1,
+        }
     }
 
-    pub fn witnessed_by(&self, witness: &U512) -> bool {
+    pub fn witnessed_by(&self, witness: &W) -> bool {
         match self.raise_to_n_minus_1_mod_n(witness) {
-            Ok(result) => fails_fermats_condition(result),
+            Ok(result) => self.fails_fermats_condition(result),
             Err(FoundNonTrivialSqrtOf1) => true,
         }
     }
 
-    fn raise_to_n_minus_1_mod_n(&self, base: &U512) -> ExponentiationResult {
-        let odd_factor_in_exp = &self.n_minus_1.odd_factor;
-        let mut result = modpow(base, odd_factor_in_exp, self.n);
+    fn raise_to_n_minus_1_mod_n(&self, base: &W) -> ExponentiationResult<W> {
+        let odd_factor_in_exp = self.n_minus_1.odd_factor;
+        let base = self.context.to_montgomery(*base);
+        let mut result = self.context.pow(base, odd_factor_in_exp);
         for _ in 0..self.n_minus_1.exponent_of_2 {
             if self.is_nontrivial_sqrt_of_1(&result) {
                 return Err(FoundNonTrivialSqrtOf1);
             }
-            result = modpow(&result, &U512::TWO, self.n);
+            result = self.context.square(result);
         }
         Ok(RaisedToNMinus1ModN(result))
     }
 
-    pub fn is_nontrivial_sqrt_of_1(&self, solution: &U512) -> bool {
-        let squared = modpow(solution, &U512::TWO, self.n);
-        squared == U512::one()
-            && solution != &U512::ONE
-            && solution != &(self.n - U512::ONE)
+    /// Expects `solution` in Montgomery form.
+    pub fn is_nontrivial_sqrt_of_1(&self, solution: &W) -> bool {
+        let one = self.context.one();
+        let squared = self.context.square(*solution);
+        squared == one && solution != &one && solution != &self.minus_one
     }
-}
 
-fn modpow(base: &U512, exponent: &U512, modulus: &U512) -> U512 {
-    let mut result = U512::ONE;
-    let mut base = base % modulus;
-    let mut exp = *exponent;
-    while exp > U512::ZERO {
-        if exp.is_odd() {
-            result = result * base % modulus;
-        }
-        base = base * base % modulus;
-        exp /= U512::TWO;
+    fn fails_fermats_condition(&self, r: RaisedToNMinus1ModN<W>) -> bool {
+        r.0 != self.context.one()
     }
-    result
-}
-
-fn fails_fermats_condition(r: RaisedToNMinus1ModN) -> bool {
-    !r.0.is_one()
 }
 
-type ExponentiationResult = Result<RaisedToNMinus1ModN, FoundNonTrivialSqrtOf1>;
+type ExponentiationResult<W> = Result<RaisedToNMinus1ModN<W>, FoundNonTrivialSqrtOf1>;
 
-struct RaisedToNMinus1ModN(U512);
+struct RaisedToNMinus1ModN<W>(W);
 
 struct FoundNonTrivialSqrtOf1;
 
-struct Decomposed {
-    exponent_of_2: u32,
-    odd_factor: U512,
+pub struct Decomposed<W> {
+    pub exponent_of_2: u32,
+    pub odd_factor: W,
 }
 
-impl Decomposed {
+impl<W: Word> Decomposed<W> {
     /// Decomposes `number` into `exponent_of_2` and `odd_factor`,
     /// where `number = 2^exponent_of_2 * odd_factor`.
-    pub fn new(number: U512) -> Self {
+    pub fn new(number: W) -> Self {
         let exponent_of_2 = utils::highest_power_of_2_divisor(&number);
-        let odd_factor = number / U512::TWO.pow(exponent_of_2);
+        let odd_factor = (0..exponent_of_2).fold(number, |odd, _| odd / W::two());
         Self {
             exponent_of_2,
             odd_factor,
@@ -1,32 +1,31 @@
-use bnum::types::U512;
-use num_integer::Integer;
+use crate::word::Word;
 use rand::Rng;
 use std::ops::Range;
 
-pub struct RandomIntegers {
-    range: Range<U512>,
+pub struct RandomIntegers<W: Word> {
+    range: Range<W>,
 }
 
-impl RandomIntegers {
-    pub fn new(range: Range<U512>) -> Self {
+impl<W: Word> RandomIntegers<W> {
+    pub fn new(range: Range<W>) -> Self {
         Self { range }
     }
 }
 
-impl Iterator for RandomIntegers {
-    type Item = U512;
+impl<W: Word> Iterator for RandomIntegers<W> {
+    type Item = W;
 
     fn next(&mut self) -> Option<Self::Item> {
         Some(rand::thread_rng().gen_range(self.range.clone()))
     }
 }
 
-pub fn highest_power_of_2_divisor(base: &U512) -> u32 {
+pub fn highest_power_of_2_divisor<W: Word>(base: &W) -> u32 {
     let mut exp = 0;
     let mut base = *base;
     while base.is_even() {
         exp += 1;
-        base /= U512::TWO;
+        base = base / W::two();
     }
     exp
 }
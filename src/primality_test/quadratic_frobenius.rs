@@ -0,0 +1,94 @@
+use super::baillie_psw;
+use crate::lucas;
+use crate::modular::MontgomeryContext;
+use crate::traits::PrimalityTest;
+use crate::word::{self, Word};
+use bnum::types::U512;
+use rustic_factors_derive::PrimalityCommand;
+
+/// The Frobenius test of Crandall and Pomerance with respect to `x² - Px + Q`, which checks
+/// `x^(n+1) = Q` in `(Z/nZ)[x] / (x² - Px + Q)`, or equivalently `U_{n+1} = 0` and
+/// `V_{n+1} = 2Q mod n` for the Lucas sequences of `P` and `Q`.
+///
+/// The parameters are chosen with Selfridge's method A*, where the discriminant `D` satisfies
+/// `(D/n) = -1`, so that `x^n` is the conjugate of `x` for prime `n`. The test implies the
+/// Lucas test with the same parameters, and no composite below `10^6` passes it.
+#[derive(PrimalityCommand)]
+pub struct QuadraticFrobenius;
+
+impl PrimalityTest for QuadraticFrobenius {
+    fn is_prime(p: &U512) -> bool {
+        word::is_prime_narrowest::<Self>(p)
+    }
+
+    fn is_prime_word<W: Word>(p: W) -> bool {
+        if let Some(is_prime) = baillie_psw::check_small_primes(p) {
+            return is_prime;
+        }
+        let Some((p_parameter, q)) = frobenius_parameters(p) else {
+            return false;
+        };
+        if q.gcd(&p) != W::one() {
+            return false;
+        }
+        let context = MontgomeryContext::new(p);
+        let (p_parameter, q) = (context.to_montgomery(p_parameter), context.to_montgomery(q));
+        let (u, v, _) = lucas::lucas_sequences(&context, p_parameter, q, p + W::one());
+        u.is_zero() && v == context.add(q, q)
+    }
+}
+
+/// Selfridge's parameters, except that `P = Q = 5` replaces `P = 1, Q = -1` for `D = 5`, which
+/// composites such as 5777 and 10877 pass.
+fn frobenius_parameters<W: Word>(n: W) -> Option<(W, W)> {
+    let (p, q) = baillie_psw::selfridge_parameters(n)?;
+    if q == n - W::one() {
+        let five = W::from(5u8);
+        Some((five, five))
+    } else {
+        Some((p, q))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primality_test::BailliePsw;
+    use crate::test_framework::pseudoprimes;
+
+    #[test]
+    fn agrees_with_sieve() {
+        let primes = crate::primes::sieve_of_eratosthenes(1_000_000);
+        for n in 0..1_000_000u64 {
+            let expected = primes.binary_search(&n).is_ok();
+            assert_eq!(QuadraticFrobenius::is_prime_word(n), expected, "{n}");
+        }
+    }
+
+    #[test]
+    fn pseudoprimes_of_other_tests() {
+        let composites = pseudoprimes::CARMICHAEL_NUMBERS
+            .iter()
+            .chain(&pseudoprimes::EULER_JACOBI_PSEUDOPRIMES_TO_BASE_2)
+            .chain(&pseudoprimes::STRONG_PSEUDOPRIMES_TO_BASE_2)
+            .chain(&pseudoprimes::STRONG_LUCAS_PSEUDOPRIMES);
+        for &composite in composites {
+            assert!(!QuadraticFrobenius::is_prime_word(composite), "{composite}");
+        }
+    }
+
+    #[test]
+    fn agrees_with_baillie_psw() {
+        let start = u64::MAX - 20_000;
+        for n in (start..u64::MAX).step_by(2) {
+            assert_eq!(
+                QuadraticFrobenius::is_prime_word(n),
+                BailliePsw::is_prime_word(n),
+                "{n}"
+            );
+        }
+        let prime = U512::power_of_two(511) - U512::from(187u8);
+        assert!(QuadraticFrobenius::is_prime(&prime));
+        assert!(!QuadraticFrobenius::is_prime(&(prime - U512::TWO)));
+    }
+}
@@ -0,0 +1,103 @@
+use super::miller_rabin::utils;
+use crate::modular::{self, MontgomeryContext};
+use crate::traits::{CompositeWitness, PrimalityTest};
+use crate::word::{self, Word};
+use bnum::types::U512;
+use num_integer::Integer;
+use rustic_factors_derive::PrimalityCommand;
+
+/// The Solovay–Strassen test, which runs `ROUNDS` rounds with random bases `a` and checks
+/// Euler's criterion `a^((n-1)/2) = (a/n) mod n` with the Jacobi symbol `(a/n)`.
+///
+/// Unlike the Fermat test, it has no analogue of Carmichael numbers: a composite passes a round
+/// with probability at most `1/2`. This is twice the bound of
+/// [`MillerRabin`](super::MillerRabin), whose condition implies Euler's criterion.
+#[derive(Default, PrimalityCommand)]
+#[primality_command(witness)]
+pub struct SolovayStrassen<const ROUNDS: usize = 50>;
+
+impl<const ROUNDS: usize> PrimalityTest for SolovayStrassen<ROUNDS> {
+    fn is_prime(p: &U512) -> bool {
+        word::is_prime_narrowest::<Self>(p)
+    }
+
+    fn is_prime_word<W: Word>(p: W) -> bool {
+        if p == W::two() || p == W::from(3u8) {
+            return true;
+        }
+        if p < W::two() || p.is_even() {
+            return false;
+        }
+        Self::find_witness(p).is_none()
+    }
+}
+
+impl<const ROUNDS: usize> CompositeWitness for SolovayStrassen<ROUNDS> {
+    fn composite_witness(n: &U512) -> Option<U512> {
+        if *n <= U512::THREE || n.is_even() {
+            return None;
+        }
+        Self::find_witness(*n)
+    }
+}
+
+impl<const ROUNDS: usize> SolovayStrassen<ROUNDS> {
+    /// Expects an odd `p > 3`.
+    fn find_witness<W: Word>(p: W) -> Option<W> {
+        let context = MontgomeryContext::new(p);
+        utils::RandomIntegers::new(W::two()..p - W::one())
+            .take(ROUNDS)
+            .find(|&base| !satisfies_eulers_criterion(&context, base))
+    }
+}
+
+fn satisfies_eulers_criterion<W: Word>(context: &MontgomeryContext<W>, base: W) -> bool {
+    let n = context.modulus();
+    let power = context.pow(context.to_montgomery(base), (n - W::one()) / W::two());
+    match modular::jacobi_symbol(base, n) {
+        1 => power == context.one(),
+        -1 => power == context.sub(W::zero(), context.one()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_framework::pseudoprimes;
+
+    #[test]
+    fn agrees_with_sieve() {
+        let primes = crate::primes::sieve_of_eratosthenes(5_000);
+        for n in 0..5_000u64 {
+            let expected = primes.binary_search(&n).is_ok();
+            assert_eq!(<SolovayStrassen>::is_prime(&U512::from(n)), expected, "{n}");
+        }
+    }
+
+    #[test]
+    fn euler_jacobi_pseudoprimes_to_base_2() {
+        for composite in pseudoprimes::EULER_JACOBI_PSEUDOPRIMES_TO_BASE_2 {
+            let context = MontgomeryContext::new(composite);
+            assert!(satisfies_eulers_criterion(&context, 2), "{composite}");
+            assert!(!<SolovayStrassen>::is_prime_word(composite), "{composite}");
+        }
+    }
+
+    #[test]
+    fn carmichael_numbers() {
+        for carmichael in pseudoprimes::CARMICHAEL_NUMBERS {
+            assert!(
+                !<SolovayStrassen>::is_prime_word(carmichael),
+                "{carmichael}"
+            );
+        }
+    }
+
+    #[test]
+    fn primes_near_512_bits() {
+        let prime = U512::power_of_two(511) - U512::from(187u8);
+        assert!(<SolovayStrassen>::is_prime(&prime));
+        assert!(!<SolovayStrassen>::is_prime(&(prime - U512::TWO)));
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Returns all primes `p <= limit` in increasing order, using the sieve of Eratosthenes.
+pub fn sieve_of_eratosthenes(limit: u64) -> Vec<u64> {
+    if limit < 2 {
+        return vec![];
+    }
+    let limit = limit as usize;
+    let mut is_composite = vec![false; limit + 1];
+    let mut i = 2;
+    while i * i <= limit {
+        if !is_composite[i] {
+            for multiple in (i * i..=limit).step_by(i) {
+                is_composite[multiple] = true;
+            }
+        }
+        i += 1;
+    }
+    (2..=limit)
+        .filter(|&p| !is_composite[p])
+        .map(|p| p as u64)
+        .collect()
+}
+
+/// Does the same as [`sieve_of_eratosthenes`], but keeps the primes for later calls with the
+/// same limit. The methods with smoothness bounds are called again and again on the same
+/// composite by the orchestrators, and would otherwise sieve up to their bounds every time.
+pub fn cached_primes_up_to(limit: u64) -> Arc<[u64]> {
+    static CACHE: OnceLock<Mutex<HashMap<u64, Arc<[u64]>>>> = OnceLock::new();
+    let mut cache = CACHE.get_or_init(Mutex::default).lock().unwrap();
+    cache
+        .entry(limit)
+        .or_insert_with(|| sieve_of_eratosthenes(limit).into())
+        .clone()
+}
+
+/// Returns the largest power `q^k <= bound` of the prime `q <= bound`.
+pub fn largest_power_not_exceeding(q: u64, bound: u64) -> u64 {
+    let mut power = q;
+    while power <= bound / q {
+        power *= q;
+    }
+    power
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_limits() {
+        assert_eq!(sieve_of_eratosthenes(0), vec![]);
+        assert_eq!(sieve_of_eratosthenes(1), vec![]);
+        assert_eq!(sieve_of_eratosthenes(2), vec![2]);
+        assert_eq!(
+            sieve_of_eratosthenes(30),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+    }
+
+    #[test]
+    fn prime_counting_function() {
+        assert_eq!(sieve_of_eratosthenes(10_000).len(), 1229);
+        assert_eq!(sieve_of_eratosthenes(1_000_000).len(), 78498);
+    }
+
+    #[test]
+    fn largest_powers() {
+        assert_eq!(largest_power_not_exceeding(2, 100), 64);
+        assert_eq!(largest_power_not_exceeding(3, 81), 81);
+        assert_eq!(largest_power_not_exceeding(11, 100), 11);
+        assert_eq!(largest_power_not_exceeding(2, u64::MAX), 1 << 63);
+    }
+
+    #[test]
+    fn cached_primes_are_sieved_once_per_limit() {
+        let primes = cached_primes_up_to(10_000);
+        assert_eq!(*primes, *sieve_of_eratosthenes(10_000));
+        assert!(Arc::ptr_eq(&primes, &cached_primes_up_to(10_000)));
+        assert_eq!(cached_primes_up_to(30).len(), 10);
+    }
+}
@@ -1 +1,2 @@
 pub mod prime_factorization;
+pub mod pseudoprimes;
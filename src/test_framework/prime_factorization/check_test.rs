@@ -19,12 +19,12 @@ impl<F: PrimeFactorization> CheckTest<F> {
 
     pub fn check_cases(self) {
         for (n, factors) in &self.cases {
-            Self::check(n, &factors);
+            Self::check(n, factors);
         }
     }
 
     pub fn check(n: &U512, expected: &[U512]) {
-        let mut actual = F::prime_factorization(&n);
+        let mut actual = F::prime_factorization(n);
         actual.sort_unstable();
         assert_eq!(actual, expected, "Test failed for n = {}", n);
     }
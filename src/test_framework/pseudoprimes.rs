@@ -0,0 +1,24 @@
+//! Composites that fool some of the primality tests, for comparing them.
+
+/// Carmichael numbers pass the Fermat test to every base coprime to them.
+pub const CARMICHAEL_NUMBERS: [u64; 33] = [
+    561, 1105, 1729, 2465, 2821, 6601, 8911, 10585, 15841, 29341, 41041, 46657, 52633, 62745,
+    63973, 75361, 101101, 115921, 126217, 162401, 172081, 188461, 252601, 278545, 294409, 314821,
+    334153, 340561, 399001, 410041, 449065, 488881, 512461,
+];
+
+/// Composites `n` with `2^((n-1)/2) = (2/n) mod n`, which pass a round of the
+/// Solovay–Strassen test with base 2.
+pub const EULER_JACOBI_PSEUDOPRIMES_TO_BASE_2: [u64; 12] = [
+    561, 1105, 1729, 1905, 2047, 2465, 3277, 4033, 4681, 6601, 8321, 8481,
+];
+
+/// Composites that pass a round of the Miller–Rabin test with base 2.
+pub const STRONG_PSEUDOPRIMES_TO_BASE_2: [u64; 10] = [
+    2047, 3277, 4033, 4681, 8321, 15841, 29341, 42799, 49141, 52633,
+];
+
+/// Composites that pass the strong Lucas test with Selfridge's parameters.
+pub const STRONG_LUCAS_PSEUDOPRIMES: [u64; 10] = [
+    5459, 5777, 10877, 16109, 18971, 22499, 24569, 25199, 40309, 58519,
+];
@@ -1,19 +1,82 @@
+use crate::budget::{Budget, GaveUp};
+use crate::factorization::FactorizationError;
+use crate::word::Word;
 use bnum::types::U512;
 
 pub trait Factorize {
     fn factorize(n: &U512) -> U512;
+
+    /// Does the same as [`Factorize::factorize`] on any [`Word`]. Implementations with native
+    /// arithmetic override this, while the default widens `n` to a `U512`.
+    fn factorize_word<W: Word>(n: W) -> W {
+        W::from_u512(&Self::factorize(&n.to_u512())).unwrap()
+    }
+
+    /// Does the same as [`Factorize::factorize`], but gives up once the budget runs out.
+    /// Algorithms that may run for a long time override this and spend the budget in their
+    /// main loops, while the default only checks it before starting.
+    fn factorize_with_budget(n: &U512, budget: &Budget) -> Result<U512, GaveUp> {
+        budget.check()?;
+        Ok(Self::factorize(n))
+    }
+
+    /// Does the same as [`Factorize::factorize_with_budget`] on any [`Word`], like
+    /// [`Factorize::factorize_word`].
+    fn factorize_word_with_budget<W: Word>(n: W, budget: &Budget) -> Result<W, GaveUp> {
+        Ok(W::from_u512(&Self::factorize_with_budget(&n.to_u512(), budget)?).unwrap())
+    }
 }
 
 pub trait PrimeFactorization {
     fn prime_factorization(n: &U512) -> Vec<U512>;
+
+    /// Does the same as [`PrimeFactorization::prime_factorization`], but reports the factors
+    /// found so far and the cofactors left composite instead of panicking when it gives up.
+    /// Implementations that can give up override this, while the default never fails.
+    fn try_prime_factorization(n: &U512) -> Result<Vec<U512>, FactorizationError> {
+        Ok(Self::prime_factorization(n))
+    }
+
+    /// Does the same as [`PrimeFactorization::try_prime_factorization`], but also gives up
+    /// once the budget runs out. The default only checks the budget before starting.
+    fn try_prime_factorization_with_budget(
+        n: &U512,
+        budget: &Budget,
+    ) -> Result<Vec<U512>, FactorizationError> {
+        if budget.check().is_err() {
+            return Err(FactorizationError::gave_up_on(n));
+        }
+        Self::try_prime_factorization(n)
+    }
 }
 
 pub trait PrimalityTest {
     fn is_prime(p: &U512) -> bool;
+
+    /// Does the same as [`PrimalityTest::is_prime`] on any [`Word`]. Implementations with
+    /// native arithmetic override this, while the default widens `p` to a `U512`.
+    fn is_prime_word<W: Word>(p: W) -> bool {
+        Self::is_prime(&p.to_u512())
+    }
+}
+
+/// A primality test that can name the base showing that a number is composite.
+pub trait CompositeWitness {
+    /// Returns a base that proves `n` composite, or `None` if `n` is prime, too small or even,
+    /// or no such base was found.
+    fn composite_witness(n: &U512) -> Option<U512>;
 }
 
 pub trait Command {
     fn run(&self, n: &U512) -> String;
+
+    /// Does the same as [`Command::run`] within the budget, but returns the error of a
+    /// factorization that gave up rather than describing it in the output.
+    fn try_run(&self, n: &U512, _budget: &Budget) -> Result<String, FactorizationError> {
+        Ok(self.run(n))
+    }
 }
 
 pub trait FactorizationCommand: Command + PrimeFactorization {}
+
+pub trait PrimalityCommand: Command + PrimalityTest {}
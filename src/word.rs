@@ -0,0 +1,289 @@
+use crate::budget::{Budget, GaveUp};
+use crate::modular;
+use crate::traits::{Factorize, PrimalityTest};
+use bnum::types::{U256, U512};
+use num_integer::{Integer, Roots};
+use rand::distributions::uniform::SampleUniform;
+use std::fmt::{Debug, Display};
+
+/// An unsigned integer type that algorithms can run on.
+///
+/// Every number is accepted as a [`U512`], but most of the time is spent on much smaller
+/// numbers and cofactors. Algorithms that are generic over `Word` run on native `u64` or
+/// `u128` arithmetic whenever the number fits, which is many times faster than `U512`.
+pub trait Word:
+    Copy + Debug + Display + Ord + Integer + Roots + From<u8> + From<u64> + SampleUniform
+{
+    const BITS: u32;
+
+    fn to_u512(self) -> U512;
+
+    /// Returns `None` if `n` does not fit.
+    fn from_u512(n: &U512) -> Option<Self>;
+
+    /// Computes `self * rhs mod n` without overflowing.
+    fn mul_mod(self, rhs: Self, n: Self) -> Self;
+
+    /// Computes `self mod d` for a divisor that fits in a `u64`.
+    fn rem_u64(self, d: u64) -> u64;
+
+    /// Returns the low and high halves of the full product `self * rhs`.
+    fn mul_wide(self, rhs: Self) -> (Self, Self);
+
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    fn two() -> Self {
+        Self::from(2u8)
+    }
+
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    /// Computes `self + rhs mod n` for `self, rhs < n` without overflowing.
+    fn add_mod(self, rhs: Self, n: Self) -> Self {
+        if self >= n - rhs {
+            self - (n - rhs)
+        } else {
+            self + rhs
+        }
+    }
+
+    fn pow_mod(self, mut exp: Self, n: Self) -> Self {
+        let mut result = Self::one() % n;
+        let mut base = self % n;
+        while !exp.is_zero() {
+            if exp.is_odd() {
+                result = result.mul_mod(base, n);
+            }
+            base = base.mul_mod(base, n);
+            exp = exp / Self::two();
+        }
+        result
+    }
+}
+
+impl Word for u64 {
+    const BITS: u32 = u64::BITS;
+
+    fn to_u512(self) -> U512 {
+        U512::from(self)
+    }
+
+    fn from_u512(n: &U512) -> Option<Self> {
+        u64::try_from(*n).ok()
+    }
+
+    fn mul_mod(self, rhs: Self, n: Self) -> Self {
+        (self as u128 * rhs as u128 % n as u128) as u64
+    }
+
+    fn rem_u64(self, d: u64) -> u64 {
+        self % d
+    }
+
+    fn mul_wide(self, rhs: Self) -> (Self, Self) {
+        let product = self as u128 * rhs as u128;
+        (product as u64, (product >> 64) as u64)
+    }
+
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl Word for u128 {
+    const BITS: u32 = u128::BITS;
+
+    fn to_u512(self) -> U512 {
+        U512::from(self)
+    }
+
+    fn from_u512(n: &U512) -> Option<Self> {
+        u128::try_from(*n).ok()
+    }
+
+    fn mul_mod(self, rhs: Self, n: Self) -> Self {
+        if let Some(product) = self.checked_mul(rhs) {
+            return product % n;
+        }
+        let product = U256::from(self) * U256::from(rhs) % U256::from(n);
+        u128::try_from(product).unwrap()
+    }
+
+    fn rem_u64(self, d: u64) -> u64 {
+        (self % d as u128) as u64
+    }
+
+    /// Multiplies the 64-bit halves separately and adds up the four partial products.
+    fn mul_wide(self, rhs: Self) -> (Self, Self) {
+        const LOW: u128 = u64::MAX as u128;
+        let (a_low, a_high) = (self & LOW, self >> 64);
+        let (b_low, b_high) = (rhs & LOW, rhs >> 64);
+        let (cross_1, cross_2) = (a_high * b_low, a_low * b_high);
+        let low = a_low * b_low;
+        let middle = (low >> 64) + (cross_1 & LOW) + (cross_2 & LOW);
+        let high = a_high * b_high + (cross_1 >> 64) + (cross_2 >> 64) + (middle >> 64);
+        ((low & LOW) | (middle << 64), high)
+    }
+
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl Word for U512 {
+    const BITS: u32 = U512::BITS;
+
+    fn to_u512(self) -> U512 {
+        self
+    }
+
+    fn from_u512(n: &U512) -> Option<Self> {
+        Some(*n)
+    }
+
+    fn mul_mod(self, rhs: Self, n: Self) -> Self {
+        modular::mul_mod(&self, &rhs, &n)
+    }
+
+    /// Works one 64-bit digit at a time, so that only native divisions are needed.
+    fn rem_u64(self, d: u64) -> u64 {
+        self.digits().iter().rev().fold(0u128, |rem, &digit| {
+            ((rem << 64) | digit as u128) % d as u128
+        }) as u64
+    }
+
+    /// Schoolbook multiplication on the 64-bit digits, skipping zero digits of `self` so
+    /// that small numbers are multiplied quickly.
+    fn mul_wide(self, rhs: Self) -> (Self, Self) {
+        const DIGITS: usize = U512::BITS as usize / 64;
+        let mut product = [0u64; 2 * DIGITS];
+        for (i, &a) in self.digits().iter().enumerate().filter(|(_, &a)| a != 0) {
+            let mut carry = 0u128;
+            for (j, &b) in rhs.digits().iter().enumerate() {
+                let t = a as u128 * b as u128 + product[i + j] as u128 + carry;
+                product[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            product[i + DIGITS] = carry as u64;
+        }
+        let (low, high) = product.split_at(DIGITS);
+        (
+            U512::from_digits(low.try_into().unwrap()),
+            U512::from_digits(high.try_into().unwrap()),
+        )
+    }
+
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self.wrapping_sub(rhs)
+    }
+}
+
+/// Runs [`Factorize::factorize_word`] on the narrowest of `u64`, `u128` and `U512` that
+/// holds `n`.
+pub fn factorize_narrowest<F: Factorize>(n: &U512) -> U512 {
+    if let Some(n) = u64::from_u512(n) {
+        F::factorize_word(n).to_u512()
+    } else if let Some(n) = u128::from_u512(n) {
+        F::factorize_word(n).to_u512()
+    } else {
+        F::factorize_word(*n)
+    }
+}
+
+/// Does the same as [`factorize_narrowest`] with
+/// [`Factorize::factorize_word_with_budget`].
+pub fn factorize_narrowest_with_budget<F: Factorize>(
+    n: &U512,
+    budget: &Budget,
+) -> Result<U512, GaveUp> {
+    if let Some(n) = u64::from_u512(n) {
+        Ok(F::factorize_word_with_budget(n, budget)?.to_u512())
+    } else if let Some(n) = u128::from_u512(n) {
+        Ok(F::factorize_word_with_budget(n, budget)?.to_u512())
+    } else {
+        F::factorize_word_with_budget(*n, budget)
+    }
+}
+
+/// Runs [`PrimalityTest::is_prime_word`] on the narrowest of `u64`, `u128` and `U512` that
+/// holds `p`.
+pub fn is_prime_narrowest<P: PrimalityTest>(p: &U512) -> bool {
+    if let Some(p) = u64::from_u512(p) {
+        P::is_prime_word(p)
+    } else if let Some(p) = u128::from_u512(p) {
+        P::is_prime_word(p)
+    } else {
+        P::is_prime_word(*p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_mod_does_not_overflow() {
+        let n = u64::MAX - 58;
+        assert_eq!((n - 1).mul_mod(n - 1, n), 1);
+        let n = u128::MAX - 158;
+        assert_eq!((n - 1).mul_mod(n - 1, n), 1);
+        assert_eq!((n - 2).mul_mod(3, n), n - 6);
+    }
+
+    #[test]
+    fn wide_products() {
+        assert_eq!(u64::MAX.mul_wide(u64::MAX), (1, u64::MAX - 1));
+        assert_eq!(u128::MAX.mul_wide(u128::MAX), (1, u128::MAX - 1));
+        assert_eq!(
+            U512::MAX.mul_wide(U512::MAX),
+            (U512::ONE, U512::MAX - U512::ONE)
+        );
+        let (a, b) = (0x1234_5678_9abc_def0_1122_3344_5566_7788u128, u128::MAX / 3);
+        let product = U512::from(a) * U512::from(b);
+        let (low, high) = a.mul_wide(b);
+        assert_eq!(product, U512::from(low) | U512::from(high) << 128);
+        assert_eq!(U512::from(a).mul_wide(U512::from(b)), (product, U512::ZERO));
+    }
+
+    #[test]
+    fn remainder_of_wide_number() {
+        let n = U512::from(3u8).pow(300);
+        for d in [2, 3, 7, 65537, 4294967291] {
+            assert_eq!(n.rem_u64(d), (n % U512::from(d)).digits()[0]);
+        }
+    }
+
+    #[test]
+    fn pow_mod_agrees_across_widths() {
+        let (base, exp, n) = (123456789u64, 987654321u64, 1000000007u64);
+        let expected = base.pow_mod(exp, n);
+        assert_eq!(
+            (base as u128).pow_mod(exp as u128, n as u128),
+            expected as u128
+        );
+        assert_eq!(
+            U512::from(base).pow_mod(U512::from(exp), U512::from(n)),
+            U512::from(expected)
+        );
+    }
+}
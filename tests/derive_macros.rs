@@ -0,0 +1,9 @@
+//! Checks that the derive macros work from a crate other than `rustic_factors`, and that they
+//! report bad attributes as compile errors.
+
+#[test]
+fn derive_macros() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/pass/*.rs");
+    cases.compile_fail("tests/ui/fail/*.rs");
+}
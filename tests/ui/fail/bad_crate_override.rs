@@ -0,0 +1,14 @@
+use rustic_factors::traits::Factorize;
+use rustic_factors::{FactorizationCommand, RecursivePrimeFactorization, U512};
+
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+#[rustic_factors(path = rustic_factors)]
+struct UnknownKey;
+
+impl Factorize for UnknownKey {
+    fn factorize(n: &U512) -> U512 {
+        *n
+    }
+}
+
+fn main() {}
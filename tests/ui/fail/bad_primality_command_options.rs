@@ -0,0 +1,24 @@
+use rustic_factors::traits::PrimalityTest;
+use rustic_factors::{PrimalityCommand, U512};
+
+#[derive(PrimalityCommand)]
+#[primality_command(witness, proof)]
+struct UnknownOption;
+
+#[derive(PrimalityCommand)]
+#[primality_command(certificate, certificate)]
+struct DuplicateOption;
+
+impl PrimalityTest for UnknownOption {
+    fn is_prime(_: &U512) -> bool {
+        true
+    }
+}
+
+impl PrimalityTest for DuplicateOption {
+    fn is_prime(_: &U512) -> bool {
+        true
+    }
+}
+
+fn main() {}
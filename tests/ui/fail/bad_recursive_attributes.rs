@@ -0,0 +1,27 @@
+use rustic_factors::traits::Factorize;
+use rustic_factors::{RecursivePrimeFactorization, U512};
+
+#[derive(RecursivePrimeFactorization)]
+#[prime_tester = "BailliePsw"]
+#[max_successive_fails = "many"]
+struct BadValues;
+
+#[derive(RecursivePrimeFactorization)]
+#[max_successive_fails = 0]
+#[prime_tester(MillerRabin)]
+#[prime_tester(BailliePsw)]
+struct ZeroAndDuplicate;
+
+impl Factorize for BadValues {
+    fn factorize(n: &U512) -> U512 {
+        *n
+    }
+}
+
+impl Factorize for ZeroAndDuplicate {
+    fn factorize(n: &U512) -> U512 {
+        *n
+    }
+}
+
+fn main() {}
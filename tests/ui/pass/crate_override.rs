@@ -0,0 +1,24 @@
+mod reexport {
+    pub use rustic_factors as factors;
+}
+
+use reexport::factors::traits::{Command, Factorize};
+use reexport::factors::{FactorizationCommand, RecursivePrimeFactorization, U512};
+
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+#[rustic_factors(crate = crate::reexport::factors)]
+struct Halving;
+
+impl Factorize for Halving {
+    fn factorize(n: &U512) -> U512 {
+        if (n % U512::TWO).is_zero() {
+            U512::TWO
+        } else {
+            *n
+        }
+    }
+}
+
+fn main() {
+    assert_eq!(Halving.run(&U512::from(8u8)), "8 = 2³");
+}
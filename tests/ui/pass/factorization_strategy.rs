@@ -0,0 +1,33 @@
+use rustic_factors::primality_test::BailliePsw;
+use rustic_factors::traits::{Command, Factorize};
+use rustic_factors::{FactorizationCommand, RecursivePrimeFactorization, U512};
+
+/// Finds the smallest factor of at most 16 bits.
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+#[prime_tester(BailliePsw)]
+#[max_successive_fails = 10]
+struct SmallTrialDivision;
+
+impl Factorize for SmallTrialDivision {
+    fn factorize(n: &U512) -> U512 {
+        (2u32..1 << 16)
+            .map(U512::from)
+            .find(|d| (n % d).is_zero())
+            .unwrap_or(*n)
+    }
+}
+
+#[derive(FactorizationCommand, RecursivePrimeFactorization)]
+struct WithDefaults;
+
+impl Factorize for WithDefaults {
+    fn factorize(n: &U512) -> U512 {
+        SmallTrialDivision::factorize(n)
+    }
+}
+
+fn main() {
+    let n = U512::from(1001u16);
+    assert_eq!(SmallTrialDivision.run(&n), "1001 = 7 x 11 x 13");
+    assert_eq!(WithDefaults.run(&n), "1001 = 7 x 11 x 13");
+}
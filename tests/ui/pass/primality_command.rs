@@ -0,0 +1,35 @@
+use rustic_factors::traits::{Command, CompositeWitness, PrimalityCommand, PrimalityTest};
+use rustic_factors::{PrimalityCommand, U512};
+
+#[derive(PrimalityCommand)]
+#[primality_command(witness, certificate)]
+struct TrialDivisionTest;
+
+impl PrimalityTest for TrialDivisionTest {
+    fn is_prime(p: &U512) -> bool {
+        *p >= U512::TWO && Self::composite_witness(p).is_none()
+    }
+}
+
+impl CompositeWitness for TrialDivisionTest {
+    fn composite_witness(n: &U512) -> Option<U512> {
+        (2u32..1 << 16)
+            .map(U512::from)
+            .take_while(|d| d * d <= *n)
+            .find(|d| (n % d).is_zero())
+    }
+}
+
+fn assert_primality_command<T: PrimalityCommand>(_: &T) {}
+
+fn main() {
+    assert_primality_command(&TrialDivisionTest);
+    assert_eq!(
+        TrialDivisionTest.run(&U512::from(91u8)),
+        "91 is composite, witnessed by 7"
+    );
+    assert_eq!(
+        TrialDivisionTest.run(&U512::from(97u8)),
+        "97 is prime\n97 is prime by trial division"
+    );
+}